@@ -24,6 +24,11 @@ pub enum Commands {
         #[command(flatten)]
         options: RunOptions,
     },
+    /// Write the current findings to a baseline file, to only fail on new findings
+    Baseline {
+        #[command(flatten)]
+        options: BaselineOptions,
+    },
 }
 
 #[derive(Args, Debug, Clone)]
@@ -52,11 +57,74 @@ pub struct RunOptions {
     #[arg(long, short = 'g', default_value = "target/catalog.json")]
     pub catalog_file: String,
 
+    /// Path to the `sources.json` written by `dbt source freshness`, only read when `freshness_tests` are configured
+    #[arg(long, default_value = "target/sources.json")]
+    pub sources_file: String,
+
     #[arg(long, default_value_t = false)]
     pub only_manifest: bool,
 
     #[arg(long, default_value_t = false)]
     pub disable_hyperlinks: bool,
+
+    /// Output format of the findings (table, json, sarif, junit or github)
+    #[arg(long, value_parser = ["table", "json", "sarif", "junit", "github"], default_value = "table")]
+    pub output_format: String,
+
+    /// Write the findings to this file instead of stdout (machine-readable formats only)
+    #[arg(long)]
+    pub output_file: Option<String>,
+
+    /// Baseline file created by `dbtective baseline`, findings in it are not reported
+    #[arg(long)]
+    pub baseline: Option<String>,
+
+    /// Only evaluate objects defined in files changed since this git ref (e.g. `origin/main`)
+    #[arg(long)]
+    pub changed_since: Option<String>,
+
+    /// Manifest of a previous run, only new or modified objects are evaluated
+    #[arg(long)]
+    pub state: Option<String>,
+
+    /// Only evaluate the selected objects, using dbt's node selection syntax (e.g. `tag:finance`, `+orders`)
+    #[arg(long, short = 's', num_args = 1..)]
+    pub select: Option<Vec<String>>,
+
+    /// Do not evaluate the selected objects, using dbt's node selection syntax
+    #[arg(long, num_args = 1..)]
+    pub exclude: Option<Vec<String>>,
+
+    /// Only evaluate the objects selected by a selector defined in the project's `selectors.yml`
+    #[arg(long)]
+    pub selector: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct BaselineOptions {
+    /// Path to dbt project root directory
+    #[arg(long, default_value = ".")]
+    pub entry_point: String,
+
+    #[arg(long, short = 'c')]
+    pub config_file: Option<String>,
+
+    #[arg(long, short = 'm', default_value = "target/manifest.json")]
+    pub manifest_file: String,
+
+    #[arg(long, short = 'g', default_value = "target/catalog.json")]
+    pub catalog_file: String,
+
+    /// Path to the `sources.json` written by `dbt source freshness`, only read when `freshness_tests` are configured
+    #[arg(long, default_value = "target/sources.json")]
+    pub sources_file: String,
+
+    #[arg(long, default_value_t = false)]
+    pub only_manifest: bool,
+
+    /// File to write the baseline to
+    #[arg(long, short = 'o', default_value = "dbtective-baseline.json")]
+    pub output: String,
 }
 
 #[cfg(test)]
@@ -105,8 +173,17 @@ mod tests {
             entry_point: "./".to_string(),
             config_file: Some("dbtective.toml".to_string()),
             catalog_file: "target/catalog.json".to_string(),
+            sources_file: "target/sources.json".to_string(),
             only_manifest: false,
             disable_hyperlinks: false,
+            output_format: "table".to_string(),
+            output_file: None,
+            baseline: None,
+            changed_since: None,
+            state: None,
+            select: None,
+            exclude: None,
+            selector: None,
         };
         let debug_str = format!("{options:?}");
         assert!(debug_str.contains("RunOptions"));
@@ -121,8 +198,17 @@ mod tests {
             entry_point: "./".to_string(),
             config_file: Some("dbtective.toml".to_string()),
             catalog_file: "target/catalog.json".to_string(),
+            sources_file: "target/sources.json".to_string(),
             only_manifest: false,
             disable_hyperlinks: false,
+            output_format: "table".to_string(),
+            output_file: None,
+            baseline: None,
+            changed_since: None,
+            state: None,
+            select: None,
+            exclude: None,
+            selector: None,
         };
 
         assert_eq!(options.entry_point, "./");
@@ -137,8 +223,17 @@ mod tests {
             entry_point: "/path/to/project".to_string(),
             config_file: Some("custom_config.toml".to_string()),
             catalog_file: "custom_catalog.json".to_string(),
+            sources_file: "target/sources.json".to_string(),
             only_manifest: true,
             disable_hyperlinks: false,
+            output_format: "table".to_string(),
+            output_file: None,
+            baseline: None,
+            changed_since: None,
+            state: None,
+            select: None,
+            exclude: None,
+            selector: None,
         };
 
         assert_eq!(options.entry_point, "/path/to/project");
@@ -154,7 +249,7 @@ mod tests {
 
         match init_cmd {
             Commands::Init { options: _ } => {}
-            Commands::Run { .. } => panic!("Expected Init variant"),
+            Commands::Run { .. } | Commands::Baseline { .. } => panic!("Expected Init variant"),
         }
 
         let run_cmd = Commands::Run {
@@ -163,14 +258,23 @@ mod tests {
                 entry_point: "./".to_string(),
                 config_file: None,
                 catalog_file: "target/catalog.json".to_string(),
+                sources_file: "target/sources.json".to_string(),
                 only_manifest: false,
                 disable_hyperlinks: false,
+                output_format: "table".to_string(),
+                output_file: None,
+                baseline: None,
+                changed_since: None,
+                state: None,
+                select: None,
+                exclude: None,
+                selector: None,
             },
         };
 
         match run_cmd {
             Commands::Run { options: _ } => {}
-            Commands::Init { .. } => panic!("Expected Run variant"),
+            Commands::Init { .. } | Commands::Baseline { .. } => panic!("Expected Run variant"),
         }
     }
 
@@ -204,9 +308,18 @@ mod tests {
                     manifest_file: "custom_manifest.json".to_string(),
                     entry_point: "./src".to_string(),
                     catalog_file: "target/catalog.json".to_string(),
+                    sources_file: "target/sources.json".to_string(),
                     config_file: Some("config.toml".to_string()),
                     only_manifest: false,
                     disable_hyperlinks: false,
+                    output_format: "table".to_string(),
+                    output_file: None,
+                    baseline: None,
+                    changed_since: None,
+                    state: None,
+                    select: None,
+                    exclude: None,
+                    selector: None,
                 },
             }),
         };
@@ -236,8 +349,17 @@ mod tests {
                 entry_point: "./".to_string(),
                 config_file: Some("dbtective.toml".to_string()),
                 catalog_file: "target/catalog.json".to_string(),
+                sources_file: "target/sources.json".to_string(),
                 only_manifest: false,
                 disable_hyperlinks: false,
+                output_format: "table".to_string(),
+                output_file: None,
+                baseline: None,
+                changed_since: None,
+                state: None,
+                select: None,
+                exclude: None,
+                selector: None,
             },
         };
         let debug_str = format!("{run_cmd:?}");
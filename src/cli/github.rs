@@ -0,0 +1,126 @@
+use crate::cli::output::{project_path, write_output};
+use crate::cli::table::{sort_results, RuleResult};
+use crate::core::config::severity::Severity;
+use crate::core::utils::unwrap_or_exit;
+
+/// Escape the message part of a workflow command.
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a property value (`file`, `title`) of a workflow command.
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+const fn command(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    }
+}
+
+/// Render all findings as GitHub Actions workflow commands,
+/// which show up as annotations on the pull request diff.
+///
+/// Findings on properties defined in a YAML property file point at that file,
+/// all other findings point at the file of the object itself.
+pub fn render_github(results: &[(RuleResult, &Severity)], entry_point: &str) -> String {
+    sort_results(results)
+        .into_iter()
+        .map(|(result, severity)| {
+            let mut properties = Vec::new();
+            if let Some(path) = result.location_path() {
+                properties.push(format!(
+                    "file={}",
+                    escape_property(&project_path(entry_point, path))
+                ));
+                if let Some(line) = result.line {
+                    properties.push(format!("line={line}"));
+                }
+                if let Some(column) = result.column {
+                    properties.push(format!("col={column}"));
+                }
+            }
+            properties.push(format!("title={}", escape_property(&result.rule_name)));
+
+            format!(
+                "::{} {}::{}",
+                command(severity),
+                properties.join(","),
+                escape_data(&result.message)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn show_results_as_github(
+    results: &[(RuleResult, &Severity)],
+    entry_point: &str,
+    output_file: Option<&String>,
+) -> i32 {
+    unwrap_or_exit(write_output(
+        &render_github(results, entry_point),
+        output_file,
+    ));
+
+    i32::from(!results.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escaping() {
+        assert_eq!(escape_data("50% done\nnext"), "50%25 done%0Anext");
+        assert_eq!(escape_property("a:b,c"), "a%3Ab%2Cc");
+    }
+
+    #[test]
+    fn test_render_github() {
+        let results = vec![
+            (
+                RuleResult::new(
+                    &Severity::Error,
+                    "Model",
+                    "has_description",
+                    "orders is missing a description.",
+                    Some("models/orders.sql".to_string()),
+                )
+                .with_patch_path(Some("models/_models.yml"))
+                .with_location(4, Some(5)),
+                &Severity::Error,
+            ),
+            (
+                RuleResult::new(
+                    &Severity::Warning,
+                    "Model",
+                    "has_refs",
+                    "orders does not have any upstream references (refs/sources)",
+                    Some("models/orders.sql".to_string()),
+                ),
+                &Severity::Warning,
+            ),
+            (
+                RuleResult::new(&Severity::Warning, "Model", "has_refs", "no file", None),
+                &Severity::Warning,
+            ),
+        ];
+
+        let output = render_github(&results, "dbt_project");
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "::error file=dbt_project/models/_models.yml,line=4,col=5,title=has_description::orders is missing a description.",
+                "::warning title=has_refs::no file",
+                "::warning file=dbt_project/models/orders.sql,title=has_refs::orders does not have any upstream references (refs/sources)",
+            ]
+        );
+    }
+}
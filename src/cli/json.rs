@@ -0,0 +1,186 @@
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::cli::output::{write_output, RunInfo};
+use crate::cli::table::{sort_results, RuleResult};
+use crate::core::config::severity::Severity;
+use crate::core::utils::unwrap_or_exit;
+
+/// Version of the JSON document layout.
+/// Bump this whenever a field is renamed or removed, adding fields is backwards compatible.
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize)]
+struct JsonReport<'a> {
+    schema_version: u32,
+    dbtective_version: &'static str,
+    metadata: JsonMetadata<'a>,
+    summary: JsonSummary,
+    results: Vec<JsonResult<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonMetadata<'a> {
+    dbt_version: &'a str,
+    project_name: Option<&'a str>,
+    manifest_generated_at: Option<&'a str>,
+    duration_seconds: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonSummary {
+    total: usize,
+    errors: usize,
+    warnings: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonResult<'a> {
+    severity: &'a Severity,
+    object_type: &'a str,
+    rule_name: &'a str,
+    message: &'a str,
+    relative_path: Option<&'a str>,
+    unique_id: Option<&'a str>,
+    patch_path: Option<&'a str>,
+    line: Option<usize>,
+    column: Option<usize>,
+}
+
+/// Render all findings and run information as a versioned JSON document.
+/// # Errors
+/// Returns an error if the report cannot be serialized
+pub fn render_json(results: &[(RuleResult, &Severity)], run_info: &RunInfo) -> Result<String> {
+    let errors = results
+        .iter()
+        .filter(|(_, severity)| **severity == Severity::Error)
+        .count();
+
+    let report = JsonReport {
+        schema_version: JSON_SCHEMA_VERSION,
+        dbtective_version: env!("CARGO_PKG_VERSION"),
+        metadata: JsonMetadata {
+            dbt_version: run_info.dbt_version,
+            project_name: run_info.project_name,
+            manifest_generated_at: run_info.manifest_generated_at,
+            duration_seconds: run_info.duration.map(|d| d.as_secs_f64()),
+        },
+        summary: JsonSummary {
+            total: results.len(),
+            errors,
+            warnings: results.len() - errors,
+        },
+        results: sort_results(results)
+            .into_iter()
+            .map(|(result, severity)| JsonResult {
+                severity,
+                object_type: &result.object_type,
+                rule_name: &result.rule_name,
+                message: &result.message,
+                relative_path: result.relative_path.as_deref(),
+                unique_id: result.unique_id.as_deref(),
+                patch_path: result.patch_path.as_deref(),
+                line: result.line,
+                column: result.column,
+            })
+            .collect(),
+    };
+
+    Ok(serde_json::to_string_pretty(&report)?)
+}
+
+pub fn show_results_as_json(
+    results: &[(RuleResult, &Severity)],
+    run_info: &RunInfo,
+    output_file: Option<&String>,
+) -> i32 {
+    let report = unwrap_or_exit(render_json(results, run_info));
+    unwrap_or_exit(write_output(&report, output_file));
+
+    i32::from(!results.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use std::time::Duration;
+
+    fn run_info() -> RunInfo<'static> {
+        RunInfo {
+            dbt_version: "1.10.2",
+            project_name: Some("jaffle_shop"),
+            manifest_generated_at: None,
+            duration: Some(Duration::from_millis(1500)),
+        }
+    }
+
+    #[test]
+    fn test_render_json_document() {
+        let results = vec![
+            (
+                RuleResult::new(
+                    &Severity::Warning,
+                    "Model",
+                    "has_tags",
+                    "orders is missing tags",
+                    Some("models/orders.sql".to_string()),
+                )
+                .with_unique_id("model.jaffle_shop.orders"),
+                &Severity::Warning,
+            ),
+            (
+                RuleResult::new(
+                    &Severity::Error,
+                    "Source",
+                    "has_description",
+                    "raw is missing a description.",
+                    None,
+                ),
+                &Severity::Error,
+            ),
+        ];
+
+        let json: Value =
+            serde_json::from_str(&render_json(&results, &run_info()).unwrap()).unwrap();
+
+        assert_eq!(json["schema_version"], JSON_SCHEMA_VERSION);
+        assert_eq!(json["metadata"]["dbt_version"], "1.10.2");
+        assert_eq!(json["metadata"]["project_name"], "jaffle_shop");
+        assert_eq!(json["metadata"]["duration_seconds"], 1.5);
+        assert_eq!(json["summary"]["total"], 2);
+        assert_eq!(json["summary"]["errors"], 1);
+        assert_eq!(json["summary"]["warnings"], 1);
+
+        // Errors are sorted before warnings
+        assert_eq!(json["results"][0]["severity"], "error");
+        assert_eq!(json["results"][0]["relative_path"], Value::Null);
+        assert_eq!(json["results"][1]["severity"], "warning");
+        assert_eq!(json["results"][1]["rule_name"], "has_tags");
+        assert_eq!(json["results"][1]["relative_path"], "models/orders.sql");
+        assert_eq!(json["results"][1]["unique_id"], "model.jaffle_shop.orders");
+    }
+
+    #[test]
+    fn test_render_json_without_findings() {
+        let json: Value = serde_json::from_str(&render_json(&[], &run_info()).unwrap()).unwrap();
+        assert_eq!(json["summary"]["total"], 0);
+        assert_eq!(json["results"], Value::Array(vec![]));
+    }
+
+    #[test]
+    fn test_show_results_as_json_writes_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("report.json").display().to_string();
+        let results = vec![(
+            RuleResult::new(&Severity::Error, "Model", "has_refs", "no refs", None),
+            &Severity::Error,
+        )];
+
+        let exit_code = show_results_as_json(&results, &run_info(), Some(&path));
+
+        assert_eq!(exit_code, 1);
+        let json: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(json["results"][0]["message"], "no refs");
+    }
+}
@@ -0,0 +1,329 @@
+use anyhow::Result;
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::cli::output::{project_path, write_output};
+use crate::core::config::severity::Severity;
+use crate::core::config::Config;
+use crate::core::rules::rule_outcomes::RuleOutcomes;
+use crate::core::utils::unwrap_or_exit;
+
+/// A single evaluation of a rule against an object.
+struct TestCase<'a> {
+    name: &'a str,
+    classname: &'a str,
+    file: Option<String>,
+    line: Option<usize>,
+    outcome: TestCaseOutcome<'a>,
+}
+
+enum TestCaseOutcome<'a> {
+    Passed,
+    Failure(&'a str),
+    Skipped(&'a str),
+}
+
+struct TestSuite<'a> {
+    name: String,
+    rule_type: String,
+    testcases: Vec<TestCase<'a>>,
+}
+
+impl TestSuite<'_> {
+    fn failures(&self) -> usize {
+        self.testcases
+            .iter()
+            .filter(|tc| matches!(tc.outcome, TestCaseOutcome::Failure(_)))
+            .count()
+    }
+
+    fn skipped(&self) -> usize {
+        self.testcases
+            .iter()
+            .filter(|tc| matches!(tc.outcome, TestCaseOutcome::Skipped(_)))
+            .count()
+    }
+}
+
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // Control characters are not allowed in XML 1.0
+            c if c.is_control() && !matches!(c, '\n' | '\r' | '\t') => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Every configured rule becomes a testsuite, rules sharing a name end up in the same suite.
+fn test_suites<'a>(
+    outcomes: &'a RuleOutcomes,
+    config: &Config,
+    entry_point: &str,
+) -> Vec<TestSuite<'a>> {
+    let configured_rules = config
+        .manifest_tests
+        .iter()
+        .flatten()
+        .map(|rule| (rule.get_name(), rule.rule.as_str().to_string()))
+        .chain(
+            config
+                .catalog_tests
+                .iter()
+                .flatten()
+                .map(|rule| (rule.get_name(), rule.rule.as_str().to_string())),
+        )
+        .chain(
+            config
+                .freshness_tests
+                .iter()
+                .flatten()
+                .map(|rule| (rule.get_name(), rule.rule.as_str().to_string())),
+        );
+
+    let mut suites: Vec<TestSuite> = Vec::new();
+    for (name, rule_type) in configured_rules {
+        if suites.iter().any(|suite| suite.name == name) {
+            continue;
+        }
+        suites.push(TestSuite {
+            name,
+            rule_type,
+            testcases: Vec::new(),
+        });
+    }
+
+    let suite_index = |suites: &mut Vec<TestSuite>, rule_name: &str| {
+        suites
+            .iter()
+            .position(|suite| suite.name == rule_name)
+            .unwrap_or_else(|| {
+                suites.push(TestSuite {
+                    name: rule_name.to_string(),
+                    rule_type: rule_name.to_string(),
+                    testcases: Vec::new(),
+                });
+                suites.len() - 1
+            })
+    };
+
+    for pass in &outcomes.passes {
+        let index = suite_index(&mut suites, &pass.rule_name);
+        suites[index].testcases.push(TestCase {
+            name: &pass.unique_id,
+            classname: &pass.object_type,
+            file: pass
+                .relative_path
+                .as_ref()
+                .map(|path| project_path(entry_point, path)),
+            line: None,
+            outcome: TestCaseOutcome::Passed,
+        });
+    }
+
+    for (result, severity) in &outcomes.findings {
+        let index = suite_index(&mut suites, &result.rule_name);
+        suites[index].testcases.push(TestCase {
+            name: result.unique_id.as_deref().unwrap_or(&result.message),
+            classname: &result.object_type,
+            file: result
+                .location_path()
+                .map(|path| project_path(entry_point, path)),
+            line: result.line,
+            outcome: match severity {
+                Severity::Error => TestCaseOutcome::Failure(&result.message),
+                Severity::Warning => TestCaseOutcome::Skipped(&result.message),
+            },
+        });
+    }
+
+    for suite in &mut suites {
+        suite
+            .testcases
+            .sort_by(|a, b| a.classname.cmp(b.classname).then(a.name.cmp(b.name)));
+    }
+    suites
+}
+
+/// Render all rule evaluations as a `JUnit` XML report for CI test dashboards.
+///
+/// Passing evaluations become passed testcases, error findings become failures
+/// and warning findings are reported as skipped testcases.
+/// # Errors
+/// Returns an error if the report cannot be written
+pub fn render_junit(
+    outcomes: &RuleOutcomes,
+    config: &Config,
+    entry_point: &str,
+    duration: Option<Duration>,
+) -> Result<String> {
+    let suites = test_suites(outcomes, config, entry_point);
+    let failures: usize = suites.iter().map(TestSuite::failures).sum();
+    let skipped: usize = suites.iter().map(TestSuite::skipped).sum();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    write!(
+        xml,
+        "<testsuites name=\"dbtective\" tests=\"{}\" failures=\"{failures}\" errors=\"0\" skipped=\"{skipped}\"",
+        outcomes.evaluated()
+    )?;
+    if let Some(duration) = duration {
+        write!(xml, " time=\"{:.3}\"", duration.as_secs_f64())?;
+    }
+    xml.push_str(">\n");
+
+    for suite in &suites {
+        writeln!(
+            xml,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"{}\">",
+            escape_xml(&suite.name),
+            suite.testcases.len(),
+            suite.failures(),
+            suite.skipped(),
+        )?;
+        for testcase in &suite.testcases {
+            write!(
+                xml,
+                "    <testcase name=\"{}\" classname=\"{}\"",
+                escape_xml(testcase.name),
+                escape_xml(testcase.classname),
+            )?;
+            if let Some(file) = &testcase.file {
+                write!(xml, " file=\"{}\"", escape_xml(file))?;
+            }
+            if let Some(line) = testcase.line {
+                write!(xml, " line=\"{line}\"")?;
+            }
+            match testcase.outcome {
+                TestCaseOutcome::Passed => xml.push_str("/>\n"),
+                TestCaseOutcome::Failure(message) => writeln!(
+                    xml,
+                    ">\n      <failure message=\"{message}\" type=\"{}\">{message}</failure>\n    </testcase>",
+                    escape_xml(&suite.rule_type),
+                    message = escape_xml(message),
+                )?,
+                TestCaseOutcome::Skipped(message) => writeln!(
+                    xml,
+                    ">\n      <skipped message=\"{}\"/>\n    </testcase>",
+                    escape_xml(message),
+                )?,
+            }
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>");
+
+    Ok(xml)
+}
+
+pub fn show_results_as_junit(
+    outcomes: &RuleOutcomes,
+    config: &Config,
+    entry_point: &str,
+    duration: Option<Duration>,
+    output_file: Option<&String>,
+) -> i32 {
+    let report = unwrap_or_exit(render_junit(outcomes, config, entry_point, duration));
+    unwrap_or_exit(write_output(&report, output_file));
+
+    i32::from(!outcomes.findings.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::table::RuleResult;
+    use crate::core::rules::rule_outcomes::RulePass;
+    use std::collections::BTreeMap;
+
+    fn config() -> Config {
+        let mut config: Config = serde_yaml::from_str(
+            r#"
+manifest_tests:
+  - name: "models_have_description"
+    type: "has_description"
+  - type: "has_refs"
+    severity: "warning"
+"#,
+        )
+        .unwrap();
+        config.clean_config();
+        config
+    }
+
+    #[test]
+    fn test_escape_xml() {
+        assert_eq!(
+            escape_xml("a < b & \"c\" > 'd'"),
+            "a &lt; b &amp; &quot;c&quot; &gt; &apos;d&apos;"
+        );
+        assert_eq!(escape_xml("bell\u{7}"), "bell");
+    }
+
+    #[test]
+    fn test_render_junit() {
+        let outcomes = RuleOutcomes {
+            findings: vec![
+                (
+                    RuleResult::new(
+                        &Severity::Error,
+                        "Model",
+                        "models_have_description",
+                        "orders is missing a description.",
+                        Some("models/orders.sql".to_string()),
+                    )
+                    .with_unique_id("model.jaffle_shop.orders"),
+                    &Severity::Error,
+                ),
+                (
+                    RuleResult::new(
+                        &Severity::Warning,
+                        "Model",
+                        "has_refs",
+                        "orders <has> no refs",
+                        Some("models/orders.sql".to_string()),
+                    )
+                    .with_unique_id("model.jaffle_shop.orders"),
+                    &Severity::Warning,
+                ),
+            ],
+            passes: vec![RulePass::new(
+                "models_have_description",
+                "Model",
+                "customers",
+                "model.jaffle_shop.customers",
+                Some("models/customers.sql".to_string()),
+            )],
+            suppressed: vec![],
+            column_coverage: BTreeMap::new(),
+        };
+
+        let xml = render_junit(&outcomes, &config(), "dbt_project", None).unwrap();
+
+        assert!(xml.contains(
+            "<testsuites name=\"dbtective\" tests=\"3\" failures=\"1\" errors=\"0\" skipped=\"1\">"
+        ));
+        assert!(xml.contains(
+            "<testsuite name=\"models_have_description\" tests=\"2\" failures=\"1\" errors=\"0\" skipped=\"0\">"
+        ));
+        assert!(xml.contains(
+            "<testcase name=\"model.jaffle_shop.customers\" classname=\"Model\" file=\"dbt_project/models/customers.sql\"/>"
+        ));
+        assert!(xml.contains(
+            "<failure message=\"orders is missing a description.\" type=\"has_description\">"
+        ));
+        assert!(xml.contains("<skipped message=\"orders &lt;has&gt; no refs\"/>"));
+        // The passing testcase is sorted before the failing one
+        assert!(
+            xml.find("model.jaffle_shop.customers").unwrap()
+                < xml.find("model.jaffle_shop.orders").unwrap()
+        );
+    }
+}
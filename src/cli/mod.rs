@@ -1,2 +1,7 @@
 pub mod commands;
+pub mod github;
+pub mod json;
+pub mod junit;
+pub mod output;
+pub mod sarif;
 pub mod table;
@@ -0,0 +1,66 @@
+use anyhow::{Context, Result};
+use std::time::Duration;
+
+/// Information about the analysed project and the run itself,
+/// shared by all machine-readable output formats.
+#[derive(Debug, Clone, Default)]
+pub struct RunInfo<'a> {
+    pub dbt_version: &'a str,
+    pub project_name: Option<&'a str>,
+    pub manifest_generated_at: Option<&'a str>,
+    pub duration: Option<Duration>,
+}
+
+/// Write a rendered report to `output_file`, or to stdout if no file is given.
+/// # Errors
+/// Returns an error if the output file cannot be written
+pub fn write_output(contents: &str, output_file: Option<&String>) -> Result<()> {
+    output_file.map_or_else(
+        || {
+            println!("{contents}");
+            Ok(())
+        },
+        |path| {
+            std::fs::write(path, contents).context(format!("Unable to write output file at {path}"))
+        },
+    )
+}
+
+/// Path of a dbt project file relative to the directory dbtective was started from.
+/// CI integrations resolve paths from the repository root, which is not necessarily the dbt project root.
+pub fn project_path(entry_point: &str, relative_path: &str) -> String {
+    let entry = entry_point.trim_start_matches("./").trim_end_matches('/');
+    let path = relative_path
+        .replace('\\', "/")
+        .trim_start_matches("./")
+        .trim_start_matches('/')
+        .to_string();
+
+    if entry.is_empty() || entry == "." {
+        path
+    } else {
+        format!("{entry}/{path}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_project_path() {
+        assert_eq!(project_path(".", "models/orders.sql"), "models/orders.sql");
+        assert_eq!(
+            project_path("./", "./models/orders.sql"),
+            "models/orders.sql"
+        );
+        assert_eq!(
+            project_path("dbt_project/", "/models/orders.sql"),
+            "dbt_project/models/orders.sql"
+        );
+        assert_eq!(
+            project_path("./dbt_project", "models\\staging\\stg_orders.sql"),
+            "dbt_project/models/staging/stg_orders.sql"
+        );
+    }
+}
@@ -0,0 +1,461 @@
+use anyhow::Result;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::cli::output::{project_path, write_output};
+use crate::cli::table::{sort_results, RuleResult};
+use crate::core::config::changed_files::repository_root;
+use crate::core::config::severity::Severity;
+use crate::core::config::Config;
+use crate::core::utils::unwrap_or_exit;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+const RULES_DOCS_URI: &str = "https://feliblo.github.io/dbtective/docs/rules/";
+// Artifact locations are relative to the root of the repository
+const SRCROOT: &str = "%SRCROOT%";
+
+#[derive(Debug, Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<SarifRun>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRun {
+    tool: SarifTool,
+    original_uri_base_ids: SarifUriBaseIds,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifUriBaseIds {
+    #[serde(rename = "%SRCROOT%")]
+    src_root: SarifUri,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifUri {
+    uri: String,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<SarifRuleDescriptor>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRuleDescriptor {
+    id: String,
+    name: String,
+    short_description: SarifMessage,
+    help_uri: &'static str,
+    default_configuration: SarifConfiguration,
+    properties: SarifRuleProperties,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifRuleProperties {
+    #[serde(rename = "type")]
+    rule_type: String,
+    category: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifConfiguration {
+    level: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: &'static str,
+    message: SarifMessage,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    locations: Vec<SarifLocation>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    region: SarifRegion,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactLocation {
+    uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uri_base_id: Option<&'static str>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_column: Option<usize>,
+}
+
+const fn sarif_level(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    }
+}
+
+// Canonical paths resolve `..` and symlinks, so they can be compared with the repository root given by git
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize()
+        .or_else(|_| std::path::absolute(path))
+        .unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// `file` URI of a directory, SARIF requires base URIs to end with a slash
+fn directory_uri(path: &Path) -> String {
+    let path = path
+        .to_string_lossy()
+        .replace('\\', "/")
+        .replace(' ', "%20");
+    // Canonical Windows paths are verbatim paths, e.g. `\\?\C:\repo`
+    let path = path.trim_start_matches("//?/").trim_end_matches('/');
+    let separator = if path.starts_with('/') { "" } else { "/" };
+    format!("file://{separator}{path}/")
+}
+
+/// Every configured rule becomes a SARIF rule descriptor, keyed on the rule name.
+/// Rules sharing a name are only described once, the first definition wins.
+fn rule_descriptors(config: &Config) -> Vec<SarifRuleDescriptor> {
+    let manifest_rules = config.manifest_tests.iter().flatten().map(|rule| {
+        (
+            rule.get_name(),
+            rule.rule.as_str().to_string(),
+            rule.description.as_ref(),
+            &rule.severity,
+            "manifest",
+        )
+    });
+    let catalog_rules = config.catalog_tests.iter().flatten().map(|rule| {
+        (
+            rule.get_name(),
+            rule.rule.as_str().to_string(),
+            rule.description.as_ref(),
+            &rule.severity,
+            "catalog",
+        )
+    });
+    let freshness_rules = config.freshness_tests.iter().flatten().map(|rule| {
+        (
+            rule.get_name(),
+            rule.rule.as_str().to_string(),
+            rule.description.as_ref(),
+            &rule.severity,
+            "freshness",
+        )
+    });
+
+    let mut descriptors: Vec<SarifRuleDescriptor> = Vec::new();
+    for (name, rule_type, description, severity, category) in
+        manifest_rules.chain(catalog_rules).chain(freshness_rules)
+    {
+        if descriptors.iter().any(|d| d.id == name) {
+            continue;
+        }
+        descriptors.push(SarifRuleDescriptor {
+            short_description: SarifMessage {
+                text: description.cloned().unwrap_or_else(|| rule_type.clone()),
+            },
+            id: name,
+            name: rule_type.clone(),
+            help_uri: RULES_DOCS_URI,
+            default_configuration: SarifConfiguration {
+                level: sarif_level(severity),
+            },
+            properties: SarifRuleProperties {
+                rule_type,
+                category,
+            },
+        });
+    }
+    descriptors
+}
+
+/// Render all findings as a SARIF 2.1.0 log, e.g. for GitHub code scanning.
+///
+/// Artifact locations are relative to `source_root` (the `%SRCROOT%` base),
+/// so the location of the dbt project in it is prepended to the paths of the project.
+/// Projects outside of `source_root` get absolute `file` URIs.
+/// # Errors
+/// Returns an error if the log cannot be serialized
+pub fn render_sarif(
+    results: &[(RuleResult, &Severity)],
+    config: &Config,
+    entry_point: &str,
+    source_root: &Path,
+) -> Result<String> {
+    let source_root = canonical(source_root);
+    let project_dir = canonical(Path::new(entry_point));
+    let (project_dir, uri_base_id) = project_dir.strip_prefix(&source_root).map_or_else(
+        |_| (directory_uri(&project_dir), None),
+        |relative| (relative.to_string_lossy().replace('\\', "/"), Some(SRCROOT)),
+    );
+
+    let rules = rule_descriptors(config);
+    let rule_indices: HashMap<&str, usize> = rules
+        .iter()
+        .enumerate()
+        .map(|(index, rule)| (rule.id.as_str(), index))
+        .collect();
+
+    let sarif_results = sort_results(results)
+        .into_iter()
+        .map(|(result, severity)| SarifResult {
+            rule_id: result.rule_name.clone(),
+            rule_index: rule_indices.get(result.rule_name.as_str()).copied(),
+            level: sarif_level(severity),
+            message: SarifMessage {
+                text: result.message.clone(),
+            },
+            locations: result
+                .location_path()
+                .map(|path| SarifLocation {
+                    physical_location: SarifPhysicalLocation {
+                        artifact_location: SarifArtifactLocation {
+                            uri: project_path(&project_dir, path).replace(' ', "%20"),
+                            uri_base_id,
+                        },
+                        region: SarifRegion {
+                            start_line: result.line.unwrap_or(1),
+                            start_column: result.column,
+                        },
+                    },
+                })
+                .into_iter()
+                .collect(),
+        })
+        .collect();
+
+    let log = SarifLog {
+        schema: SARIF_SCHEMA,
+        version: SARIF_VERSION,
+        runs: vec![SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver {
+                    name: "dbtective",
+                    version: env!("CARGO_PKG_VERSION"),
+                    information_uri: "https://feliblo.github.io/dbtective/",
+                    rules,
+                },
+            },
+            original_uri_base_ids: SarifUriBaseIds {
+                src_root: SarifUri {
+                    uri: directory_uri(&source_root),
+                },
+            },
+            results: sarif_results,
+        }],
+    };
+
+    Ok(serde_json::to_string_pretty(&log)?)
+}
+
+pub fn show_results_as_sarif(
+    results: &[(RuleResult, &Severity)],
+    config: &Config,
+    entry_point: &str,
+    output_file: Option<&String>,
+) -> i32 {
+    // Outside of a git repository, paths are relative to the directory dbtective was started from
+    let source_root = repository_root(entry_point)
+        .or_else(|| std::env::current_dir().ok())
+        .unwrap_or_else(|| PathBuf::from("."));
+    let log = unwrap_or_exit(render_sarif(results, config, entry_point, &source_root));
+    unwrap_or_exit(write_output(&log, output_file));
+
+    i32::from(!results.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    fn config() -> Config {
+        let mut config: Config = serde_yaml::from_str(
+            r#"
+manifest_tests:
+  - name: "models_have_description"
+    type: "has_description"
+    description: "Models must be documented."
+  - type: "has_refs"
+    severity: "warning"
+catalog_tests:
+  - type: "columns_all_documented"
+"#,
+        )
+        .unwrap();
+        config.clean_config();
+        config
+    }
+
+    #[test]
+    fn test_rule_descriptors_from_config() {
+        let results = vec![];
+        let sarif: Value =
+            serde_json::from_str(&render_sarif(&results, &config(), ".", Path::new(".")).unwrap())
+                .unwrap();
+
+        assert_eq!(sarif["version"], "2.1.0");
+        let rules = &sarif["runs"][0]["tool"]["driver"]["rules"];
+        assert_eq!(rules.as_array().unwrap().len(), 3);
+        assert_eq!(rules[0]["id"], "models_have_description");
+        assert_eq!(rules[0]["name"], "has_description");
+        assert_eq!(
+            rules[0]["shortDescription"]["text"],
+            "Models must be documented."
+        );
+        assert_eq!(rules[0]["defaultConfiguration"]["level"], "error");
+        assert_eq!(rules[1]["id"], "has_refs");
+        assert_eq!(rules[1]["defaultConfiguration"]["level"], "warning");
+        assert_eq!(rules[2]["properties"]["category"], "catalog");
+    }
+
+    #[test]
+    fn test_results_with_locations() {
+        let results = vec![
+            (
+                RuleResult::new(
+                    &Severity::Warning,
+                    "Model",
+                    "has_refs",
+                    "orders does not have any upstream references (refs/sources)",
+                    Some("models/orders.sql".to_string()),
+                )
+                .with_location(12, None),
+                &Severity::Warning,
+            ),
+            (
+                RuleResult::new(
+                    &Severity::Error,
+                    "Model",
+                    "models_have_description",
+                    "orders is missing a description.",
+                    None,
+                ),
+                &Severity::Error,
+            ),
+        ];
+
+        let sarif: Value = serde_json::from_str(
+            &render_sarif(&results, &config(), "./dbt_project/", Path::new(".")).unwrap(),
+        )
+        .unwrap();
+        let sarif_results = &sarif["runs"][0]["results"];
+
+        assert_eq!(sarif_results[0]["ruleId"], "models_have_description");
+        assert_eq!(sarif_results[0]["ruleIndex"], 0);
+        assert_eq!(sarif_results[0]["level"], "error");
+        assert!(sarif_results[0].get("locations").is_none());
+
+        assert_eq!(sarif_results[1]["ruleIndex"], 1);
+        assert_eq!(sarif_results[1]["level"], "warning");
+        assert_eq!(
+            sarif_results[1]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "dbt_project/models/orders.sql"
+        );
+        assert_eq!(
+            sarif_results[1]["locations"][0]["physicalLocation"]["artifactLocation"]["uriBaseId"],
+            "%SRCROOT%"
+        );
+        assert_eq!(
+            sarif_results[1]["locations"][0]["physicalLocation"]["region"]["startLine"],
+            12
+        );
+    }
+
+    #[test]
+    fn test_locations_relative_to_source_root() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let project = temp_dir.path().join("dbt project");
+        std::fs::create_dir(&project).unwrap();
+        let results = vec![(
+            RuleResult::new(
+                &Severity::Error,
+                "Model",
+                "has_refs",
+                "orders does not have any upstream references (refs/sources)",
+                Some("models/orders.sql".to_string()),
+            ),
+            &Severity::Error,
+        )];
+        let location = |entry_point: &Path, source_root: &Path| -> Value {
+            let sarif: Value = serde_json::from_str(
+                &render_sarif(
+                    &results,
+                    &config(),
+                    entry_point.to_str().unwrap(),
+                    source_root,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            sarif["runs"][0]["results"][0]["locations"][0]["physicalLocation"]["artifactLocation"]
+                .clone()
+        };
+
+        // An absolute entry point inside the source root
+        let inside = location(&project, temp_dir.path());
+        assert_eq!(inside["uri"], "dbt%20project/models/orders.sql");
+        assert_eq!(inside["uriBaseId"], "%SRCROOT%");
+
+        // A project outside of the source root keeps an absolute path
+        let outside = location(temp_dir.path(), &project);
+        assert!(outside["uri"].as_str().unwrap().starts_with("file:///"));
+        assert!(outside["uri"]
+            .as_str()
+            .unwrap()
+            .ends_with("/models/orders.sql"));
+        assert!(outside.get("uriBaseId").is_none());
+    }
+
+    #[test]
+    fn test_original_uri_base_ids() {
+        let sarif: Value = serde_json::from_str(
+            &render_sarif(&[], &config(), ".", Path::new("/repo/root")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            sarif["runs"][0]["originalUriBaseIds"]["%SRCROOT%"]["uri"],
+            "file:///repo/root/"
+        );
+    }
+}
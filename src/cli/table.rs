@@ -22,6 +22,17 @@ pub struct RuleResult {
     pub message: String,
     #[tabled(skip)]
     pub relative_path: Option<String>,
+    #[tabled(skip)]
+    pub unique_id: Option<String>,
+    /// YAML property file the finding belongs to, relative to the project root
+    #[tabled(skip)]
+    pub patch_path: Option<String>,
+    /// 1-based line in the file the finding points at, see [`RuleResult::location_path`]
+    #[tabled(skip)]
+    pub line: Option<usize>,
+    /// 1-based column in the file the finding points at
+    #[tabled(skip)]
+    pub column: Option<usize>,
 }
 
 impl RuleResult {
@@ -39,6 +50,55 @@ impl RuleResult {
             rule_name: rule_name.into(),
             message: message.into(),
             relative_path,
+            unique_id: None,
+            patch_path: None,
+            line: None,
+            column: None,
+        }
+    }
+
+    /// Attach the `unique_id` of the dbt object the finding belongs to.
+    /// Set by the rule appliers, since the rule checks themselves only see trait objects.
+    #[must_use]
+    pub fn with_unique_id(mut self, unique_id: impl Into<String>) -> Self {
+        self.unique_id = Some(unique_id.into());
+        self
+    }
+
+    /// Attach the YAML property file of the object, for findings on properties defined in it.
+    #[must_use]
+    pub fn with_patch_path(mut self, patch_path: Option<&str>) -> Self {
+        self.patch_path = patch_path.map(ToString::to_string);
+        self
+    }
+
+    /// Attach the YAML property file only for rules that check properties defined in it.
+    #[must_use]
+    pub fn with_patch_path_if(
+        self,
+        checks_yaml_properties: bool,
+        patch_path: Option<&str>,
+    ) -> Self {
+        if checks_yaml_properties {
+            self.with_patch_path(patch_path)
+        } else {
+            self
+        }
+    }
+
+    /// Attach the line (and column) in the file the finding points at.
+    #[must_use]
+    pub const fn with_location(mut self, line: usize, column: Option<usize>) -> Self {
+        self.line = Some(line);
+        self.column = column;
+        self
+    }
+
+    /// File the finding points at: the YAML property file for findings on properties, otherwise the file of the object.
+    pub const fn location_path(&self) -> Option<&String> {
+        match self.patch_path {
+            Some(ref patch_path) => Some(patch_path),
+            None => self.relative_path.as_ref(),
         }
     }
 }
@@ -68,8 +128,8 @@ pub fn show_results_and_exit(
                 if disable_hyperlinks {
                     return new_row;
                 }
-                // Add file hyperlinks to message if relative_path is present
-                if let Some(ref path) = row.relative_path {
+                // Add file hyperlinks to message if the finding points at a file
+                if let Some(path) = row.location_path() {
                     let entry = entry_point.trim_end_matches('/');
                     let path = path.trim_start_matches('/');
                     let full_path = format!("{entry}/{path}");
@@ -92,6 +152,11 @@ pub fn show_results_and_exit(
                     } else {
                         format!("file://{abs_path}")
                     };
+                    // Terminals that support it (e.g. kitty) open the file at the line in the fragment
+                    let file_url = match row.line {
+                        Some(line) => format!("{file_url}#{line}"),
+                        None => file_url,
+                    };
 
                     new_row.message = format!(
                         "\x1b]8;;{url}\x1b\\{text}\x1b]8;;\x1b\\",
@@ -135,7 +200,8 @@ fn get_terminal_size() -> (usize, usize) {
 }
 
 /// Sort results by severity (FAIL before WARN), then by `object_type`, then by `rule_name`
-fn sort_results<'a>(
+/// Remaining ties are broken on path and message, so the order is stable between runs.
+pub fn sort_results<'a>(
     results: &'a [(RuleResult, &'a Severity)],
 ) -> Vec<&'a (RuleResult, &'a Severity)> {
     let mut sorted: Vec<_> = results.iter().collect();
@@ -146,6 +212,8 @@ fn sort_results<'a>(
             .reverse()
             .then_with(|| a.object_type.cmp(&b.object_type))
             .then_with(|| a.rule_name.cmp(&b.rule_name))
+            .then_with(|| a.relative_path.cmp(&b.relative_path))
+            .then_with(|| a.message.cmp(&b.message))
     });
     sorted
 }
@@ -0,0 +1,387 @@
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use crate::cli::commands::BaselineOptions;
+use crate::cli::table::RuleResult;
+use crate::core::config::severity::Severity;
+use crate::core::run::{evaluate_rules, load_artifacts};
+use crate::core::utils::unwrap_or_exit;
+
+/// Version of the baseline file layout.
+pub const BASELINE_VERSION: u32 = 1;
+
+/// Findings that existed when the baseline was created.
+/// Findings in the baseline are not reported by `dbtective run --baseline <file>`.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Baseline {
+    pub version: u32,
+    pub findings: Vec<BaselineEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BaselineEntry {
+    pub rule_name: String,
+    pub unique_id: String,
+    pub fingerprint: String,
+    /// Only stored to make the baseline file readable, matching uses the fingerprint
+    #[serde(default)]
+    pub message: String,
+}
+
+impl BaselineEntry {
+    fn from_result(result: &RuleResult) -> Self {
+        Self {
+            rule_name: result.rule_name.clone(),
+            unique_id: result.unique_id.clone().unwrap_or_default(),
+            fingerprint: fingerprint(&result.message),
+            message: result.message.clone(),
+        }
+    }
+
+    fn into_key(self) -> (String, String, String) {
+        (self.rule_name, self.unique_id, self.fingerprint)
+    }
+}
+
+/// Stable (FNV-1a) hash of a finding message, identical across platforms and releases.
+/// The standard library hasher is randomly seeded, so it cannot be used for files stored in git.
+///
+/// Numbers are left out, they change while the issue remains (e.g. line counts, chain depths, coverage).
+/// Anything else that changes, such as a newly untested column, makes it a new finding.
+fn fingerprint(message: &str) -> String {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+    let hash = message
+        .bytes()
+        .filter(|byte| !byte.is_ascii_digit())
+        .fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+        });
+    format!("{hash:016x}")
+}
+
+/// Result of comparing the findings of a run against a baseline
+#[derive(Debug, PartialEq, Eq)]
+pub struct BaselineComparison {
+    /// Findings that were already in the baseline
+    pub suppressed: usize,
+    /// Baseline entries of evaluated objects that no longer occur
+    pub fixed: usize,
+}
+
+impl BaselineComparison {
+    pub fn report(&self, baseline_file: &str) {
+        // Printed to stderr, so machine-readable output on stdout stays valid
+        eprintln!(
+            "{} {} existing issue(s) suppressed by {baseline_file}, {} fixed since the baseline was created.",
+            "Baseline:".yellow().bold(),
+            self.suppressed,
+            self.fixed,
+        );
+        if self.fixed > 0 {
+            eprintln!(
+                "Run `dbtective baseline` to remove the fixed issues from {baseline_file}, so they cannot return unnoticed."
+            );
+        }
+    }
+}
+
+impl Baseline {
+    pub fn from_findings(findings: &[(RuleResult, &Severity)]) -> Self {
+        let mut entries: Vec<BaselineEntry> = findings
+            .iter()
+            .map(|(result, _)| BaselineEntry::from_result(result))
+            .collect();
+        entries.sort();
+
+        Self {
+            version: BASELINE_VERSION,
+            findings: entries,
+        }
+    }
+
+    /// # Errors
+    /// Returns an error if the file cannot be read or is not a valid baseline
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).context(format!(
+            "Unable to read baseline file at {}",
+            path.display()
+        ))?;
+        let baseline: Self = serde_json::from_str(&contents).context(format!(
+            "Unable to parse baseline file at {}",
+            path.display()
+        ))?;
+
+        if baseline.version > BASELINE_VERSION {
+            anyhow::bail!(
+                "Baseline file at {} has version {}, this version of dbtective supports up to version {BASELINE_VERSION}. Please upgrade dbtective.",
+                path.display(),
+                baseline.version
+            );
+        }
+        Ok(baseline)
+    }
+
+    /// # Errors
+    /// Returns an error if the file cannot be written
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let contents = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, contents + "\n").context(format!(
+            "Unable to write baseline file at {}",
+            path.display()
+        ))
+    }
+
+    /// Remove all findings that are part of the baseline.
+    /// Every baseline entry suppresses at most one finding, so duplicated findings are still reported.
+    /// Only entries of objects in `evaluated` count as fixed, objects outside the selection
+    /// (e.g. `--changed-since`, `--state` or `--select`) were not checked this run.
+    pub fn filter_findings(
+        &self,
+        findings: &mut Vec<(RuleResult, &Severity)>,
+        evaluated: &HashSet<String>,
+    ) -> BaselineComparison {
+        let mut remaining: HashMap<(String, String, String), usize> = HashMap::new();
+        for entry in &self.findings {
+            *remaining.entry(entry.clone().into_key()).or_default() += 1;
+        }
+
+        let before = findings.len();
+        findings.retain(|(result, _)| {
+            match remaining.get_mut(&BaselineEntry::from_result(result).into_key()) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    false
+                }
+                _ => true,
+            }
+        });
+
+        BaselineComparison {
+            suppressed: before - findings.len(),
+            fixed: remaining
+                .iter()
+                .filter(|((_, unique_id, _), _)| evaluated.contains(unique_id))
+                .map(|(_, count)| count)
+                .sum(),
+        }
+    }
+}
+
+#[must_use]
+pub fn baseline(options: &BaselineOptions, verbose: bool) -> i32 {
+    let artifacts = load_artifacts(
+        &options.entry_point,
+        options.config_file.as_ref(),
+        &options.manifest_file,
+        &options.catalog_file,
+        &options.sources_file,
+        options.only_manifest,
+    );
+    let outcomes = evaluate_rules(&artifacts, &options.entry_point, verbose);
+
+    let baseline = Baseline::from_findings(&outcomes.findings);
+    unwrap_or_exit(baseline.write(&options.output));
+
+    println!(
+        "{} Wrote {} existing issue(s) to {}",
+        "✓".green(),
+        baseline.findings.len(),
+        options.output
+    );
+    println!(
+        "Run `dbtective run --baseline {}` to only fail on new issues.",
+        options.output
+    );
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finding(rule_name: &str, unique_id: &str, message: &str) -> (RuleResult, &'static Severity) {
+        (
+            RuleResult::new(&Severity::Error, "Model", rule_name, message, None)
+                .with_unique_id(unique_id),
+            &Severity::Error,
+        )
+    }
+
+    fn evaluated(unique_ids: &[&str]) -> HashSet<String> {
+        unique_ids.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_fingerprint_is_stable() {
+        assert_eq!(fingerprint(""), "cbf29ce484222325");
+        assert_eq!(fingerprint("a"), "af63dc4c8601ec8c");
+        assert_ne!(
+            fingerprint("orders is missing a description."),
+            fingerprint("customers is missing a description.")
+        );
+        assert_eq!(
+            fingerprint("orders has a chain depth of 5"),
+            fingerprint("orders has a chain depth of 12")
+        );
+    }
+
+    #[test]
+    fn test_filter_findings() {
+        let baseline = Baseline::from_findings(&[
+            finding(
+                "has_description",
+                "model.p.orders",
+                "orders is missing a description.",
+            ),
+            finding(
+                "has_description",
+                "model.p.fixed",
+                "fixed is missing a description.",
+            ),
+            finding("has_refs", "model.p.orders", "orders has no refs"),
+        ]);
+
+        let mut findings = vec![
+            finding(
+                "has_description",
+                "model.p.orders",
+                "orders is missing a description.",
+            ),
+            // Same rule and object, but a different message is a new finding
+            finding("has_refs", "model.p.orders", "orders has other refs"),
+            finding(
+                "has_description",
+                "model.p.new",
+                "new is missing a description.",
+            ),
+        ];
+        let comparison = baseline.filter_findings(
+            &mut findings,
+            &evaluated(&["model.p.orders", "model.p.fixed", "model.p.new"]),
+        );
+
+        assert_eq!(
+            comparison,
+            BaselineComparison {
+                suppressed: 1,
+                fixed: 2
+            }
+        );
+        let remaining: Vec<&str> = findings.iter().map(|(r, _)| r.message.as_str()).collect();
+        assert_eq!(
+            remaining,
+            vec!["orders has other refs", "new is missing a description."]
+        );
+    }
+
+    #[test]
+    fn test_changed_message_stays_suppressed() {
+        let baseline = Baseline::from_findings(&[finding(
+            "max_code_lines",
+            "model.p.orders",
+            "orders has 160 lines of code which exceeds the maximum allowed of 150 lines.",
+        )]);
+        let mut findings = vec![finding(
+            "max_code_lines",
+            "model.p.orders",
+            "orders has 175 lines of code which exceeds the maximum allowed of 150 lines.",
+        )];
+
+        let comparison = baseline.filter_findings(&mut findings, &evaluated(&["model.p.orders"]));
+        assert_eq!(
+            comparison,
+            BaselineComparison {
+                suppressed: 1,
+                fixed: 0
+            }
+        );
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn test_new_problem_on_baselined_object_is_reported() {
+        let baseline = Baseline::from_findings(&[finding(
+            "has_relationship_test",
+            "model.p.orders",
+            "orders has no relationships test on customer_id",
+        )]);
+        let mut findings = vec![finding(
+            "has_relationship_test",
+            "model.p.orders",
+            "orders has no relationships test on customer_id, store_id",
+        )];
+
+        let comparison = baseline.filter_findings(&mut findings, &evaluated(&["model.p.orders"]));
+        assert_eq!(comparison.suppressed, 0);
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn test_unevaluated_entries_are_not_fixed() {
+        let baseline = Baseline::from_findings(&[
+            finding("has_refs", "model.p.changed", "changed has no refs"),
+            finding("has_refs", "model.p.unchanged", "unchanged has no refs"),
+        ]);
+        // Only `changed` was evaluated (e.g. with --changed-since), and it has been fixed
+        let mut findings = vec![];
+
+        let comparison = baseline.filter_findings(&mut findings, &evaluated(&["model.p.changed"]));
+        assert_eq!(
+            comparison,
+            BaselineComparison {
+                suppressed: 0,
+                fixed: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_duplicate_findings_are_suppressed_once() {
+        let baseline = Baseline::from_findings(&[finding("r", "model.p.a", "m")]);
+        let mut findings = vec![
+            finding("r", "model.p.a", "m"),
+            finding("r", "model.p.a", "m"),
+        ];
+
+        let comparison = baseline.filter_findings(&mut findings, &evaluated(&["model.p.a"]));
+        assert_eq!(comparison.suppressed, 1);
+        assert_eq!(comparison.fixed, 0);
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn test_write_and_read_baseline() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("baseline.json");
+        let baseline = Baseline::from_findings(&[
+            finding("has_refs", "model.p.b", "b has no refs"),
+            finding(
+                "has_description",
+                "model.p.a",
+                "a is missing a description.",
+            ),
+        ]);
+
+        baseline.write(&path).unwrap();
+        let read = Baseline::from_file(&path).unwrap();
+        assert_eq!(read, baseline);
+        // Entries are sorted, so the file does not change between runs
+        assert_eq!(read.findings[0].rule_name, "has_description");
+    }
+
+    #[test]
+    fn test_newer_baseline_version_is_rejected() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("baseline.json");
+        std::fs::write(&path, r#"{"version": 99, "findings": []}"#).unwrap();
+
+        assert!(Baseline::from_file(&path).is_err());
+    }
+}
@@ -117,6 +117,46 @@ pub struct AppliesTo {
     pub custom_objects: Vec<RuleTarget>,
 }
 
+impl AppliesTo {
+    /// Validate that all targets are part of the `options` of the rule type
+    /// # Errors
+    /// Returns an error listing the targets that are not valid for the rule type
+    pub fn validate_against(&self, options: &Self, rule_type: &str) -> anyhow::Result<()> {
+        // All applies to that are nodes get the Node target here
+        // All other applies to get their own target type
+        let pairs = [
+            (&self.node_objects, &options.node_objects),
+            (&self.source_objects, &options.source_objects),
+            (&self.unit_test_objects, &options.unit_test_objects),
+            (&self.macro_objects, &options.macro_objects),
+            (&self.exposure_objects, &options.exposure_objects),
+            (
+                &self.semantic_model_objects,
+                &options.semantic_model_objects,
+            ),
+            (&self.custom_objects, &options.custom_objects),
+        ];
+        let invalid_targets: Vec<&str> = pairs
+            .iter()
+            .flat_map(|(targets, valid)| targets.iter().filter(|target| !valid.contains(target)))
+            .map(RuleTarget::as_snake_case)
+            .collect();
+
+        if !invalid_targets.is_empty() {
+            let valid_options: Vec<String> = pairs
+                .iter()
+                .flat_map(|(_, valid)| valid.iter().map(|t| t.as_snake_case().to_string()))
+                .collect();
+
+            anyhow::bail!(
+                "Invalid applies_to targets: {invalid_targets:?} for rule type '{rule_type}'. Valid options are: {valid_options:?}"
+            );
+        }
+
+        Ok(())
+    }
+}
+
 impl<'de> Deserialize<'de> for AppliesTo {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
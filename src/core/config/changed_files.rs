@@ -0,0 +1,177 @@
+use anyhow::{Context, Result};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::core::config::includes_excludes::IncludeExcludable;
+
+/// Files changed since a git ref, relative to the dbt project root.
+/// Used by `--changed-since` to only evaluate rules against objects defined in these files.
+#[derive(Debug, Default, Clone)]
+pub struct ChangedFiles {
+    paths: HashSet<String>,
+}
+
+fn normalize(path: &str) -> String {
+    path.trim().replace('\\', "/")
+}
+
+fn git(entry_point: &str, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(entry_point)
+        .output()
+        .context("Unable to run `git`, is it installed and on the PATH?")?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "`git {}` failed in {entry_point}: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Root of the git repository the entry point is in, `None` outside of a repository or without `git`
+pub fn repository_root(entry_point: &str) -> Option<PathBuf> {
+    git(entry_point, &["rev-parse", "--show-toplevel"])
+        .ok()
+        .map(|root| PathBuf::from(root.trim()))
+}
+
+impl ChangedFiles {
+    /// Collect the files changed between the merge base of `git_ref` and the working tree,
+    /// including untracked files. Paths outside of the dbt project are ignored.
+    ///
+    /// # Errors
+    /// Returns an error if `git` cannot be run, the entry point is not in a git repository or the ref is unknown
+    pub fn from_git(entry_point: &str, git_ref: &str) -> Result<Self> {
+        if !Path::new(entry_point).is_dir() {
+            anyhow::bail!("Entry point {entry_point} is not a directory");
+        }
+
+        // `--relative` makes the paths relative to the entry point, like the paths in the manifest
+        let diff = git(
+            entry_point,
+            &["diff", "--name-only", "--relative", "--merge-base", git_ref],
+        )?;
+        let untracked = git(entry_point, &["ls-files", "--others", "--exclude-standard"])?;
+
+        Ok(Self::from_paths(diff.lines().chain(untracked.lines())))
+    }
+
+    pub fn from_paths<'a, I: IntoIterator<Item = &'a str>>(paths: I) -> Self {
+        Self {
+            paths: paths
+                .into_iter()
+                .filter(|path| !path.trim().is_empty())
+                .map(normalize)
+                .collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.paths.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    /// Whether the file of the object, or the YAML file its properties are defined in, changed
+    pub fn contains<T: IncludeExcludable>(&self, object: &T) -> bool {
+        self.paths.contains(&normalize(object.get_relative_path()))
+            || object
+                .get_schema_path()
+                .is_some_and(|path| self.paths.contains(&normalize(path)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestObject {
+        path: String,
+        schema_path: Option<&'static str>,
+    }
+
+    impl IncludeExcludable for TestObject {
+        fn get_relative_path(&self) -> &String {
+            &self.path
+        }
+
+        fn get_unique_id(&self) -> &String {
+            &self.path
+        }
+
+        fn get_schema_path(&self) -> Option<&str> {
+            self.schema_path
+        }
+    }
+
+    fn object(path: &str, schema_path: Option<&'static str>) -> TestObject {
+        TestObject {
+            path: path.to_string(),
+            schema_path,
+        }
+    }
+
+    #[test]
+    fn test_contains_changed_file_or_schema() {
+        let changed = ChangedFiles::from_paths("models/orders.sql\nmodels\\schema.yml\n\n".lines());
+        assert_eq!(changed.len(), 2);
+
+        assert!(changed.contains(&object("models/orders.sql", None)));
+        assert!(changed.contains(&object("models/customers.sql", Some("models/schema.yml"))));
+        assert!(!changed.contains(&object("models/customers.sql", Some("models/other.yml"))));
+        assert!(!changed.contains(&object("models/payments.sql", None)));
+    }
+
+    #[test]
+    fn test_from_git() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path().to_str().unwrap();
+        let project = temp_dir.path().join("dbt_project");
+        std::fs::create_dir_all(project.join("models")).unwrap();
+        std::fs::write(project.join("models/orders.sql"), "select 1").unwrap();
+        std::fs::write(project.join("models/customers.sql"), "select 1").unwrap();
+        std::fs::write(temp_dir.path().join("README.md"), "readme").unwrap();
+
+        let commit = |message: &str| {
+            git(root, &["add", "-A"]).unwrap();
+            git(
+                root,
+                &[
+                    "-c",
+                    "user.name=dbtective",
+                    "-c",
+                    "user.email=dbtective@example.com",
+                    "commit",
+                    "-q",
+                    "-m",
+                    message,
+                ],
+            )
+            .unwrap();
+        };
+        git(root, &["init", "-q"]).unwrap();
+        commit("initial");
+        git(root, &["tag", "base"]).unwrap();
+
+        std::fs::write(project.join("models/orders.sql"), "select 2").unwrap();
+        std::fs::write(temp_dir.path().join("README.md"), "changed").unwrap();
+        commit("change orders");
+        std::fs::write(project.join("models/new.sql"), "select 3").unwrap();
+
+        let changed = ChangedFiles::from_git(project.to_str().unwrap(), "base").unwrap();
+        // Changes outside of the dbt project are ignored
+        assert_eq!(changed.len(), 2);
+        assert!(changed.contains(&object("models/orders.sql", None)));
+        assert!(changed.contains(&object("models/new.sql", None)));
+        assert!(!changed.contains(&object("models/customers.sql", None)));
+
+        assert!(ChangedFiles::from_git(project.to_str().unwrap(), "unknown-ref").is_err());
+    }
+}
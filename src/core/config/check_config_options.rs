@@ -1,5 +1,9 @@
+use anyhow::Result;
 use strum_macros::{AsRefStr, EnumString};
 
+use crate::core::config::includes_excludes::PathPatterns;
+use crate::core::manifest::dbt_objects::freshness::FreshnessThreshold;
+
 // HasTags
 #[derive(EnumString, Debug, PartialEq, Eq, Default)]
 #[strum(serialize_all = "snake_case")]
@@ -49,6 +53,289 @@ pub fn default_allowed_test_names() -> Vec<String> {
     ]
 }
 
+pub fn default_not_null_test_names() -> Vec<String> {
+    vec!["not_null".to_string()]
+}
+
 pub const fn default_max_code_lines() -> usize {
     150
 }
+
+pub const fn default_max_source_references() -> usize {
+    1
+}
+
+// MaxChildren & MaxParents
+pub const fn default_max_dependencies() -> usize {
+    10
+}
+
+// MaxChainDepth
+pub const fn default_max_chain_depth() -> usize {
+    10
+}
+
+// Resource types as they appear in unique ids, e.g. `model.jaffle_shop.orders`
+const RESOURCE_TYPES: [&str; 11] = [
+    "model",
+    "seed",
+    "snapshot",
+    "source",
+    "analysis",
+    "test",
+    "unit_test",
+    "exposure",
+    "metric",
+    "semantic_model",
+    "saved_query",
+];
+
+// Tests, unit tests and exposures don't count as dependencies unless configured
+pub fn default_counted_resource_types() -> Vec<String> {
+    ["model", "seed", "snapshot", "source"]
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// # Errors
+/// Returns an error if a resource type is not a known dbt resource type
+pub fn validate_resource_types(resource_types: &[String]) -> Result<()> {
+    for resource_type in resource_types {
+        if !RESOURCE_TYPES.contains(&resource_type.as_str()) {
+            anyhow::bail!(
+                "Unknown resource type '{resource_type}' in `resource_types`. Valid options are: {}",
+                RESOURCE_TYPES.join(", ")
+            );
+        }
+    }
+    Ok(())
+}
+
+// Whether a fqn starts with a dot separated prefix, e.g. `jaffle_shop.staging`
+fn fqn_has_prefix(fqn: &[String], prefix: &str) -> bool {
+    let parts: Vec<&str> = prefix.split('.').collect();
+    parts.len() <= fqn.len() && parts.iter().zip(fqn).all(|(part, fqn)| part == fqn)
+}
+
+// Whether an object matches any of the path globs, fqn prefixes or name prefixes
+// Shared by layers and materialization policies
+fn matches_path_fqn_or_name(
+    paths: Option<&PathPatterns>,
+    fqn_prefixes: &[String],
+    name_prefixes: &[String],
+    path: &str,
+    fqn: &[String],
+    name: &str,
+) -> bool {
+    paths.is_some_and(|paths| paths.is_match(path))
+        || fqn_prefixes
+            .iter()
+            .any(|prefix| fqn_has_prefix(fqn, prefix))
+        || name_prefixes
+            .iter()
+            .any(|prefix| name.starts_with(prefix.as_str()))
+}
+
+// LayerDependencies
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+// A layer of the DAG (e.g. staging, intermediate, marts), an object belongs to the first layer it matches
+pub struct Layer {
+    pub name: String,
+    /// Glob patterns matched against the path of the object
+    pub paths: Option<PathPatterns>,
+    /// Prefixes of the fqn, e.g. `jaffle_shop.staging`
+    #[serde(default)]
+    pub fqn_prefixes: Vec<String>,
+    /// Prefixes of the name, e.g. `stg_`
+    #[serde(default)]
+    pub name_prefixes: Vec<String>,
+    /// Resource types, e.g. `source` or `seed`
+    #[serde(default)]
+    pub resource_types: Vec<String>,
+    /// Layers this layer may depend on, any layer if not set
+    pub allowed_upstream: Option<Vec<String>>,
+}
+
+impl Layer {
+    pub fn matches(&self, path: &str, fqn: &[String], name: &str, resource_type: &str) -> bool {
+        matches_path_fqn_or_name(
+            self.paths.as_ref(),
+            &self.fqn_prefixes,
+            &self.name_prefixes,
+            path,
+            fqn,
+            name,
+        ) || self.resource_types.iter().any(|rt| rt == resource_type)
+    }
+
+    pub fn allows(&self, upstream: &Self) -> bool {
+        self.allowed_upstream
+            .as_ref()
+            .is_none_or(|allowed| allowed.contains(&upstream.name))
+    }
+}
+
+/// # Errors
+/// Returns an error if there are no layers, a layer has no way to match objects,
+/// a name is used twice or `allowed_upstream` refers to an unknown layer
+pub fn validate_layers(layers: &[Layer]) -> Result<()> {
+    if layers.is_empty() {
+        anyhow::bail!("layer_dependencies requires at least one layer in `layers`");
+    }
+    for (index, layer) in layers.iter().enumerate() {
+        if layers[..index].iter().any(|other| other.name == layer.name) {
+            anyhow::bail!("Layer '{}' is defined more than once", layer.name);
+        }
+        if layer.paths.is_none()
+            && layer.fqn_prefixes.is_empty()
+            && layer.name_prefixes.is_empty()
+            && layer.resource_types.is_empty()
+        {
+            anyhow::bail!(
+                "Layer '{}' needs at least one of `paths`, `fqn_prefixes`, `name_prefixes` or `resource_types`",
+                layer.name
+            );
+        }
+        for upstream in layer.allowed_upstream.iter().flatten() {
+            if !layers.iter().any(|other| &other.name == upstream) {
+                anyhow::bail!(
+                    "Layer '{}' allows unknown upstream layer '{upstream}'. Defined layers are: {}",
+                    layer.name,
+                    layers
+                        .iter()
+                        .map(|layer| layer.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+// Materialization
+pub const fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+// Allowed materializations for the models matching a path or prefix, the first matching policy applies
+pub struct MaterializationPolicy {
+    /// Glob patterns matched against the path of the model
+    pub paths: Option<PathPatterns>,
+    /// Prefixes of the fqn, e.g. `jaffle_shop.staging`
+    #[serde(default)]
+    pub fqn_prefixes: Vec<String>,
+    /// Prefixes of the name, e.g. `stg_`
+    #[serde(default)]
+    pub name_prefixes: Vec<String>,
+    /// Allowed materializations, e.g. `view` or `incremental`
+    pub allowed: Vec<String>,
+}
+
+impl MaterializationPolicy {
+    pub fn matches(&self, path: &str, fqn: &[String], name: &str) -> bool {
+        matches_path_fqn_or_name(
+            self.paths.as_ref(),
+            &self.fqn_prefixes,
+            &self.name_prefixes,
+            path,
+            fqn,
+            name,
+        )
+    }
+}
+
+/// # Errors
+/// Returns an error if a policy has no way to match models or allows no materializations
+pub fn validate_materialization_policies(policies: &[MaterializationPolicy]) -> Result<()> {
+    for (index, policy) in policies.iter().enumerate() {
+        if policy.paths.is_none()
+            && policy.fqn_prefixes.is_empty()
+            && policy.name_prefixes.is_empty()
+        {
+            anyhow::bail!(
+                "Materialization policy {} needs at least one of `paths`, `fqn_prefixes` or `name_prefixes`",
+                index + 1
+            );
+        }
+        if policy.allowed.is_empty() {
+            anyhow::bail!(
+                "Materialization policy {} needs at least one materialization in `allowed`",
+                index + 1
+            );
+        }
+    }
+    Ok(())
+}
+
+// TestCoverage
+pub const fn default_min_test_coverage() -> usize {
+    100
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+// Tests every documented column matching one of the patterns needs, e.g. `*_id` columns need `not_null`
+pub struct RequiredColumnTests {
+    /// Glob patterns matched against the column name, e.g. `*_id`
+    pub columns: PathPatterns,
+    /// Test names, as `name` or `namespace.name` for tests from packages
+    pub tests: Vec<String>,
+}
+
+/// # Errors
+/// Returns an error if the minimum coverage is above 100 percent or a requirement lists no tests
+pub fn validate_test_coverage(
+    min_coverage: usize,
+    required_tests: &[RequiredColumnTests],
+) -> Result<()> {
+    if min_coverage > 100 {
+        anyhow::bail!(
+            "`min_coverage` is a percentage and can not be above 100, got {min_coverage}"
+        );
+    }
+    if let Some(index) = required_tests
+        .iter()
+        .position(|required| required.tests.is_empty())
+    {
+        anyhow::bail!(
+            "Required column tests {} need at least one test in `tests`",
+            index + 1
+        );
+    }
+    Ok(())
+}
+
+// HasRelationshipTest
+pub fn default_foreign_key_columns() -> PathPatterns {
+    PathPatterns::from(vec!["*_id".to_string()])
+}
+
+pub fn default_relationship_test_names() -> Vec<String> {
+    vec![
+        "relationships".to_string(),
+        "dbt_utils.relationships_where".to_string(),
+    ]
+}
+
+// SourceHasFreshness
+/// # Errors
+/// Returns an error if a maximum threshold is missing its `count` or `period`
+pub fn validate_max_freshness(
+    max_warn_after: Option<&FreshnessThreshold>,
+    max_error_after: Option<&FreshnessThreshold>,
+) -> Result<()> {
+    for (name, threshold) in [
+        ("max_warn_after", max_warn_after),
+        ("max_error_after", max_error_after),
+    ] {
+        if threshold.is_some_and(|threshold| !threshold.is_set()) {
+            anyhow::bail!("`{name}` needs a positive `count` and a `period` (minute, hour or day)");
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anyhow::Context;
+use anyhow::Result;
+use serde::Deserialize;
+
+use crate::core::config::applies_to::RuleTarget;
+use crate::core::config::{
+    applies_to::AppliesTo, check_config_options::default_true, includes_excludes::PathPatterns,
+    severity::Severity,
+};
+use crate::core::selection::RuleSelection;
+use strum_macros::{AsRefStr, EnumIter, EnumString};
+
+#[derive(Debug, Deserialize, EnumIter, AsRefStr, EnumString)]
+#[strum(serialize_all = "snake_case")]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FreshnessSpecificRuleConfig {
+    SourceIsFresh {
+        #[serde(default = "default_true")]
+        fail_on_warn: bool,
+    },
+}
+
+impl FreshnessSpecificRuleConfig {
+    pub fn as_str(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+const fn freshness_default_severity() -> Severity {
+    Severity::Error
+}
+
+#[derive(Debug, Deserialize)]
+// Rules that require the results of `dbt source freshness` (sources.json)
+// Like the catalog, these need an active connection to the data warehouse to be generated
+pub struct FreshnessRule {
+    pub name: Option<String>,
+    #[serde(default = "freshness_default_severity")]
+    pub severity: Severity,
+    pub description: Option<String>, // Human-readable description of the rule, not used in logic
+    pub includes: Option<PathPatterns>,
+    pub excludes: Option<PathPatterns>,
+    pub applies_to: Option<AppliesTo>,
+    #[serde(flatten)]
+    pub selection: RuleSelection,
+    #[serde(flatten)]
+    pub rule: FreshnessSpecificRuleConfig,
+}
+
+impl FreshnessRule {
+    pub fn get_name(&self) -> String {
+        self.name
+            .as_ref()
+            .map_or_else(|| self.rule.as_str().to_string(), Clone::clone)
+    }
+
+    /// Validate that the `applies_to` targets are valid for the specific rule
+    /// # Errors
+    /// Returns an error if any target in `applies_to` is not valid for the rule type
+    pub fn validate_applies_to(&self) -> Result<()> {
+        self.applies_to
+            .as_ref()
+            .context("applies_to must be set before validation, so this should never happen")?
+            .validate_against(
+                &applies_to_options_for_freshness_rule(&self.rule),
+                self.rule.as_str(),
+            )
+    }
+}
+
+// Only sources have freshness results
+pub fn default_applies_to_for_freshness_rule(rule_type: &FreshnessSpecificRuleConfig) -> AppliesTo {
+    match rule_type {
+        FreshnessSpecificRuleConfig::SourceIsFresh { .. } => AppliesTo {
+            node_objects: vec![],
+            source_objects: vec![RuleTarget::Sources],
+            unit_test_objects: vec![],
+            macro_objects: vec![],
+            exposure_objects: vec![],
+            semantic_model_objects: vec![],
+            custom_objects: vec![],
+        },
+    }
+}
+
+fn applies_to_options_for_freshness_rule(rule_type: &FreshnessSpecificRuleConfig) -> AppliesTo {
+    default_applies_to_for_freshness_rule(rule_type)
+}
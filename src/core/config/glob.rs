@@ -0,0 +1,238 @@
+use regex::Regex;
+
+/// A compiled gitignore-style glob pattern.
+///
+/// - `*` matches anything except `/`, `?` a single character except `/`
+/// - `**` matches any number of directories (`models/**/*.sql`, `**/staging`, `models/**`)
+/// - `[abc]`, `[a-z]` and `[!abc]` match a single character from (or not from) a class
+/// - A pattern ending in `/` matches everything inside the directory
+/// - A pattern without a `/` matches the file or directory name at any depth (`*.py`)
+///
+/// Backslashes are treated as path separators, so Windows paths match the same patterns.
+#[derive(Debug, Clone)]
+pub struct Glob {
+    regex: Regex,
+}
+
+fn normalize(path: &str) -> String {
+    path.replace('\\', "/")
+}
+
+/// Translate a `[...]` class starting at `start`, returns the regex and the index after the class.
+/// Returns `None` if the class is not closed, in which case `[` is matched literally.
+fn translate_class(chars: &[char], start: usize) -> Option<(String, usize)> {
+    let mut index = start + 1;
+    let negated = matches!(chars.get(index), Some('!' | '^'));
+    if negated {
+        index += 1;
+    }
+    let content_start = index;
+    // A `]` directly after the opening bracket is part of the class
+    if chars.get(index) == Some(&']') {
+        index += 1;
+    }
+    while chars.get(index).is_some_and(|c| *c != ']') {
+        index += 1;
+    }
+    if index >= chars.len() {
+        return None;
+    }
+
+    let content: String = chars[content_start..index]
+        .iter()
+        .map(|c| match c {
+            '-' => "-".to_string(),
+            c => regex::escape(&c.to_string()),
+        })
+        .collect();
+    // Like `*` and `?`, a class never matches the path separator
+    let class = if negated {
+        format!("[^/{content}]")
+    } else {
+        format!("[{content}&&[^/]]")
+    };
+    Some((class, index + 1))
+}
+
+fn translate(pattern: &str) -> String {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut regex = String::new();
+    let mut index = 0;
+
+    while index < chars.len() {
+        match chars[index] {
+            '*' => {
+                let start = index;
+                while chars.get(index) == Some(&'*') {
+                    index += 1;
+                }
+                let whole_segment = (start == 0 || chars[start - 1] == '/')
+                    && chars.get(index).is_none_or(|c| *c == '/');
+                if index - start > 1 && whole_segment {
+                    if index < chars.len() {
+                        // `**/`: zero or more directories
+                        regex.push_str("(?:.*/)?");
+                        index += 1;
+                    } else {
+                        regex.push_str(".*");
+                    }
+                } else {
+                    regex.push_str("[^/]*");
+                }
+                continue;
+            }
+            '?' => regex.push_str("[^/]"),
+            '[' => {
+                if let Some((class, next)) = translate_class(&chars, index) {
+                    regex.push_str(&class);
+                    index = next;
+                    continue;
+                }
+                regex.push_str(r"\[");
+            }
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+        index += 1;
+    }
+    regex
+}
+
+impl Glob {
+    /// # Panics
+    /// Never in practice, every character of the pattern is escaped or translated before it is compiled.
+    pub fn new(pattern: &str) -> Self {
+        let pattern = normalize(pattern);
+        let pattern = pattern.trim_start_matches("./");
+        let (pattern, directory) = pattern
+            .strip_suffix('/')
+            .map_or((pattern, false), |pattern| (pattern, true));
+
+        let mut regex = String::from("^");
+        if pattern.starts_with('/') {
+            regex.push_str(&translate(pattern.trim_start_matches('/')));
+        } else {
+            if !pattern.contains('/') {
+                regex.push_str("(?:.*/)?");
+            }
+            regex.push_str(&translate(pattern));
+        }
+        if directory {
+            regex.push_str("/.*");
+        }
+        regex.push('$');
+
+        Self {
+            regex: Regex::new(&regex).expect("glob patterns are escaped before compiling"),
+        }
+    }
+
+    /// Whether the compiled glob can only match a single path exactly:
+    /// no glob syntax, and neither a name at any depth (no `/`) nor a whole directory (trailing `/`)
+    pub fn is_literal(pattern: &str) -> bool {
+        let pattern = normalize(pattern);
+        pattern.contains('/') && !pattern.ends_with('/') && !pattern.contains(['*', '?', '['])
+    }
+
+    pub fn is_match(&self, path: &str) -> bool {
+        self.regex.is_match(&normalize(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(pattern: &str, path: &str) -> bool {
+        Glob::new(pattern).is_match(path)
+    }
+
+    #[test]
+    fn test_single_star_stays_in_directory() {
+        assert!(matches("models/staging/*", "models/staging/stg_orders.sql"));
+        assert!(!matches(
+            "models/staging/*",
+            "models/staging/crm/stg_customers.sql"
+        ));
+        assert!(matches(
+            "models/*/stg_orders.sql",
+            "models/staging/stg_orders.sql"
+        ));
+        assert!(matches("models/stg_*.sql", "models/stg_orders.sql"));
+        assert!(!matches("models/stg_*.sql", "models/marts/stg_orders.sql"));
+    }
+
+    #[test]
+    fn test_double_star_crosses_directories() {
+        assert!(matches(
+            "models/staging/**",
+            "models/staging/stg_orders.sql"
+        ));
+        assert!(matches(
+            "models/staging/**",
+            "models/staging/crm/stg_customers.sql"
+        ));
+        assert!(!matches("models/staging/**", "models/staging"));
+        assert!(matches("models/**/*.sql", "models/orders.sql"));
+        assert!(matches(
+            "models/**/*.sql",
+            "models/marts/finance/orders.sql"
+        ));
+        assert!(matches("**/staging/*.sql", "models/staging/stg_orders.sql"));
+        assert!(matches(
+            "models/**/crm/*",
+            "models/staging/crm/stg_customers.sql"
+        ));
+        assert!(!matches("models/**/crm/*", "models/staging/stg_orders.sql"));
+        // `**` inside a segment is a regular `*`
+        assert!(!matches("models/stg**", "models/staging/stg_orders.sql"));
+    }
+
+    #[test]
+    fn test_question_mark_and_classes() {
+        assert!(matches("models/v?/orders.sql", "models/v2/orders.sql"));
+        assert!(!matches("models/v?/orders.sql", "models/v10/orders.sql"));
+        assert!(matches("models/v[12]/*.sql", "models/v1/orders.sql"));
+        assert!(!matches("models/v[12]/*.sql", "models/v3/orders.sql"));
+        assert!(matches("models/v[0-9]/*.sql", "models/v7/orders.sql"));
+        assert!(matches("models/v[!12]/*.sql", "models/v3/orders.sql"));
+        assert!(!matches("models/v[!12]/*.sql", "models/v1/orders.sql"));
+        // Classes never match a path separator, also not through a range
+        assert!(!matches("models[/]orders.sql", "models/orders.sql"));
+        assert!(!matches("models[+-0]orders.sql", "models/orders.sql"));
+        assert!(matches("models[+-0]orders.sql", "models-orders.sql"));
+        // Unclosed classes are matched literally
+        assert!(matches("models/[draft.sql", "models/[draft.sql"));
+    }
+
+    #[test]
+    fn test_unanchored_and_directory_patterns() {
+        // Without a `/` the pattern matches a name at any depth
+        assert!(matches("*.py", "models/marts/orders.py"));
+        assert!(matches("orders.sql", "models/marts/orders.sql"));
+        assert!(!matches("orders", "models/marts/orders.sql"));
+        // A leading `/` anchors the pattern to the project root
+        assert!(!matches("/*.py", "models/orders.py"));
+        // A trailing `/` matches everything inside the directory
+        assert!(matches("models/legacy/", "models/legacy/old/orders.sql"));
+        assert!(matches("./models/legacy/*", "models/legacy/orders.sql"));
+        assert!(matches(
+            r"models\staging\*",
+            r"models\staging\stg_orders.sql"
+        ));
+    }
+
+    #[test]
+    fn test_special_characters_are_literal() {
+        assert!(matches(
+            "models/orders (old).sql",
+            "models/orders (old).sql"
+        ));
+        assert!(!matches("models/orders.sql", "models/ordersxsql"));
+        assert!(Glob::is_literal("models/orders.sql"));
+        assert!(Glob::is_literal(r"models\orders.sql"));
+        assert!(!Glob::is_literal("models/*.sql"));
+        // Match any depth or a whole directory
+        assert!(!Glob::is_literal("orders.sql"));
+        assert!(!Glob::is_literal("models/"));
+    }
+}
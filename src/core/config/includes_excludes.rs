@@ -1,60 +1,111 @@
-use regex::Regex;
+use serde::Deserialize;
+
+use crate::core::config::glob::Glob;
 
 pub trait IncludeExcludable {
     fn get_relative_path(&self) -> &String;
+
+    fn get_unique_id(&self) -> &String;
+
+    /// Path of the YAML file the properties of the object are defined in, if separate from its own file
+    fn get_schema_path(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// A list of `includes` or `excludes` glob patterns, compiled when the configuration is loaded.
+///
+/// Patterns are matched in order and the last matching pattern decides, so a pattern starting
+/// with `!` re-includes paths matched by an earlier pattern (like in `.gitignore`).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(from = "Vec<String>")]
+pub struct PathPatterns {
+    patterns: Vec<PathPattern>,
+}
+
+#[derive(Debug, Clone)]
+struct PathPattern {
+    glob: Glob,
+    negated: bool,
+    literal: bool,
+}
+
+impl From<Vec<String>> for PathPatterns {
+    fn from(patterns: Vec<String>) -> Self {
+        Self {
+            patterns: patterns
+                .iter()
+                .map(|pattern| {
+                    let (pattern, negated) = pattern
+                        .strip_prefix('!')
+                        .map_or((pattern.as_str(), false), |pattern| (pattern, true));
+                    PathPattern {
+                        glob: Glob::new(pattern),
+                        negated,
+                        literal: Glob::is_literal(pattern),
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+impl PathPatterns {
+    /// The pattern deciding that `path` matches, `None` if no pattern matches or the last one is negated
+    fn matching(&self, path: &str) -> Option<&PathPattern> {
+        self.patterns
+            .iter()
+            .rev()
+            .find(|pattern| pattern.glob.is_match(path))
+            .filter(|pattern| !pattern.negated)
+    }
+
+    pub fn is_match(&self, path: &str) -> bool {
+        self.matching(path).is_some()
+    }
 }
 
+/// Whether a rule applies to the object, based on the rule's `includes` and `excludes`.
+///
+/// Literal paths take precedence over globs: a literal exclude always excludes, a literal
+/// include overrides any glob exclude, a glob exclude overrides a glob include.
+/// With `includes` only matching objects are included.
 pub fn should_run_test<T: IncludeExcludable>(
     object: &T,
-    includes: Option<&Vec<String>>,
-    excludes: Option<&Vec<String>>,
+    includes: Option<&PathPatterns>,
+    excludes: Option<&PathPatterns>,
 ) -> bool {
     let path = object.get_relative_path();
+    let exclude_match = excludes.and_then(|excludes| excludes.matching(path));
+    let include_match = includes.and_then(|includes| includes.matching(path));
+
     // 1. Exact exclude -> always exclude
-    if let Some(ex) = excludes {
-        if ex.iter().any(|p| !p.contains('*') && p == path) {
-            return false;
-        }
+    if exclude_match.is_some_and(|pattern| pattern.literal) {
+        return false;
     }
 
     // 2. Exact include -> always include (excepted by exact exclude above)
-    if let Some(inc) = includes {
-        if inc.iter().any(|p| !p.contains('*') && p == path) {
-            return true; //
-        }
+    if include_match.is_some_and(|pattern| pattern.literal) {
+        return true;
     }
 
     // 3. Wildcard exclude -> exclude
-    if let Some(ex) = excludes {
-        if ex
-            .iter()
-            .any(|p| p.contains('*') && wildcard_match(p, path))
-        {
-            return false;
-        }
-    }
-
-    // 4. Wildcard include -> include
-    if let Some(inc) = includes {
-        if inc.iter().any(|p| wildcard_match(p, path)) {
-            return true;
-        }
+    if exclude_match.is_some() {
         return false;
     }
 
-    // 5. Default allow
-    true
-}
-
-fn wildcard_match(pattern: &str, path: &str) -> bool {
-    let regex_pattern = regex::escape(pattern).replace(r"\*", ".*");
-    let re = Regex::new(&format!("^{regex_pattern}$")).unwrap();
-    re.is_match(path)
+    // 4. Wildcard include -> include, 5. Default allow
+    includes.is_none() || include_match.is_some()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+
+    fn patterns(patterns: &[&str]) -> PathPatterns {
+        PathPatterns::from(patterns.iter().map(ToString::to_string).collect::<Vec<_>>())
+    }
+
     struct TestObject {
         path: String,
     }
@@ -62,6 +113,10 @@ mod tests {
         fn get_relative_path(&self) -> &String {
             &self.path
         }
+
+        fn get_unique_id(&self) -> &String {
+            &self.path
+        }
     }
 
     // Include tests
@@ -70,7 +125,7 @@ mod tests {
         let obj = TestObject {
             path: "dbt_project/models/my_model.sql".to_string(),
         };
-        let includes = Some(vec!["dbt_project/models/my_model.sql".to_string()]);
+        let includes = Some(patterns(&["dbt_project/models/my_model.sql"]));
         let excludes = None;
         assert!(
             should_run_test(&obj, includes.as_ref(), excludes.as_ref()),
@@ -82,7 +137,7 @@ mod tests {
         let obj = TestObject {
             path: "dbt_project/models/my_model.sql".to_string(),
         };
-        let includes = Some(vec!["my_model".to_string()]);
+        let includes = Some(patterns(&["my_model"]));
         let excludes = None;
         assert!(
             !should_run_test(&obj, includes.as_ref(), excludes.as_ref()),
@@ -95,7 +150,7 @@ mod tests {
         let obj = TestObject {
             path: "dbt_project/models/my_model.sql".to_string(),
         };
-        let includes = Some(vec!["dbt_project/models/*.sql".to_string()]);
+        let includes = Some(patterns(&["dbt_project/models/*.sql"]));
         let excludes = None;
         assert!(
             should_run_test(&obj, includes.as_ref(), excludes.as_ref()),
@@ -108,7 +163,7 @@ mod tests {
         let obj = TestObject {
             path: "dbt_project/models/subfolder/my_model.sql".to_string(),
         };
-        let includes = Some(vec!["dbt_project/models/**/*.sql".to_string()]);
+        let includes = Some(patterns(&["dbt_project/models/**/*.sql"]));
         let excludes = None;
         assert!(
             should_run_test(&obj, includes.as_ref(), excludes.as_ref()),
@@ -121,7 +176,7 @@ mod tests {
         let obj = TestObject {
             path: "dbt_project/other_folder/my_model.sql".to_string(),
         };
-        let includes = Some(vec!["dbt_project/models/*.sql".to_string()]);
+        let includes = Some(patterns(&["dbt_project/models/*.sql"]));
         let excludes = None;
         assert!(
             !should_run_test(&obj, includes.as_ref(), excludes.as_ref()),
@@ -136,7 +191,7 @@ mod tests {
             path: "dbt_project/models/my_model.sql".to_string(),
         };
         let includes = None;
-        let excludes = Some(vec!["dbt_project/models/my_model.sql".to_string()]);
+        let excludes = Some(patterns(&["dbt_project/models/my_model.sql"]));
         assert!(
             !should_run_test(&obj, includes.as_ref(), excludes.as_ref()),
             "Object should be excluded based on complete path match"
@@ -149,7 +204,7 @@ mod tests {
             path: "dbt_project/models/my_model.sql".to_string(),
         };
         let includes = None;
-        let excludes = Some(vec!["my_model".to_string()]);
+        let excludes = Some(patterns(&["my_model"]));
         assert!(
             should_run_test(&obj, includes.as_ref(), excludes.as_ref()),
             "Object should not be excluded based on partial match"
@@ -162,7 +217,7 @@ mod tests {
             path: "dbt_project/models/my_model.sql".to_string(),
         };
         let includes = None;
-        let excludes = Some(vec!["dbt_project/models/*.sql".to_string()]);
+        let excludes = Some(patterns(&["dbt_project/models/*.sql"]));
         assert!(
             !should_run_test(&obj, includes.as_ref(), excludes.as_ref()),
             "Object should be excluded based on wildcard match"
@@ -175,7 +230,7 @@ mod tests {
             path: "dbt_project/models/subfolder/my_model.sql".to_string(),
         };
         let includes = None;
-        let excludes = Some(vec!["dbt_project/models/**/*.sql".to_string()]);
+        let excludes = Some(patterns(&["dbt_project/models/**/*.sql"]));
         assert!(
             !should_run_test(&obj, includes.as_ref(), excludes.as_ref()),
             "Object should be excluded based on recursive wildcard match"
@@ -188,7 +243,7 @@ mod tests {
             path: "dbt_project/models/my_model.sql".to_string(),
         };
         let includes = None;
-        let excludes = Some(vec!["dbt_project/other_folder/*.sql".to_string()]);
+        let excludes = Some(patterns(&["dbt_project/other_folder/*.sql"]));
         assert!(
             should_run_test(&obj, includes.as_ref(), excludes.as_ref()),
             "Object should not be excluded when wildcard does not match"
@@ -200,8 +255,8 @@ mod tests {
         let obj = TestObject {
             path: "dbt_project/models/my_model.sql".to_string(),
         };
-        let includes = Some(vec!["dbt_project/models/my_model.sql".to_string()]);
-        let excludes = Some(vec!["dbt_project/models/*.sql".to_string()]);
+        let includes = Some(patterns(&["dbt_project/models/my_model.sql"]));
+        let excludes = Some(patterns(&["dbt_project/models/*.sql"]));
         assert!(
             should_run_test(&obj, includes.as_ref(), excludes.as_ref()),
             "Exact include should override wildcard exclude"
@@ -213,8 +268,8 @@ mod tests {
         let obj = TestObject {
             path: "dbt_project/models/my_model.sql".to_string(),
         };
-        let includes = Some(vec!["dbt_project/models/*.sql".to_string()]);
-        let excludes = Some(vec!["dbt_project/models/my_model.sql".to_string()]);
+        let includes = Some(patterns(&["dbt_project/models/*.sql"]));
+        let excludes = Some(patterns(&["dbt_project/models/my_model.sql"]));
         assert!(
             !should_run_test(&obj, includes.as_ref(), excludes.as_ref()),
             "Exact exclude should override wildcard include"
@@ -226,9 +281,9 @@ mod tests {
         let obj = TestObject {
             path: "dbt_project/models/my_model.sql".to_string(),
         };
-        let includes = Some(vec![
-            "dbt_project/some_other_dir/some_other_model.sql".to_string()
-        ]);
+        let includes = Some(patterns(&[
+            "dbt_project/some_other_dir/some_other_model.sql",
+        ]));
         assert!(
             !should_run_test(&obj, includes.as_ref(), None),
             "File should not be included"
@@ -240,8 +295,8 @@ mod tests {
         let obj = TestObject {
             path: "dbt_project/models/my_model.sql".to_string(),
         };
-        let includes = Some(vec!["dbt_project/models/*.sql".to_string()]);
-        let excludes = Some(vec!["dbt_project/models/*.sql".to_string()]);
+        let includes = Some(patterns(&["dbt_project/models/*.sql"]));
+        let excludes = Some(patterns(&["dbt_project/models/*.sql"]));
         assert!(
             !should_run_test(&obj, includes.as_ref(), excludes.as_ref()),
             "Wildcard exclude should override wildcard include"
@@ -260,4 +315,92 @@ mod tests {
             "Object should be included when no includes or excludes are specified"
         );
     }
+
+    #[test]
+    fn single_wildcard_does_not_match_nested_folders() {
+        let obj = TestObject {
+            path: "models/staging/crm/stg_customers.sql".to_string(),
+        };
+        assert!(!should_run_test(
+            &obj,
+            Some(patterns(&["models/staging/*"])).as_ref(),
+            None
+        ));
+        assert!(should_run_test(
+            &obj,
+            Some(patterns(&["models/staging/**"])).as_ref(),
+            None
+        ));
+    }
+
+    #[test]
+    fn negated_pattern_overrides_earlier_patterns() {
+        let kept = TestObject {
+            path: "models/legacy/orders.sql".to_string(),
+        };
+        let other = TestObject {
+            path: "models/legacy/customers.sql".to_string(),
+        };
+        let excludes = Some(patterns(&["models/legacy/**", "!models/legacy/orders.sql"]));
+        assert!(should_run_test(&kept, None, excludes.as_ref()));
+        assert!(!should_run_test(&other, None, excludes.as_ref()));
+
+        let includes = Some(patterns(&["models/**", "!models/legacy/*"]));
+        assert!(!should_run_test(&kept, includes.as_ref(), None));
+    }
+
+    #[test]
+    fn last_matching_pattern_wins() {
+        let obj = TestObject {
+            path: "models/legacy/orders.sql".to_string(),
+        };
+        // Excluded again after being re-included
+        let excludes = Some(patterns(&[
+            "models/**",
+            "!models/legacy/**",
+            "models/legacy/orders.*",
+        ]));
+        assert!(!should_run_test(&obj, None, excludes.as_ref()));
+    }
+
+    #[test]
+    fn negated_exclude_does_not_override_literal_exclude_of_other_file() {
+        let obj = TestObject {
+            path: "models/orders.sql".to_string(),
+        };
+        let includes = Some(patterns(&["models/*.sql"]));
+        let excludes = Some(patterns(&["models/orders.sql", "!models/customers.sql"]));
+        assert!(!should_run_test(&obj, includes.as_ref(), excludes.as_ref()));
+    }
+
+    #[test]
+    fn directory_include_does_not_override_glob_exclude() {
+        let obj = TestObject {
+            path: "models/staging/tmp_orders.sql".to_string(),
+        };
+        let includes = Some(patterns(&["models/"]));
+        let excludes = Some(patterns(&["models/**/tmp_*"]));
+        assert!(!should_run_test(&obj, includes.as_ref(), excludes.as_ref()));
+
+        // A directory exclude is a glob, so a literal include overrides it
+        let excludes = Some(patterns(&["models/staging/"]));
+        let includes = Some(patterns(&["models/staging/tmp_orders.sql"]));
+        assert!(should_run_test(&obj, includes.as_ref(), excludes.as_ref()));
+    }
+
+    #[test]
+    fn name_pattern_without_slash_is_not_literal() {
+        let obj = TestObject {
+            path: "models/staging/tmp_orders.sql".to_string(),
+        };
+        // `tmp_orders.sql` matches at any depth, so it doesn't beat a glob exclude
+        let includes = Some(patterns(&["tmp_orders.sql"]));
+        let excludes = Some(patterns(&["models/staging/*"]));
+        assert!(!should_run_test(&obj, includes.as_ref(), excludes.as_ref()));
+
+        // As an exclude, a literal include overrides it
+        let includes = Some(patterns(&["models/staging/tmp_orders.sql"]));
+        let excludes = Some(patterns(&["tmp_orders.sql"]));
+        assert!(should_run_test(&obj, includes.as_ref(), excludes.as_ref()));
+    }
 }
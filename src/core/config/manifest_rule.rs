@@ -7,10 +7,18 @@ use serde::Deserialize;
 use crate::core::config::applies_to::AppliesTo;
 use crate::core::config::applies_to::RuleTarget;
 use crate::core::config::check_config_options::{
-    default_allowed_references, default_allowed_test_names, default_max_code_lines,
-    HasTagsCriteria, OrphanedReferenceType,
+    default_allowed_references, default_allowed_test_names, default_counted_resource_types,
+    default_foreign_key_columns, default_max_chain_depth, default_max_code_lines,
+    default_max_dependencies, default_max_source_references, default_min_test_coverage,
+    default_not_null_test_names, default_relationship_test_names, default_true, validate_layers,
+    validate_materialization_policies, validate_max_freshness, validate_resource_types,
+    validate_test_coverage, HasTagsCriteria, Layer, MaterializationPolicy, OrphanedReferenceType,
+    RequiredColumnTests,
 };
+use crate::core::config::includes_excludes::PathPatterns;
 use crate::core::config::severity::Severity;
+use crate::core::manifest::dbt_objects::freshness::FreshnessThreshold;
+use crate::core::selection::RuleSelection;
 use strum_macros::{AsRefStr, EnumIter, EnumString};
 
 #[derive(Debug, Deserialize, EnumIter, AsRefStr, EnumString)]
@@ -45,12 +53,118 @@ pub enum ManifestSpecificRuleConfig {
         #[serde(default = "default_max_code_lines")]
         max_lines: usize,
     },
+    LayerDependencies {
+        layers: Vec<Layer>,
+    },
+    SourceFanout {
+        #[serde(default = "default_max_source_references")]
+        max_references: usize,
+    },
+    DirectSourceReference {
+        allowed_paths: Option<PathPatterns>,
+        allowed_name_prefixes: Option<Vec<String>>,
+    },
+    MaxChildren {
+        #[serde(default = "default_max_dependencies")]
+        max_children: usize,
+        #[serde(default = "default_counted_resource_types")]
+        resource_types: Vec<String>,
+    },
+    MaxParents {
+        #[serde(default = "default_max_dependencies")]
+        max_parents: usize,
+        #[serde(default = "default_counted_resource_types")]
+        resource_types: Vec<String>,
+    },
+    NoRedundantDependencies {},
+    MaxChainDepth {
+        #[serde(default = "default_max_chain_depth")]
+        max_depth: usize,
+        materializations: Option<Vec<String>>,
+    },
+    Materialization {
+        #[serde(default)]
+        policies: Vec<MaterializationPolicy>,
+        #[serde(default = "default_true")]
+        require_unique_key: bool,
+        #[serde(default = "default_true")]
+        require_on_schema_change: bool,
+    },
+    NoHardcodedReferences {},
+    NoSelectStar {},
+    NoOrderBy {},
+    NoDistinctWithoutComment {},
+    ForbiddenKeywords {
+        keywords: Vec<String>,
+    },
+    ImportCtes {},
+    PrimaryKeyTested {
+        meta_key: Option<String>,
+        #[serde(default = "default_allowed_test_names")]
+        unique_test_names: Vec<String>,
+        #[serde(default = "default_not_null_test_names")]
+        not_null_test_names: Vec<String>,
+    },
+    TestCoverage {
+        #[serde(default = "default_min_test_coverage")]
+        min_coverage: usize,
+        #[serde(default)]
+        required_tests: Vec<RequiredColumnTests>,
+    },
+    HasRelationshipTest {
+        #[serde(default = "default_foreign_key_columns")]
+        columns: PathPatterns,
+        #[serde(default = "default_relationship_test_names")]
+        test_names: Vec<String>,
+    },
+    SourceHasFreshness {
+        max_warn_after: Option<FreshnessThreshold>,
+        max_error_after: Option<FreshnessThreshold>,
+    },
 }
 
 impl ManifestSpecificRuleConfig {
     pub fn as_str(&self) -> &str {
         self.as_ref()
     }
+
+    /// Rules that check properties defined in YAML property files rather than the SQL file.
+    /// Their findings point at the property file (`patch_path`) when one exists.
+    pub const fn checks_yaml_properties(&self) -> bool {
+        matches!(
+            self,
+            Self::HasDescription {}
+                | Self::HasTags { .. }
+                | Self::HasUniqueTest { .. }
+                | Self::HasContractEnforced {}
+                | Self::HasMetadataKeys { .. }
+        )
+    }
+
+    /// Validate the rule specific options
+    /// # Errors
+    /// Returns an error if an option is invalid
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            Self::LayerDependencies { layers } => validate_layers(layers),
+            Self::MaxChildren { resource_types, .. } | Self::MaxParents { resource_types, .. } => {
+                validate_resource_types(resource_types)
+            }
+            Self::Materialization { policies, .. } => validate_materialization_policies(policies),
+            Self::TestCoverage {
+                min_coverage,
+                required_tests,
+            } => validate_test_coverage(*min_coverage, required_tests),
+            Self::SourceHasFreshness {
+                max_warn_after,
+                max_error_after,
+            } => validate_max_freshness(max_warn_after.as_ref(), max_error_after.as_ref()),
+            Self::ForbiddenKeywords { keywords } if keywords.is_empty() => {
+                anyhow::bail!("`keywords` must contain at least one keyword")
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 const fn manifest_default_severity() -> Severity {
@@ -65,8 +179,10 @@ pub struct ManifestRule {
     #[allow(dead_code)]
     pub description: Option<String>, // Human-readable description of the rule, not used in logic
     pub applies_to: Option<AppliesTo>,
-    pub includes: Option<Vec<String>>,
-    pub excludes: Option<Vec<String>>,
+    pub includes: Option<PathPatterns>,
+    pub excludes: Option<PathPatterns>,
+    #[serde(flatten)]
+    pub selection: RuleSelection,
     #[serde(flatten)]
     pub rule: ManifestSpecificRuleConfig,
 }
@@ -94,79 +210,37 @@ impl ManifestRule {
             .map_or_else(|| self.rule.as_str().to_string(), Clone::clone)
     }
 
-    pub fn normalize_includes_excludes(&mut self) {
-        self.includes = self.includes.take().map(|v| {
-            v.into_iter()
-                .map(|s| {
-                    s.trim_start_matches("./")
-                        .trim_start_matches('/')
-                        .to_string()
-                })
-                .collect()
-        });
-        self.excludes = self.excludes.take().map(|v| {
-            v.into_iter()
-                .map(|s| {
-                    s.trim_start_matches("./")
-                        .trim_start_matches('/')
-                        .to_string()
-                })
-                .collect()
-        });
-    }
     /// Validate that the `applies_to` targets are valid for the specific rule
     /// # Errors
     /// Returns an error if any target in `applies_to` is not valid for the rule type
     pub fn validate_applies_to(&self) -> Result<()> {
-        let options = applies_to_options_for_manifest_rule(&self.rule);
-        let mut invalid_targets = Vec::new();
-        let applies_to = self
-            .applies_to
+        self.applies_to
             .as_ref()
-            .context("applies_to must be set before validation, so this should never happen")?;
-
-        // Check each target in applies_to against the valid options for this rule
-        // All applies to that are nodes get the Node target here
-        // All other applies to get their own target type
-        let pairs = [
-            (&applies_to.node_objects, &options.node_objects),
-            (&applies_to.source_objects, &options.source_objects),
-            (&applies_to.unit_test_objects, &options.unit_test_objects),
-            (&applies_to.macro_objects, &options.macro_objects),
-            (&applies_to.exposure_objects, &options.exposure_objects),
-            (
-                &applies_to.semantic_model_objects,
-                &options.semantic_model_objects,
-            ),
-            (&applies_to.custom_objects, &options.custom_objects),
-        ];
-        for (targets, valid) in pairs {
-            for target in targets {
-                if !valid.contains(target) {
-                    invalid_targets.push(target.as_snake_case());
-                }
-            }
-        }
-
-        if !invalid_targets.is_empty() {
-            let valid_options: Vec<String> = pairs
-                .iter()
-                .flat_map(|(_, valid)| valid.iter().map(|t| t.as_snake_case().to_string()))
-                .collect();
-
-            return Err(anyhow::anyhow!(
-                "Invalid applies_to targets: {:?} for rule type '{}'. Valid options are: {:?}",
-                invalid_targets,
+            .context("applies_to must be set before validation, so this should never happen")?
+            .validate_against(
+                &applies_to_options_for_manifest_rule(&self.rule),
                 self.rule.as_str(),
-                valid_options
-            ));
-        }
+            )
+    }
 
-        Ok(())
+    /// A rule with a warning severity and no other options set, for unit tests of rule checks.
+    #[cfg(test)]
+    pub fn for_test(rule: ManifestSpecificRuleConfig) -> Self {
+        Self {
+            name: None,
+            severity: Severity::Warning,
+            description: None,
+            applies_to: None,
+            includes: None,
+            excludes: None,
+            selection: RuleSelection::default(),
+            rule,
+        }
     }
 }
 
 // default options if applies_to is not set
+#[allow(clippy::too_many_lines)]
 pub fn default_applies_to_for_manifest_rule(rule_type: &ManifestSpecificRuleConfig) -> AppliesTo {
     match rule_type {
         // has_description
@@ -209,8 +283,10 @@ pub fn default_applies_to_for_manifest_rule(rule_type: &ManifestSpecificRuleConf
             semantic_model_objects: vec![],
             custom_objects: vec![],
         },
-        // is_not_orphaned
-        ManifestSpecificRuleConfig::IsNotOrphaned { .. } => AppliesTo {
+        // is_not_orphaned, source_fanout & source_has_freshness
+        ManifestSpecificRuleConfig::IsNotOrphaned { .. }
+        | ManifestSpecificRuleConfig::SourceFanout { .. }
+        | ManifestSpecificRuleConfig::SourceHasFreshness { .. } => AppliesTo {
             node_objects: vec![],
             source_objects: vec![RuleTarget::Sources],
             unit_test_objects: vec![],
@@ -229,7 +305,10 @@ pub fn default_applies_to_for_manifest_rule(rule_type: &ManifestSpecificRuleConf
             semantic_model_objects: vec![],
             custom_objects: vec![],
         },
-        ManifestSpecificRuleConfig::MaxCodeLines { .. } => AppliesTo {
+        ManifestSpecificRuleConfig::MaxCodeLines { .. }
+        | ManifestSpecificRuleConfig::NoSelectStar {}
+        | ManifestSpecificRuleConfig::NoDistinctWithoutComment {}
+        | ManifestSpecificRuleConfig::ForbiddenKeywords { .. } => AppliesTo {
             node_objects: vec![RuleTarget::Models, RuleTarget::Snapshots],
             source_objects: vec![],
             unit_test_objects: vec![],
@@ -240,7 +319,8 @@ pub fn default_applies_to_for_manifest_rule(rule_type: &ManifestSpecificRuleConf
         },
         // has_unique_test & has_metadata_keys
         ManifestSpecificRuleConfig::HasUniqueTest { .. }
-        | ManifestSpecificRuleConfig::HasMetadataKeys { .. } => AppliesTo {
+        | ManifestSpecificRuleConfig::HasMetadataKeys { .. }
+        | ManifestSpecificRuleConfig::TestCoverage { .. } => AppliesTo {
             node_objects: vec![RuleTarget::Models],
             source_objects: vec![RuleTarget::Sources],
             unit_test_objects: vec![],
@@ -262,10 +342,39 @@ pub fn default_applies_to_for_manifest_rule(rule_type: &ManifestSpecificRuleConf
             semantic_model_objects: vec![],
             custom_objects: vec![],
         },
+        ManifestSpecificRuleConfig::LayerDependencies { .. }
+        | ManifestSpecificRuleConfig::DirectSourceReference { .. }
+        | ManifestSpecificRuleConfig::MaxChildren { .. }
+        | ManifestSpecificRuleConfig::MaxParents { .. }
+        | ManifestSpecificRuleConfig::NoRedundantDependencies {}
+        | ManifestSpecificRuleConfig::MaxChainDepth { .. }
+        | ManifestSpecificRuleConfig::Materialization { .. }
+        | ManifestSpecificRuleConfig::PrimaryKeyTested { .. }
+        | ManifestSpecificRuleConfig::HasRelationshipTest { .. } => AppliesTo {
+            node_objects: vec![RuleTarget::Models],
+            source_objects: vec![],
+            unit_test_objects: vec![],
+            macro_objects: vec![],
+            exposure_objects: vec![],
+            semantic_model_objects: vec![],
+            custom_objects: vec![],
+        },
+        ManifestSpecificRuleConfig::NoHardcodedReferences {}
+        | ManifestSpecificRuleConfig::NoOrderBy {}
+        | ManifestSpecificRuleConfig::ImportCtes {} => AppliesTo {
+            node_objects: vec![RuleTarget::Models, RuleTarget::Snapshots],
+            source_objects: vec![],
+            unit_test_objects: vec![],
+            macro_objects: vec![],
+            exposure_objects: vec![],
+            semantic_model_objects: vec![],
+            custom_objects: vec![],
+        },
     }
 }
 
 // All options a user can choose
+#[allow(clippy::too_many_lines)]
 fn applies_to_options_for_manifest_rule(rule_type: &ManifestSpecificRuleConfig) -> AppliesTo {
     match rule_type {
         // has_description
@@ -318,7 +427,11 @@ fn applies_to_options_for_manifest_rule(rule_type: &ManifestSpecificRuleConfig)
             semantic_model_objects: vec![],
             custom_objects: vec![],
         },
-        ManifestSpecificRuleConfig::HasUniqueTest { .. } => AppliesTo {
+        ManifestSpecificRuleConfig::HasUniqueTest { .. }
+        | ManifestSpecificRuleConfig::MaxChildren { .. }
+        | ManifestSpecificRuleConfig::PrimaryKeyTested { .. }
+        | ManifestSpecificRuleConfig::TestCoverage { .. }
+        | ManifestSpecificRuleConfig::HasRelationshipTest { .. } => AppliesTo {
             node_objects: vec![RuleTarget::Models, RuleTarget::Seeds, RuleTarget::Snapshots],
             source_objects: vec![RuleTarget::Sources],
             unit_test_objects: vec![],
@@ -327,7 +440,8 @@ fn applies_to_options_for_manifest_rule(rule_type: &ManifestSpecificRuleConfig)
             semantic_model_objects: vec![],
             custom_objects: vec![],
         },
-        ManifestSpecificRuleConfig::HasContractEnforced { .. } => AppliesTo {
+        ManifestSpecificRuleConfig::HasContractEnforced { .. }
+        | ManifestSpecificRuleConfig::Materialization { .. } => AppliesTo {
             node_objects: vec![RuleTarget::Models],
             macro_objects: vec![],
             source_objects: vec![],
@@ -345,7 +459,10 @@ fn applies_to_options_for_manifest_rule(rule_type: &ManifestSpecificRuleConfig)
             semantic_model_objects: vec![RuleTarget::SemanticModels],
             custom_objects: vec![],
         },
-        ManifestSpecificRuleConfig::MaxCodeLines { .. } => AppliesTo {
+        ManifestSpecificRuleConfig::MaxCodeLines { .. }
+        | ManifestSpecificRuleConfig::NoSelectStar {}
+        | ManifestSpecificRuleConfig::NoDistinctWithoutComment {}
+        | ManifestSpecificRuleConfig::ForbiddenKeywords { .. } => AppliesTo {
             node_objects: vec![RuleTarget::Models, RuleTarget::Snapshots],
             source_objects: vec![],
             unit_test_objects: vec![],
@@ -367,5 +484,44 @@ fn applies_to_options_for_manifest_rule(rule_type: &ManifestSpecificRuleConfig)
             semantic_model_objects: vec![RuleTarget::SemanticModels],
             custom_objects: vec![],
         },
+        ManifestSpecificRuleConfig::SourceFanout { .. }
+        | ManifestSpecificRuleConfig::SourceHasFreshness { .. } => AppliesTo {
+            node_objects: vec![],
+            source_objects: vec![RuleTarget::Sources],
+            unit_test_objects: vec![],
+            macro_objects: vec![],
+            exposure_objects: vec![],
+            semantic_model_objects: vec![],
+            custom_objects: vec![],
+        },
+        ManifestSpecificRuleConfig::LayerDependencies { .. }
+        | ManifestSpecificRuleConfig::DirectSourceReference { .. }
+        | ManifestSpecificRuleConfig::MaxParents { .. }
+        | ManifestSpecificRuleConfig::NoRedundantDependencies {}
+        | ManifestSpecificRuleConfig::MaxChainDepth { .. } => AppliesTo {
+            node_objects: vec![
+                RuleTarget::Models,
+                RuleTarget::Snapshots,
+                RuleTarget::Analyses,
+            ],
+            source_objects: vec![],
+            unit_test_objects: vec![],
+            macro_objects: vec![],
+            exposure_objects: vec![],
+            semantic_model_objects: vec![],
+            custom_objects: vec![],
+        },
+        // Not available for macros, see `apply_macro_rules`
+        ManifestSpecificRuleConfig::NoHardcodedReferences {}
+        | ManifestSpecificRuleConfig::NoOrderBy {}
+        | ManifestSpecificRuleConfig::ImportCtes {} => AppliesTo {
+            node_objects: vec![RuleTarget::Models, RuleTarget::Snapshots],
+            source_objects: vec![],
+            unit_test_objects: vec![],
+            macro_objects: vec![],
+            exposure_objects: vec![],
+            semantic_model_objects: vec![],
+            custom_objects: vec![],
+        },
     }
 }
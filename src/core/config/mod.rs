@@ -1,8 +1,12 @@
 pub mod applies_to;
+pub mod changed_files;
+pub mod glob;
 pub mod includes_excludes;
 pub mod parse_config;
 pub mod severity;
 pub use parse_config::Config;
 pub mod catalog_rule;
 pub mod check_config_options;
+pub mod freshness_rule;
 pub mod manifest_rule;
+pub mod modified_state;
@@ -0,0 +1,124 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::core::config::includes_excludes::IncludeExcludable;
+use crate::core::manifest::dbt_objects::column::Column;
+use crate::core::manifest::dbt_objects::{Node, Source};
+use crate::core::manifest::{Exposure, Macro, Manifest, SemanticModel, UnitTest};
+
+/// Whether an object is unchanged compared to its version in a previous manifest,
+/// similar to dbt's `state:modified` selector.
+trait StateComparable {
+    fn same_state(&self, previous: &Self) -> bool;
+}
+
+fn column_descriptions(
+    columns: Option<&HashMap<String, Column>>,
+) -> HashMap<&str, Option<&String>> {
+    columns
+        .into_iter()
+        .flatten()
+        .map(|(name, column)| (name.as_str(), column.description.as_ref()))
+        .collect()
+}
+
+impl StateComparable for Node {
+    fn same_state(&self, previous: &Self) -> bool {
+        let (current, previous) = (self.get_base(), previous.get_base());
+        current.checksum.checksum == previous.checksum.checksum
+            && current.description == previous.description
+            && current.unrendered_config == previous.unrendered_config
+            && column_descriptions(current.columns.as_ref())
+                == column_descriptions(previous.columns.as_ref())
+    }
+}
+
+impl StateComparable for Source {
+    fn same_state(&self, previous: &Self) -> bool {
+        self.description == previous.description
+            && self.unrendered_config == previous.unrendered_config
+            && self.meta == previous.meta
+            && self.tags == previous.tags
+            && column_descriptions(self.columns.as_ref())
+                == column_descriptions(previous.columns.as_ref())
+    }
+}
+
+impl StateComparable for Macro {
+    fn same_state(&self, previous: &Self) -> bool {
+        self.macro_sql == previous.macro_sql
+            && self.description == previous.description
+            && self.meta == previous.meta
+    }
+}
+
+impl StateComparable for Exposure {
+    fn same_state(&self, previous: &Self) -> bool {
+        self.description == previous.description
+            && self.unrendered_config == previous.unrendered_config
+            && self.meta == previous.meta
+            && self.tags == previous.tags
+    }
+}
+
+impl StateComparable for SemanticModel {
+    fn same_state(&self, previous: &Self) -> bool {
+        self.description == previous.description
+            && self.unrendered_config == previous.unrendered_config
+    }
+}
+
+impl StateComparable for UnitTest {
+    fn same_state(&self, previous: &Self) -> bool {
+        self.checksum == previous.checksum && self.description == previous.description
+    }
+}
+
+fn modified<'a, T: StateComparable>(
+    current: &'a HashMap<String, T>,
+    previous: &'a HashMap<String, T>,
+) -> impl Iterator<Item = String> + 'a {
+    current
+        .iter()
+        .filter(|(unique_id, object)| {
+            previous
+                .get(*unique_id)
+                .is_none_or(|previous| !object.same_state(previous))
+        })
+        .map(|(unique_id, _)| unique_id.clone())
+}
+
+/// Objects that are new or modified compared to a previous manifest.
+/// Used by `--state` to only evaluate rules against new work.
+#[derive(Debug, Default, Clone)]
+pub struct ModifiedState {
+    unique_ids: HashSet<String>,
+}
+
+impl ModifiedState {
+    pub fn compare(current: &Manifest, previous: &Manifest) -> Self {
+        Self {
+            unique_ids: modified(&current.nodes, &previous.nodes)
+                .chain(modified(&current.sources, &previous.sources))
+                .chain(modified(&current.macros, &previous.macros))
+                .chain(modified(&current.exposures, &previous.exposures))
+                .chain(modified(
+                    &current.semantic_models,
+                    &previous.semantic_models,
+                ))
+                .chain(modified(&current.unit_tests, &previous.unit_tests))
+                .collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.unique_ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.unique_ids.is_empty()
+    }
+
+    pub fn contains<T: IncludeExcludable>(&self, object: &T) -> bool {
+        self.unique_ids.contains(object.get_unique_id())
+    }
+}
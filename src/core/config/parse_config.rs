@@ -1,12 +1,17 @@
+use crate::core::config::changed_files::ChangedFiles;
+use crate::core::config::includes_excludes::IncludeExcludable;
 use crate::core::config::manifest_rule::ManifestRule;
+use crate::core::config::modified_state::ModifiedState;
 use crate::core::config::{
     catalog_rule::default_applies_to_for_catalog_rule, catalog_rule::CatalogRule,
+    freshness_rule::default_applies_to_for_freshness_rule, freshness_rule::FreshnessRule,
     manifest_rule::default_applies_to_for_manifest_rule,
 };
 use crate::core::utils::unwrap_or_exit;
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
 use serde::Deserialize;
+use std::collections::HashSet;
 use std::fs::File;
 use std::path::Path;
 
@@ -48,6 +53,16 @@ impl ConfigFile {
 pub struct Config {
     pub manifest_tests: Option<Vec<ManifestRule>>,
     pub catalog_tests: Option<Vec<CatalogRule>>,
+    pub freshness_tests: Option<Vec<FreshnessRule>>,
+    /// Set by `--changed-since`, only objects defined in these files are evaluated
+    #[serde(skip)]
+    pub changed_files: Option<ChangedFiles>,
+    /// Set by `--state`, only objects new or modified compared to the previous manifest are evaluated
+    #[serde(skip)]
+    pub modified_state: Option<ModifiedState>,
+    /// Set by `--select` and `--exclude`, unique ids of the objects that are evaluated
+    #[serde(skip)]
+    pub selected: Option<HashSet<String>>,
 }
 
 #[derive(Deserialize)]
@@ -61,6 +76,22 @@ struct Tool {
 }
 
 impl Config {
+    /// Whether an object should be evaluated in this run, regardless of the rule.
+    /// Objects have to pass `--changed-since`, `--state` and `--select`/`--exclude` when given.
+    pub fn in_scope<T: IncludeExcludable>(&self, object: &T) -> bool {
+        self.changed_files
+            .as_ref()
+            .is_none_or(|changed_files| changed_files.contains(object))
+            && self
+                .modified_state
+                .as_ref()
+                .is_none_or(|modified_state| modified_state.contains(object))
+            && self
+                .selected
+                .as_ref()
+                .is_none_or(|selected| selected.contains(object.get_unique_id()))
+    }
+
     /// Finds and selects the appropriate config file from a directory.
     /// Searches for config files in the following preference order:
     /// 1. dbtective.yml or dbtective.yaml (highest priority)
@@ -217,15 +248,13 @@ impl Config {
         }
     }
 
-    // 1. Apply default applies_to if not specified
-    // 2. Normalize the includes/excludes paths
+    // Apply default applies_to if not specified
     pub fn clean_config(&mut self) {
         if let Some(rules) = &mut self.manifest_tests {
             for rule in rules {
                 if rule.applies_to.is_none() {
                     rule.applies_to = Some(default_applies_to_for_manifest_rule(&rule.rule));
                 }
-                rule.normalize_includes_excludes();
             }
         }
         if let Some(rules) = &mut self.catalog_tests {
@@ -233,7 +262,13 @@ impl Config {
                 if rule.applies_to.is_none() {
                     rule.applies_to = Some(default_applies_to_for_catalog_rule(&rule.rule));
                 }
-                rule.normalize_includes_excludes();
+            }
+        }
+        if let Some(rules) = &mut self.freshness_tests {
+            for rule in rules {
+                if rule.applies_to.is_none() {
+                    rule.applies_to = Some(default_applies_to_for_freshness_rule(&rule.rule));
+                }
             }
         }
     }
@@ -247,11 +282,20 @@ impl Config {
         if let Some(rules) = &self.manifest_tests {
             for rule in rules {
                 rule.validate_applies_to()?;
+                rule.rule.validate()?;
+                rule.selection.validate()?;
             }
         }
         if let Some(rules) = &self.catalog_tests {
             for rule in rules {
                 rule.validate_applies_to()?;
+                rule.selection.validate()?;
+            }
+        }
+        if let Some(rules) = &self.freshness_tests {
+            for rule in rules {
+                rule.validate_applies_to()?;
+                rule.selection.validate()?;
             }
         }
         Ok(())
@@ -0,0 +1,175 @@
+// Documentation: https://docs.getdbt.com/reference/artifacts/sources-json
+// sources.json is produced by running dbt source freshness
+// Versions 1 up to 3 are supported, they share the fields used here
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+const SUPPORTED_SOURCES_VERSIONS: [&str; 3] = [
+    "https://schemas.getdbt.com/dbt/sources/v1.json",
+    "https://schemas.getdbt.com/dbt/sources/v2.json",
+    "https://schemas.getdbt.com/dbt/sources/v3.json",
+];
+
+/// Check if the sources.json version is supported
+/// # Errors
+/// Returns an error if the sources.json version is not supported
+pub fn check_sources_version(dbt_schema_version: &str) -> Result<()> {
+    if SUPPORTED_SOURCES_VERSIONS.contains(&dbt_schema_version) {
+        return Ok(());
+    }
+    anyhow::bail!(
+        "Unsupported sources.json schema version: {dbt_schema_version}, expected version 1 to 3. Please regenerate it using 'dbt source freshness' see: \x1b]8;;https://docs.getdbt.com/reference/artifacts/sources-json\x1b\\dbt sources documentation\x1b]8;;\x1b\\."
+    )
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FreshnessStatus {
+    Pass,
+    Warn,
+    Error,
+    #[serde(rename = "runtime error")]
+    RuntimeError,
+}
+
+impl FreshnessStatus {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Warn => "warn",
+            Self::Error => "error",
+            Self::RuntimeError => "runtime error",
+        }
+    }
+}
+
+// The result of a single source, runtime errors only have `unique_id`, `status` and `error`
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct SourceFreshnessResult {
+    pub unique_id: String,
+    pub status: FreshnessStatus,
+    pub max_loaded_at: Option<String>,
+    pub snapshotted_at: Option<String>,
+    pub max_loaded_at_time_ago_in_s: Option<f64>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct FreshnessResults {
+    pub metadata: SourcesMetadata,
+    pub results: Vec<SourceFreshnessResult>,
+    pub elapsed_time: Option<f64>,
+}
+
+impl FreshnessResults {
+    /// Load and parse a dbt sources.json file from the given path
+    /// # Errors
+    /// Returns an error if the file cannot be opened or parsed
+    pub fn from_file<P: AsRef<Path>>(sources_path: P) -> Result<Self> {
+        let sources_path = sources_path.as_ref();
+
+        let file = File::open(sources_path).context(format!(
+            "Unable to open sources file at {}, generate it using 'dbt source freshness'",
+            sources_path.display()
+        ))?;
+
+        let reader = BufReader::new(file);
+
+        let mut de = serde_json::Deserializer::from_reader(reader);
+        let results: Self = serde_path_to_error::deserialize(&mut de).context(format!(
+            "Unable to parse sources JSON, delete it from {} and regenerate using 'dbt source freshness'\nSee: \x1b]8;;https://docs.getdbt.com/reference/artifacts/sources-json\x1b\\dbt sources documentation\x1b]8;;\x1b\\",
+            sources_path.display()
+        ))?;
+
+        check_sources_version(&results.metadata.dbt_schema_version)?;
+
+        Ok(results)
+    }
+
+    /// The freshness results by the unique id of their source
+    pub fn by_unique_id(&self) -> HashMap<&str, &SourceFreshnessResult> {
+        self.results
+            .iter()
+            .map(|result| (result.unique_id.as_str(), result))
+            .collect()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct SourcesMetadata {
+    pub dbt_schema_version: String,
+    pub dbt_version: String,
+    pub generated_at: String,
+    pub invocation_id: Option<String>,
+    pub env: Option<HashMap<String, String>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_sources_version() {
+        assert!(check_sources_version("https://schemas.getdbt.com/dbt/sources/v3.json").is_ok());
+        assert!(check_sources_version("https://schemas.getdbt.com/dbt/sources/v4.json").is_err());
+    }
+
+    #[test]
+    fn test_deserialize_sources() {
+        let json_str = r#"
+        {
+            "metadata": {
+                "dbt_schema_version": "https://schemas.getdbt.com/dbt/sources/v3.json",
+                "dbt_version": "1.10.0",
+                "generated_at": "2025-01-01T00:00:00Z",
+                "invocation_id": "test-invocation",
+                "env": {}
+            },
+            "results": [
+                {
+                    "unique_id": "source.test.raw.orders",
+                    "max_loaded_at": "2025-01-01T00:00:00+00:00",
+                    "snapshotted_at": "2025-01-01T06:00:00+00:00",
+                    "max_loaded_at_time_ago_in_s": 21600.0,
+                    "status": "warn",
+                    "criteria": {
+                        "warn_after": {"count": 1, "period": "hour"},
+                        "error_after": {"count": null, "period": null},
+                        "filter": null
+                    },
+                    "adapter_response": {},
+                    "timing": [],
+                    "thread_id": "Thread-1",
+                    "execution_time": 0.1
+                },
+                {
+                    "unique_id": "source.test.raw.customers",
+                    "error": "Database Error: relation does not exist",
+                    "status": "runtime error"
+                }
+            ],
+            "elapsed_time": 0.5
+        }
+        "#;
+
+        let results: FreshnessResults = serde_json::from_str(json_str).unwrap();
+        let by_unique_id = results.by_unique_id();
+        assert_eq!(
+            by_unique_id["source.test.raw.orders"].status,
+            FreshnessStatus::Warn
+        );
+        assert_eq!(
+            by_unique_id["source.test.raw.customers"].status,
+            FreshnessStatus::RuntimeError
+        );
+        assert!(by_unique_id["source.test.raw.customers"].error.is_some());
+    }
+}
@@ -0,0 +1,84 @@
+use serde::Deserialize;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FreshnessPeriod {
+    Minute,
+    Hour,
+    Day,
+}
+
+impl FreshnessPeriod {
+    pub const fn minutes(self) -> u64 {
+        match self {
+            Self::Minute => 1,
+            Self::Hour => 60,
+            Self::Day => 60 * 24,
+        }
+    }
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Minute => "minute",
+            Self::Hour => "hour",
+            Self::Day => "day",
+        }
+    }
+}
+
+// dbt writes `{count: null, period: null}` for thresholds that are not set
+#[derive(Debug, Clone, Deserialize)]
+pub struct FreshnessThreshold {
+    pub count: Option<u64>,
+    pub period: Option<FreshnessPeriod>,
+}
+
+impl FreshnessThreshold {
+    /// The threshold in minutes, `None` if it is not set
+    pub const fn minutes(&self) -> Option<u64> {
+        match (self.count, self.period) {
+            (Some(count), Some(period)) if count > 0 => Some(count * period.minutes()),
+            _ => None,
+        }
+    }
+
+    pub const fn is_set(&self) -> bool {
+        self.minutes().is_some()
+    }
+}
+
+impl fmt::Display for FreshnessThreshold {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.count, self.period) {
+            (Some(count), Some(period)) => write!(f, "{count} {}(s)", period.as_str()),
+            _ => write!(f, "not set"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[allow(dead_code)]
+pub struct FreshnessPolicy {
+    pub warn_after: Option<FreshnessThreshold>,
+    pub error_after: Option<FreshnessThreshold>,
+    pub filter: Option<String>,
+}
+
+impl FreshnessPolicy {
+    pub fn warn_after(&self) -> Option<&FreshnessThreshold> {
+        self.warn_after
+            .as_ref()
+            .filter(|threshold| threshold.is_set())
+    }
+
+    pub fn error_after(&self) -> Option<&FreshnessThreshold> {
+        self.error_after
+            .as_ref()
+            .filter(|threshold| threshold.is_set())
+    }
+
+    pub fn is_set(&self) -> bool {
+        self.warn_after().is_some() || self.error_after().is_some()
+    }
+}
@@ -1,9 +1,22 @@
 use serde::Deserialize;
 use serde_json::Value;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, PartialEq, Eq)]
 pub struct Meta(pub Value);
 
+/// Config block of objects of which only the `meta` is used
+#[derive(Debug, Deserialize)]
+pub struct MetaConfig {
+    pub meta: Option<Meta>,
+}
+
+/// A rule disabled through `meta: {dbtective: {disable: [...]}}`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisabledRule {
+    /// Justification supplied by the user, if any
+    pub reason: Option<String>,
+}
+
 impl Meta {
     pub fn missing_keys<'a>(&self, required_keys: &'a [String]) -> Vec<&'a String> {
         match &self.0 {
@@ -14,4 +27,90 @@ impl Meta {
             _ => required_keys.iter().collect(),
         }
     }
+
+    /// Whether the object opts out of a rule, matched on the rule name or rule type.
+    /// Entries of `disable` are either rule names or `{rule: <name>, reason: <justification>}`,
+    /// a `reason` next to `disable` applies to all entries without one.
+    pub fn disabled_rule(&self, rule_name: &str, rule_type: &str) -> Option<DisabledRule> {
+        let dbtective = self.0.get("dbtective")?;
+        let reason = dbtective.get("reason").and_then(Value::as_str);
+
+        let matches = |rule: &str| rule == rule_name || rule == rule_type;
+        let entries = match dbtective.get("disable")? {
+            Value::Array(entries) => entries.iter().collect(),
+            entry => vec![entry],
+        };
+
+        entries.into_iter().find_map(|entry| match entry {
+            Value::String(rule) if matches(rule) => Some(DisabledRule {
+                reason: reason.map(ToString::to_string),
+            }),
+            Value::Object(entry)
+                if entry
+                    .get("rule")
+                    .and_then(Value::as_str)
+                    .is_some_and(matches) =>
+            {
+                Some(DisabledRule {
+                    reason: entry
+                        .get("reason")
+                        .and_then(Value::as_str)
+                        .or(reason)
+                        .map(ToString::to_string),
+                })
+            }
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_disabled_rule_names() {
+        let meta = Meta(json!({"dbtective": {"disable": ["has_description", "max_lines"]}}));
+
+        assert_eq!(
+            meta.disabled_rule("has_description", "has_description"),
+            Some(DisabledRule { reason: None })
+        );
+        // Custom rule names and rule types both match
+        assert!(meta.disabled_rule("max_lines", "max_code_lines").is_some());
+        assert!(meta.disabled_rule("has_refs", "has_refs").is_none());
+    }
+
+    #[test]
+    fn test_disabled_rule_reasons() {
+        let meta = Meta(json!({"dbtective": {
+            "disable": [
+                "has_refs",
+                {"rule": "max_code_lines", "reason": "Generated by a macro"}
+            ],
+            "reason": "Legacy model"
+        }}));
+
+        assert_eq!(
+            meta.disabled_rule("has_refs", "has_refs").unwrap().reason,
+            Some("Legacy model".to_string())
+        );
+        assert_eq!(
+            meta.disabled_rule("max_code_lines", "max_code_lines")
+                .unwrap()
+                .reason,
+            Some("Generated by a macro".to_string())
+        );
+    }
+
+    #[test]
+    fn test_no_disabled_rules() {
+        assert!(Meta(json!({"owner": "data"}))
+            .disabled_rule("has_refs", "has_refs")
+            .is_none());
+        assert!(Meta(json!({"dbtective": {"disable": "has_refs"}}))
+            .disabled_rule("has_refs", "has_refs")
+            .is_some());
+    }
 }
@@ -1,12 +1,13 @@
 pub mod column;
 pub mod docs;
+pub mod freshness;
 pub mod meta;
 pub mod nodes;
 pub mod source;
 pub mod tags;
 
 // pub use docs::NodeDocs;
-pub use meta::Meta;
+pub use meta::{Meta, MetaConfig};
 pub use nodes::node::Node;
 pub use source::Source;
 pub use tags::Tags;
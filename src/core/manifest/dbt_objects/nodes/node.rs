@@ -3,7 +3,7 @@ use super::super::{Meta, Tags};
 use super::{Analysis, HookNode, Model, Seed, Snapshot, SqlOperation, Test};
 use crate::core::config::applies_to::RuleTarget;
 use crate::core::config::includes_excludes::IncludeExcludable;
-use crate::core::manifest::Manifest;
+use crate::core::manifest::{strip_package, Manifest};
 use crate::core::rules::common_traits::Columnable;
 use crate::core::rules::rule_config::child_map::ChildMappable;
 use crate::core::rules::rule_config::has_contract_enforced::ContractAble;
@@ -12,11 +12,19 @@ use crate::core::rules::rule_config::has_metadata_keys::HasMetadata;
 use crate::core::rules::rule_config::has_refs::CanReference;
 use crate::core::rules::rule_config::has_tags::Tagable;
 use crate::core::rules::rule_config::has_unique_test::TestAble;
+use crate::core::rules::rule_config::layer_dependencies::Layerable;
+use crate::core::rules::rule_config::materialization::Materializable;
 use crate::core::rules::rule_config::max_code_lines::HasCode;
+use crate::core::rules::rule_config::max_dependencies::HasLineage;
 use crate::core::rules::rule_config::name_convention::NameAble;
+use crate::core::rules::rule_config::primary_key_tested::HasPrimaryKey;
+use crate::core::rules::suppression::Suppressible;
 use serde::Deserialize;
 use std::collections::HashMap;
 
+/// dbt materializes models as views unless configured otherwise
+pub const DEFAULT_MATERIALIZATION: &str = "view";
+
 #[derive(Debug, Deserialize)]
 #[serde(tag = "resource_type")]
 #[allow(dead_code)]
@@ -98,6 +106,21 @@ impl Node {
     pub const fn get_relative_path(&self) -> &String {
         &self.get_base().original_file_path
     }
+
+    /// Path of the YAML property file describing this node, if any
+    pub fn get_patch_path(&self) -> Option<&str> {
+        strip_package(self.get_base().patch_path.as_ref()?)
+    }
+
+    /// The `materialized` config, e.g. `view`, `table`, `incremental` or `ephemeral`
+    pub fn get_materialized(&self) -> Option<&str> {
+        self.get_base().config.as_ref()?.materialized.as_deref()
+    }
+
+    /// The `materialized` config, or dbt's default when it is not set
+    pub fn get_materialized_or_default(&self) -> &str {
+        self.get_materialized().unwrap_or(DEFAULT_MATERIALIZATION)
+    }
 }
 
 impl NameAble for Node {
@@ -151,12 +174,28 @@ impl IncludeExcludable for Node {
     fn get_relative_path(&self) -> &String {
         &self.get_base().original_file_path
     }
+
+    fn get_unique_id(&self) -> &String {
+        self.get_unique_id()
+    }
+
+    fn get_schema_path(&self) -> Option<&str> {
+        self.get_patch_path()
+    }
 }
 
 impl IncludeExcludable for &Node {
     fn get_relative_path(&self) -> &String {
         (*self).get_relative_path()
     }
+
+    fn get_unique_id(&self) -> &String {
+        (*self).get_unique_id()
+    }
+
+    fn get_schema_path(&self) -> Option<&str> {
+        (*self).get_patch_path()
+    }
 }
 
 impl Descriptable for Node {
@@ -232,6 +271,10 @@ impl HasCode for Node {
     fn get_object_type(&self) -> &str {
         self.get_object_type()
     }
+
+    fn get_unique_id(&self) -> Option<&str> {
+        Some(self.get_unique_id())
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -250,7 +293,27 @@ pub struct DependsOn {
 
 #[derive(Debug, Deserialize)]
 pub struct NodeConfig {
+    pub materialized: Option<String>,
+    pub incremental_strategy: Option<String>,
+    pub unique_key: Option<UniqueKey>,
     pub contract: Option<Contract>,
+    pub meta: Option<Meta>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum UniqueKey {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+impl UniqueKey {
+    pub const fn is_empty(&self) -> bool {
+        match self {
+            Self::Single(key) => key.is_empty(),
+            Self::Multiple(keys) => keys.is_empty(),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -283,12 +346,12 @@ pub struct NodeBase {
     pub config: Option<NodeConfig>,
     pub depends_on: DependsOn,
     pub raw_code: Option<String>,
+    pub patch_path: Option<String>,
+    pub unrendered_config: Option<serde_json::Value>,
     // Currently unused fields that do exist in the data
     // pub group: Option<String>,
     // pub docs: Option<NodeDocs>,
-    // pub patch_path: Option<String>,
     // pub build_path: Option<String>,
-    // pub unrendered_config: Option<serde_json::Value>,
     // pub created_at: Option<f64>,
     // pub config_call_dict: Option<serde_json::Value>,
     // pub unrendered_config_call_dict: Option<serde_json::Value>,
@@ -370,6 +433,15 @@ impl TestAble for Node {
     }
 }
 
+impl HasPrimaryKey for Node {
+    fn get_primary_key(&self) -> Option<&Vec<String>> {
+        match self {
+            Self::Model(model) => model.primary_key.as_ref(),
+            _ => None,
+        }
+    }
+}
+
 // Only for model this works though
 impl ContractAble for Node {
     fn get_contract_enforced(&self) -> Option<bool> {
@@ -417,6 +489,16 @@ impl ContractAble for &Node {
     }
 }
 
+impl Suppressible for Node {
+    fn get_metas(&self) -> Vec<&Meta> {
+        let base = self.get_base();
+        base.meta
+            .iter()
+            .chain(base.config.as_ref().and_then(|config| config.meta.as_ref()))
+            .collect()
+    }
+}
+
 impl HasMetadata for Node {
     fn get_metadata(&self) -> Option<&Meta> {
         self.get_base().meta.as_ref()
@@ -461,3 +543,97 @@ impl CanReference for Node {
         Some(self.get_relative_path())
     }
 }
+
+impl Layerable for Node {
+    fn get_unique_id(&self) -> &str {
+        self.get_unique_id()
+    }
+
+    fn get_name(&self) -> &str {
+        self.get_name()
+    }
+
+    fn get_fqn(&self) -> &[String] {
+        &self.get_base().fqn
+    }
+
+    fn get_relative_path(&self) -> &String {
+        self.get_relative_path()
+    }
+
+    fn get_object_type(&self) -> &str {
+        self.get_object_type()
+    }
+
+    fn get_depends_on_nodes(&self) -> &[String] {
+        self.get_base()
+            .depends_on
+            .nodes
+            .as_deref()
+            .unwrap_or_default()
+    }
+}
+
+impl HasLineage for Node {
+    fn get_unique_id(&self) -> &str {
+        self.get_unique_id()
+    }
+
+    fn get_object_type(&self) -> &str {
+        self.get_object_type()
+    }
+
+    fn get_object_string(&self) -> &str {
+        self.get_name()
+    }
+
+    fn get_relative_path(&self) -> Option<&String> {
+        Some(&self.get_base().original_file_path)
+    }
+}
+
+impl Materializable for Node {
+    fn get_name(&self) -> &str {
+        self.get_name()
+    }
+
+    fn get_object_type(&self) -> &str {
+        self.get_object_type()
+    }
+
+    fn get_relative_path(&self) -> &String {
+        self.get_relative_path()
+    }
+
+    fn get_fqn(&self) -> &[String] {
+        &self.get_base().fqn
+    }
+
+    fn get_materialized_or_default(&self) -> &str {
+        self.get_materialized_or_default()
+    }
+
+    fn get_incremental_strategy(&self) -> Option<&str> {
+        self.get_base()
+            .config
+            .as_ref()?
+            .incremental_strategy
+            .as_deref()
+    }
+
+    fn has_unique_key(&self) -> bool {
+        self.get_base()
+            .config
+            .as_ref()
+            .and_then(|config| config.unique_key.as_ref())
+            .is_some_and(|key| !key.is_empty())
+    }
+
+    fn has_explicit_config(&self, key: &str) -> bool {
+        self.get_base()
+            .unrendered_config
+            .as_ref()
+            .and_then(|config| config.get(key))
+            .is_some_and(|value| !value.is_null())
+    }
+}
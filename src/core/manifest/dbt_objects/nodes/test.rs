@@ -1,5 +1,6 @@
 use super::node::{CompiledNodeFields, NodeBase};
 use serde::Deserialize;
+use serde_json::Value;
 use std::borrow::Cow;
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
@@ -19,7 +20,6 @@ pub struct Test {
 #[derive(Debug, Deserialize)]
 pub struct TestMetadata {
     pub name: String,
-    #[allow(dead_code)]
     pub kwargs: Option<serde_json::Value>,
     pub namespace: Option<String>,
 }
@@ -27,15 +27,56 @@ pub struct TestMetadata {
 impl Test {
     // This actually contains the type of test the test originally is
     // e.g. unique, not the name of the test as given by the user
-    // Not the name the user has given it
+    // Tests from packages are namespaced as `namespace.name` (e.g. `dbt_utils.unique_combination_of_columns`),
+    // matching how they are written in the dbtective config
     pub fn get_metadata_name(&self) -> Option<Cow<'_, str>> {
         let metadata = self.test_metadata.as_ref()?;
 
         Some(metadata.namespace.as_ref().map_or_else(
             || Cow::Borrowed(metadata.name.as_str()), // Borrowed if no namespace
-            |ns| Cow::Owned(format!("{}::{}", ns, metadata.name)), // Owned if namespaced
+            |ns| Cow::Owned(format!("{}.{}", ns, metadata.name)), // Owned if namespaced
         ))
     }
+
+    /// Whether the test is one of `names`, see [`Self::get_metadata_name`]
+    pub fn is_any_of(&self, names: &[String]) -> bool {
+        self.get_metadata_name()
+            .is_some_and(|test_name| names.iter().any(|name| *name == test_name))
+    }
+
+    /// The columns the test is defined on: a column test, or a model level test
+    /// with `combination_of_columns` (`dbt_utils`) or `column_list` (`dbt_expectations`)
+    pub fn get_tested_columns(&self) -> Option<Vec<String>> {
+        let kwargs = self
+            .test_metadata
+            .as_ref()
+            .and_then(|metadata| metadata.kwargs.as_ref());
+        ["combination_of_columns", "column_list", "column_name"]
+            .iter()
+            .find_map(|key| {
+                kwargs
+                    .and_then(|kwargs| kwargs.get(key))
+                    .and_then(string_list)
+            })
+            .or_else(|| self.column_name.as_ref().map(|column| vec![column.clone()]))
+    }
+}
+
+/// A column name or a list of column names
+pub fn string_list(value: &Value) -> Option<Vec<String>> {
+    match value {
+        Value::String(column) => Some(vec![column.clone()]),
+        Value::Array(columns) => columns
+            .iter()
+            .map(|column| column.as_str().map(ToString::to_string))
+            .collect(),
+        _ => None,
+    }
+}
+
+/// Column names are compared regardless of case and quoting
+pub fn normalize_column_name(column: &str) -> String {
+    column.trim().trim_matches('"').to_lowercase()
 }
 
 #[cfg(test)]
@@ -62,6 +103,8 @@ mod tests {
                         checksum: String::new(),
                     },
                     raw_code: None,
+                    patch_path: None,
+                    unrendered_config: None,
                     tags: None,
                     meta: None,
                     columns: None,
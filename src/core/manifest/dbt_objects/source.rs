@@ -7,7 +7,7 @@ use super::tags::Tags;
 use crate::core::{
     config::{applies_to::RuleTarget, includes_excludes::IncludeExcludable},
     manifest::{
-        dbt_objects::{column::Column, Meta},
+        dbt_objects::{column::Column, freshness::FreshnessPolicy, Meta},
         Manifest,
     },
     rules::{
@@ -15,8 +15,10 @@ use crate::core::{
         rule_config::{
             child_map::ChildMappable, has_description::Descriptable,
             has_metadata_keys::HasMetadata, has_tags::Tagable, has_unique_test::TestAble,
-            name_convention::NameAble,
+            layer_dependencies::Layerable, max_dependencies::HasLineage, name_convention::NameAble,
+            primary_key_tested::HasPrimaryKey,
         },
+        suppression::Suppressible,
     },
 };
 use serde::Deserialize;
@@ -35,24 +37,24 @@ pub struct Source {
     // pub path: String,
     pub original_file_path: String,
     pub unique_id: String,
-    // pub fqn: Vec<String>,
-    // pub source_name: String,
+    pub fqn: Option<Vec<String>>,
+    pub source_name: String,
     // pub loader: String,
     // pub identifier: String,
 
     // // Optional fields
     // pub quoting: Option<serde_json::Value>,
-    // pub loaded_at_field: Option<String>,
-    // pub loaded_at_query: Option<String>,
-    // pub freshness: Option<serde_json::Value>,
+    pub loaded_at_field: Option<String>,
+    pub loaded_at_query: Option<String>,
+    pub freshness: Option<FreshnessPolicy>,
     // pub external: Option<serde_json::Value>,
     pub columns: Option<HashMap<String, Column>>,
     pub meta: Option<Meta>,
     // pub source_meta: Option<Meta>,
     pub tags: Option<Tags>,
-    // pub config: Option<serde_json::Value>,
+    pub config: Option<SourceConfig>,
     // pub patch_path: Option<String>,
-    // pub unrendered_config: Option<serde_json::Value>,
+    pub unrendered_config: Option<serde_json::Value>,
     // pub relation_name: Option<String>,
     // pub created_at: Option<f64>,
     // pub unrendered_database: Option<String>,
@@ -60,6 +62,14 @@ pub struct Source {
     // pub doc_blocks: Option<Vec<String>>,
 }
 
+// Properties set under `config` of the source block or table, e.g. `config: {loaded_at_field: ...}`
+#[derive(Debug, Deserialize)]
+pub struct SourceConfig {
+    pub loaded_at_field: Option<String>,
+    pub loaded_at_query: Option<String>,
+    pub freshness: Option<FreshnessPolicy>,
+}
+
 impl Source {
     pub const fn get_name(&self) -> &String {
         &self.name
@@ -85,18 +95,67 @@ impl Source {
     pub const fn get_unique_id(&self) -> &String {
         &self.unique_id
     }
+
+    pub const fn get_source_name(&self) -> &String {
+        &self.source_name
+    }
+
+    /// The `loaded_at_field` or `loaded_at_query` of the table,
+    /// falling back to its `config` where dbt puts the ones set in the config of the source block
+    pub fn get_loaded_at(&self) -> Option<&String> {
+        let config = self.config.as_ref();
+        [
+            self.loaded_at_field.as_ref(),
+            self.loaded_at_query.as_ref(),
+            config.and_then(|config| config.loaded_at_field.as_ref()),
+            config.and_then(|config| config.loaded_at_query.as_ref()),
+        ]
+        .into_iter()
+        .flatten()
+        .find(|loaded_at| !loaded_at.trim().is_empty())
+    }
+
+    /// The freshness of the table, dbt already merges the freshness of the source block into it.
+    ///
+    /// dbt always writes the top-level `freshness`, with empty thresholds when it is only set in the `config`,
+    /// so the first policy with a threshold is used.
+    pub fn get_freshness(&self) -> Option<&FreshnessPolicy> {
+        [
+            self.freshness.as_ref(),
+            self.config
+                .as_ref()
+                .and_then(|config| config.freshness.as_ref()),
+        ]
+        .into_iter()
+        .flatten()
+        .find(|freshness| freshness.is_set())
+    }
+}
+
+impl Suppressible for Source {
+    fn get_metas(&self) -> Vec<&Meta> {
+        self.meta.iter().collect()
+    }
 }
 
 impl IncludeExcludable for Source {
     fn get_relative_path(&self) -> &String {
         self.get_relative_path()
     }
+
+    fn get_unique_id(&self) -> &String {
+        self.get_unique_id()
+    }
 }
 
 impl IncludeExcludable for &Source {
     fn get_relative_path(&self) -> &String {
         (*self).get_relative_path()
     }
+
+    fn get_unique_id(&self) -> &String {
+        (*self).get_unique_id()
+    }
 }
 
 impl Descriptable for Source {
@@ -178,6 +237,46 @@ impl Columnable for Source {
     }
 }
 
+impl Layerable for Source {
+    fn get_unique_id(&self) -> &str {
+        self.get_unique_id()
+    }
+
+    fn get_name(&self) -> &str {
+        self.get_name()
+    }
+
+    fn get_fqn(&self) -> &[String] {
+        self.fqn.as_deref().unwrap_or_default()
+    }
+
+    fn get_relative_path(&self) -> &String {
+        self.get_relative_path()
+    }
+
+    fn get_object_type(&self) -> &str {
+        Self::get_object_type()
+    }
+}
+
+impl HasLineage for Source {
+    fn get_unique_id(&self) -> &str {
+        self.get_unique_id()
+    }
+
+    fn get_object_type(&self) -> &str {
+        Self::get_object_type()
+    }
+
+    fn get_object_string(&self) -> &str {
+        self.get_name()
+    }
+
+    fn get_relative_path(&self) -> Option<&String> {
+        Some(self.get_relative_path())
+    }
+}
+
 impl ChildMappable for Source {
     fn get_object_type(&self) -> &str {
         Self::get_object_type()
@@ -219,6 +318,13 @@ impl TestAble for Source {
     }
 }
 
+// Sources have no `primary_key` property, it has to come from the meta key or tests
+impl HasPrimaryKey for Source {
+    fn get_primary_key(&self) -> Option<&Vec<String>> {
+        None
+    }
+}
+
 impl HasMetadata for Source {
     fn get_metadata(&self) -> Option<&Meta> {
         self.meta.as_ref()
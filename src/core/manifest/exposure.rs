@@ -2,10 +2,13 @@ use serde::Deserialize;
 
 use crate::core::{
     config::{applies_to::RuleTarget, includes_excludes::IncludeExcludable},
-    manifest::dbt_objects::{Meta, Tags},
-    rules::rule_config::{
-        has_description::Descriptable, has_metadata_keys::HasMetadata, has_refs::CanReference,
-        has_tags::Tagable, name_convention::NameAble,
+    manifest::dbt_objects::{Meta, MetaConfig, Tags},
+    rules::{
+        rule_config::{
+            has_description::Descriptable, has_metadata_keys::HasMetadata, has_refs::CanReference,
+            has_tags::Tagable, name_convention::NameAble,
+        },
+        suppression::Suppressible,
     },
 };
 
@@ -31,8 +34,8 @@ pub struct Exposure {
     pub package_name: String,
     // pub path: String,
     pub original_file_path: String,
-    // pub unique_id: String,
-    // pub fqn: Vec<String>,
+    pub unique_id: String,
+    pub fqn: Option<Vec<String>>,
     // #[serde(rename = "type")]
     // pub exposure_type: String,
     // pub owner: ExposureOwner,
@@ -41,8 +44,8 @@ pub struct Exposure {
     // pub maturity: Option<String>,
     pub meta: Option<Meta>,
     pub tags: Option<Tags>,
-    // pub config: Option<serde_json::Value>,
-    // pub unrendered_config: Option<serde_json::Value>,
+    pub config: Option<MetaConfig>,
+    pub unrendered_config: Option<serde_json::Value>,
     // pub url: Option<String>,
     pub depends_on: ExposureDependsOn,
     // pub refs: Option<Vec<serde_json::Value>>,
@@ -72,18 +75,39 @@ impl Exposure {
     pub const fn get_relative_path(&self) -> &String {
         &self.original_file_path
     }
+
+    pub const fn get_unique_id(&self) -> &String {
+        &self.unique_id
+    }
+}
+
+impl Suppressible for Exposure {
+    fn get_metas(&self) -> Vec<&Meta> {
+        self.meta
+            .iter()
+            .chain(self.config.as_ref().and_then(|config| config.meta.as_ref()))
+            .collect()
+    }
 }
 
 impl IncludeExcludable for Exposure {
     fn get_relative_path(&self) -> &String {
         self.get_relative_path()
     }
+
+    fn get_unique_id(&self) -> &String {
+        self.get_unique_id()
+    }
 }
 
 impl IncludeExcludable for &Exposure {
     fn get_relative_path(&self) -> &String {
         (*self).get_relative_path()
     }
+
+    fn get_unique_id(&self) -> &String {
+        (*self).get_unique_id()
+    }
 }
 
 impl Descriptable for Exposure {
@@ -2,11 +2,12 @@ use serde::Deserialize;
 
 use crate::core::{
     config::{applies_to::RuleTarget, includes_excludes::IncludeExcludable},
-    manifest::dbt_objects::Meta,
+    manifest::{dbt_objects::Meta, strip_package},
     rules::rule_config::{
         has_description::Descriptable, has_metadata_keys::HasMetadata, max_code_lines::HasCode,
         name_convention::NameAble,
     },
+    rules::suppression::Suppressible,
 };
 
 // #[derive(Debug, Deserialize)]
@@ -31,13 +32,13 @@ pub struct Macro {
     pub package_name: String,
     // pub path: String,
     pub original_file_path: String,
-    // pub unique_id: String,
+    pub unique_id: String,
     pub macro_sql: String,
     // pub depends_on: MacroDependsOn,
     pub description: Option<String>,
     pub meta: Option<Meta>,
     // pub docs: Option<serde_json::Value>,
-    // pub patch_path: Option<String>,
+    pub patch_path: Option<String>,
     // pub arguments: Option<Vec<MacroArgument>>,
     // pub created_at: Option<f64>,
     // pub supported_languages: Option<Vec<String>>,
@@ -64,18 +65,49 @@ impl Macro {
     pub const fn get_relative_path(&self) -> &String {
         &self.original_file_path
     }
+
+    pub const fn get_unique_id(&self) -> &String {
+        &self.unique_id
+    }
+
+    /// Path of the YAML property file describing this macro, if any
+    pub fn get_patch_path(&self) -> Option<&str> {
+        strip_package(self.patch_path.as_ref()?)
+    }
+}
+
+impl Suppressible for Macro {
+    fn get_metas(&self) -> Vec<&Meta> {
+        self.meta.iter().collect()
+    }
 }
 
 impl IncludeExcludable for Macro {
     fn get_relative_path(&self) -> &String {
         self.get_relative_path()
     }
+
+    fn get_unique_id(&self) -> &String {
+        self.get_unique_id()
+    }
+
+    fn get_schema_path(&self) -> Option<&str> {
+        self.get_patch_path()
+    }
 }
 
 impl IncludeExcludable for &Macro {
     fn get_relative_path(&self) -> &String {
         (*self).get_relative_path()
     }
+
+    fn get_unique_id(&self) -> &String {
+        (*self).get_unique_id()
+    }
+
+    fn get_schema_path(&self) -> Option<&str> {
+        (*self).get_patch_path()
+    }
 }
 
 impl Descriptable for Macro {
@@ -13,10 +13,19 @@ pub mod unit_test;
 pub use exposure::Exposure;
 pub use group::Group;
 pub use metric::Metric;
+pub use parse_manifest::Manifest;
 pub use r#macro::Macro;
 pub use saved_query::SavedQuery;
-// pub use selector::Selector;
-pub use parse_manifest::Manifest;
+pub use selector::Selector;
 pub use semantic_model::SemanticModel;
 pub use unit_test::UnitTest;
 pub mod dbt_objects;
+
+/// dbt stores YAML property file locations as `<package_name>://<path>`.
+/// Strip the package prefix to get a path relative to the project root.
+pub fn strip_package(patch_path: &str) -> Option<&str> {
+    patch_path
+        .split_once("://")
+        .map_or(Some(patch_path), |(_, path)| Some(path))
+        .filter(|path| !path.is_empty())
+}
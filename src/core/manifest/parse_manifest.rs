@@ -1,7 +1,7 @@
 use crate::core::manifest::dbt_objects::nodes::test::Test;
 
 use super::dbt_objects::{Node, Source};
-use super::{Exposure, Group, Macro, Metric, SavedQuery, SemanticModel, UnitTest};
+use super::{Exposure, Group, Macro, Metric, SavedQuery, Selector, SemanticModel, UnitTest};
 use anyhow::{Context, Result};
 use serde::Deserialize;
 use std::collections::HashMap;
@@ -48,7 +48,7 @@ pub struct Manifest {
     pub exposures: HashMap<String, Exposure>,
     pub metrics: HashMap<String, Metric>,
     pub groups: HashMap<String, Group>,
-    // pub selectors: HashMap<String, Selector>,
+    pub selectors: HashMap<String, Selector>,
     // pub disabled: HashMap<String, Vec<DisabledResource>>,
     pub parent_map: HashMap<String, Vec<String>>,
     pub child_map: HashMap<String, Vec<String>>,
@@ -71,6 +71,7 @@ impl Manifest {
     }
 
     // Get tests attached to a specific parent node
+    #[allow(clippy::iter_kv_map)]
     pub fn get_tests_by_parent(&self, parent_unique_id: &str) -> Vec<&Test> {
         self.nodes
             .iter()
@@ -206,6 +207,7 @@ mod tests {
                 exposures: HashMap::new(),
                 metrics: HashMap::new(),
                 groups: HashMap::new(),
+                selectors: HashMap::new(),
                 parent_map: HashMap::new(),
                 child_map: HashMap::new(),
                 group_map: HashMap::new(),
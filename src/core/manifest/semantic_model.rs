@@ -2,10 +2,13 @@ use serde::Deserialize;
 
 use crate::core::{
     config::{applies_to::RuleTarget, includes_excludes::IncludeExcludable},
-    manifest::dbt_objects::Meta,
-    rules::rule_config::{
-        has_description::Descriptable, has_metadata_keys::HasMetadata, has_refs::CanReference,
-        name_convention::NameAble,
+    manifest::dbt_objects::{Meta, MetaConfig},
+    rules::{
+        rule_config::{
+            has_description::Descriptable, has_metadata_keys::HasMetadata, has_refs::CanReference,
+            name_convention::NameAble,
+        },
+        suppression::Suppressible,
     },
 };
 
@@ -24,8 +27,8 @@ pub struct SemanticModel {
     // pub path: String,
     pub package_name: String,
     pub original_file_path: String,
-    // pub unique_id: String,
-    // pub fqn: Vec<String>,
+    pub unique_id: String,
+    pub fqn: Option<Vec<String>>,
     // pub model: String,
     // pub node_relation: serde_json::Value,
     pub description: Option<String>,
@@ -38,8 +41,8 @@ pub struct SemanticModel {
     pub depends_on: SemanticModelDependsOn,
     // pub refs: Option<Vec<serde_json::Value>>,
     // pub created_at: Option<f64>,
-    // pub config: Option<serde_json::Value>,
-    // pub unrendered_config: Option<serde_json::Value>,
+    pub config: Option<MetaConfig>,
+    pub unrendered_config: Option<serde_json::Value>,
     // pub primary_entity: Option<String>,
     // pub group: Option<String>,
 }
@@ -65,18 +68,40 @@ impl SemanticModel {
     pub const fn get_relative_path(&self) -> &String {
         &self.original_file_path
     }
+
+    pub const fn get_unique_id(&self) -> &String {
+        &self.unique_id
+    }
+}
+
+impl Suppressible for SemanticModel {
+    fn get_metas(&self) -> Vec<&Meta> {
+        self.config
+            .as_ref()
+            .and_then(|config| config.meta.as_ref())
+            .into_iter()
+            .collect()
+    }
 }
 
 impl IncludeExcludable for SemanticModel {
     fn get_relative_path(&self) -> &String {
         self.get_relative_path()
     }
+
+    fn get_unique_id(&self) -> &String {
+        self.get_unique_id()
+    }
 }
 
 impl IncludeExcludable for &SemanticModel {
     fn get_relative_path(&self) -> &String {
         (*self).get_relative_path()
     }
+
+    fn get_unique_id(&self) -> &String {
+        (*self).get_unique_id()
+    }
 }
 
 impl Descriptable for SemanticModel {
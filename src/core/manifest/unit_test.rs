@@ -2,7 +2,11 @@ use serde::Deserialize;
 
 use crate::core::{
     config::{applies_to::RuleTarget, includes_excludes::IncludeExcludable},
-    rules::rule_config::{has_description::Descriptable, name_convention::NameAble},
+    manifest::dbt_objects::{Meta, MetaConfig},
+    rules::{
+        rule_config::{has_description::Descriptable, name_convention::NameAble},
+        suppression::Suppressible,
+    },
 };
 
 // #[derive(Debug, Deserialize)]
@@ -23,13 +27,13 @@ pub struct UnitTest {
     pub package_name: String,
     // pub path: String,
     pub original_file_path: String,
-    // pub unique_id: String,
-    // pub fqn: Vec<String>,
+    pub unique_id: String,
+    pub fqn: Option<Vec<String>>,
     pub description: Option<String>,
     // pub overrides: Option<serde_json::Value>,
     // pub depends_on: Option<UnitTestDependsOn>,
-    // pub config: Option<serde_json::Value>,
-    // pub checksum: Option<String>,
+    pub config: Option<MetaConfig>,
+    pub checksum: Option<String>,
     // pub schema: Option<String>,
     // pub created_at: Option<f64>,
     // pub versions: Option<serde_json::Value>,
@@ -57,18 +61,40 @@ impl UnitTest {
     pub const fn get_relative_path(&self) -> &String {
         &self.original_file_path
     }
+
+    pub const fn get_unique_id(&self) -> &String {
+        &self.unique_id
+    }
+}
+
+impl Suppressible for UnitTest {
+    fn get_metas(&self) -> Vec<&Meta> {
+        self.config
+            .as_ref()
+            .and_then(|config| config.meta.as_ref())
+            .into_iter()
+            .collect()
+    }
 }
 
 impl IncludeExcludable for UnitTest {
     fn get_relative_path(&self) -> &String {
         self.get_relative_path()
     }
+
+    fn get_unique_id(&self) -> &String {
+        self.get_unique_id()
+    }
 }
 
 impl IncludeExcludable for &UnitTest {
     fn get_relative_path(&self) -> &String {
         (*self).get_relative_path()
     }
+
+    fn get_unique_id(&self) -> &String {
+        (*self).get_unique_id()
+    }
 }
 
 impl Descriptable for UnitTest {
@@ -1,7 +1,10 @@
+pub mod baseline;
 pub mod catalog;
 pub mod config;
+pub mod freshness;
 pub mod init;
 pub mod manifest;
 pub mod rules;
 pub mod run;
+pub mod selection;
 pub mod utils;
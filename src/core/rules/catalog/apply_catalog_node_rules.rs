@@ -1,12 +1,11 @@
-use crate::{
-    cli::table::RuleResult,
-    core::{
-        catalog::parse_catalog::Catalog,
-        config::{catalog_rule::CatalogSpecificRuleConfig, severity::Severity, Config},
-        manifest::Manifest,
-        rules::catalog::{
-            column_name_convention, columns_are_documented, columns_have_description,
-        },
+use crate::core::{
+    catalog::parse_catalog::Catalog,
+    config::{catalog_rule::CatalogSpecificRuleConfig, includes_excludes::should_run_test, Config},
+    manifest::Manifest,
+    rules::{
+        catalog::{column_name_convention, columns_are_documented, columns_have_description},
+        rule_outcomes::{RuleOutcomes, RulePass},
+        suppression::Suppressible,
     },
 };
 use owo_colors::OwoColorize;
@@ -26,18 +25,18 @@ pub fn apply_catalog_node_rules<'a>(
     catalog: &'a Catalog,
     manifest: &'a Manifest,
     verbose: bool,
-) -> anyhow::Result<Vec<(RuleResult, &'a Severity)>> {
+) -> anyhow::Result<RuleOutcomes<'a>> {
     let Some(catalog_tests) = &config.catalog_tests else {
-        return Ok(Vec::new());
+        return Ok(RuleOutcomes::default());
     };
 
     catalog
         .nodes
         .values()
         .flat_map(|catalog_node| catalog_tests.iter().map(move |rule| (catalog_node, rule)))
-        .try_fold(Vec::new(), |mut acc, (catalog_node, rule)| -> anyhow::Result<_> {
+        .try_fold(RuleOutcomes::default(), |mut acc, (catalog_node, rule)| -> anyhow::Result<_> {
             let Some(manifest_node) = manifest.get_node(catalog_node.get_unique_id()) else {
-                println!(
+                eprintln!(
                     "{}",
                     format!(
                         "Warning: No matching manifest node found for catalog node '{}'.\n\
@@ -50,6 +49,13 @@ pub fn apply_catalog_node_rules<'a>(
                 return Ok(acc);
             };
 
+            if !config.in_scope(manifest_node)
+                || !should_run_test(manifest_node, rule.includes.as_ref(), rule.excludes.as_ref())
+                || !rule.selection.is_selected(manifest_node, manifest)?
+            {
+                return Ok(acc);
+            }
+
             // `applies_to` filtering
             if let Some(applies) = &rule.applies_to {
                 if !applies.node_objects.contains(&manifest_node.ruletarget()) {
@@ -87,7 +93,24 @@ pub fn apply_catalog_node_rules<'a>(
             };
 
             if let Some(rule_row) = rule_row_result {
-                acc.push((rule_row, &rule.severity));
+                acc.push_finding(
+                    rule_row
+                        .with_patch_path_if(
+                            rule.rule.checks_yaml_properties(),
+                            manifest_node.get_patch_path(),
+                        )
+                        .with_unique_id(manifest_node.get_unique_id()),
+                    &rule.severity,
+                    manifest_node.disabled_rule(&rule.get_name(), rule.rule.as_str()),
+                );
+            } else {
+                acc.passes.push(RulePass::new(
+                    rule.get_name(),
+                    manifest_node.get_object_type(),
+                    manifest_node.get_name(),
+                    manifest_node.get_unique_id(),
+                    Some(manifest_node.get_relative_path().clone()),
+                ));
             }
 
             Ok(acc)
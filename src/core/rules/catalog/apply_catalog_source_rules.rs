@@ -1,12 +1,11 @@
-use crate::{
-    cli::table::RuleResult,
-    core::{
-        catalog::parse_catalog::Catalog,
-        config::{catalog_rule::CatalogSpecificRuleConfig, severity::Severity, Config},
-        manifest::Manifest,
-        rules::catalog::{
-            column_name_convention, columns_are_documented, columns_have_description,
-        },
+use crate::core::{
+    catalog::parse_catalog::Catalog,
+    config::{catalog_rule::CatalogSpecificRuleConfig, includes_excludes::should_run_test, Config},
+    manifest::{dbt_objects::Source, Manifest},
+    rules::{
+        catalog::{column_name_convention, columns_are_documented, columns_have_description},
+        rule_outcomes::{RuleOutcomes, RulePass},
+        suppression::Suppressible,
     },
 };
 use owo_colors::OwoColorize;
@@ -26,19 +25,19 @@ pub fn apply_catalog_source_rules<'a>(
     catalog: &'a Catalog,
     manifest: &'a Manifest,
     verbose: bool,
-) -> anyhow::Result<Vec<(RuleResult, &'a Severity)>> {
+) -> anyhow::Result<RuleOutcomes<'a>> {
     let Some(catalog_tests) = &config.catalog_tests else {
-        return Ok(Vec::new());
+        return Ok(RuleOutcomes::default());
     };
 
     catalog
         .sources
         .values()
         .flat_map(|catalog_source| catalog_tests.iter().map(move |rule| (catalog_source, rule)))
-        .try_fold(Vec::new(), |mut acc, (catalog_source, rule)| -> anyhow::Result<_> {
+        .try_fold(RuleOutcomes::default(), |mut acc, (catalog_source, rule)| -> anyhow::Result<_> {
             let Some(manifest_source) = manifest.get_source(catalog_source.get_unique_id()) else {
                 // Mismatch between catalog and manifest sources
-                println!(
+                eprintln!(
                     "{}",
                     format!(
                         "Warning: No matching manifest source found for catalog source '{}'.\n\
@@ -51,6 +50,13 @@ pub fn apply_catalog_source_rules<'a>(
                 return Ok(acc);
             };
 
+            if !config.in_scope(manifest_source)
+                || !should_run_test(manifest_source, rule.includes.as_ref(), rule.excludes.as_ref())
+                || !rule.selection.is_selected(manifest_source, manifest)?
+            {
+                return Ok(acc);
+            }
+
             // `applies_to` filtering has to be done from the manifest source side (only it contains the path)
             if let Some(applies) = &rule.applies_to {
                 if !applies.source_objects.contains(&manifest_source.ruletarget()) {
@@ -88,7 +94,19 @@ pub fn apply_catalog_source_rules<'a>(
             };
 
             if let Some(rule_row) = rule_row_result {
-                acc.push((rule_row, &rule.severity));
+                acc.push_finding(
+                            rule_row.with_unique_id(manifest_source.get_unique_id()),
+                            &rule.severity,
+                            manifest_source.disabled_rule(&rule.get_name(), rule.rule.as_str()),
+                        );
+            } else {
+                acc.passes.push(RulePass::new(
+                    rule.get_name(),
+                    Source::get_object_type(),
+                    manifest_source.get_name(),
+                    manifest_source.get_unique_id(),
+                    Some(manifest_source.get_relative_path().clone()),
+                ));
             }
 
             Ok(acc)
@@ -64,6 +64,7 @@ pub fn column_name_convention<C: Columnable>(
 mod tests {
     use super::*;
     use crate::core::config::{catalog_rule::CatalogSpecificRuleConfig, severity::Severity};
+    use crate::core::selection::RuleSelection;
 
     struct TestItem {
         name: String,
@@ -96,6 +97,7 @@ mod tests {
             description: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: CatalogSpecificRuleConfig::ColumnsNameConvention {
                 pattern: "snake_case".to_string(),
             },
@@ -119,6 +121,7 @@ mod tests {
             description: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: CatalogSpecificRuleConfig::ColumnsNameConvention {
                 pattern: "snake_case".to_string(),
             },
@@ -157,6 +160,7 @@ mod tests {
                 description: None,
                 includes: None,
                 excludes: None,
+                selection: RuleSelection::default(),
                 rule: CatalogSpecificRuleConfig::ColumnsNameConvention {
                     pattern: (*pattern).to_string(),
                 },
@@ -191,6 +195,7 @@ mod tests {
                 description: None,
                 includes: None,
                 excludes: None,
+                selection: RuleSelection::default(),
                 rule: CatalogSpecificRuleConfig::ColumnsNameConvention {
                     pattern: (*pattern).to_string(),
                 },
@@ -217,6 +222,7 @@ mod tests {
             description: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: CatalogSpecificRuleConfig::ColumnsNameConvention {
                 pattern: r"^[a-z]{3}[0-9]{2}$".to_string(), // custom pattern: 3 letters followed by 2 digits
             },
@@ -240,6 +246,7 @@ mod tests {
             description: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: CatalogSpecificRuleConfig::ColumnsNameConvention {
                 pattern: r"^[a-z]{3}[0-9]{2}$".to_string(), // custom pattern: 3 letters followed by 2 digits
             },
@@ -269,6 +276,7 @@ mod tests {
             description: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: CatalogSpecificRuleConfig::ColumnsNameConvention {
                 pattern: r"*[a-z".to_string(), // invalid regex
             },
@@ -115,6 +115,7 @@ fn compare_column_names(
 }
 #[cfg(test)]
 mod tests {
+    use crate::core::selection::RuleSelection;
 
     use crate::core::manifest::parse_manifest::ManifestMetadata;
 
@@ -155,6 +156,7 @@ mod tests {
             description: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule:
                 crate::core::config::catalog_rule::CatalogSpecificRuleConfig::ColumnsAllDocumented {},
         }
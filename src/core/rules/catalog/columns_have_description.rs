@@ -75,6 +75,7 @@ pub fn columns_have_description<C: Columnable, M: Columnable>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::selection::RuleSelection;
 
     struct TestColumnable {
         object_type: String,
@@ -129,6 +130,7 @@ mod tests {
             description: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule:
                 crate::core::config::catalog_rule::CatalogSpecificRuleConfig::ColumnsHaveDescription {  },
         }
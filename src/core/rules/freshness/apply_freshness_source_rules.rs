@@ -0,0 +1,75 @@
+use crate::core::{
+    config::{
+        freshness_rule::FreshnessSpecificRuleConfig, includes_excludes::should_run_test, Config,
+    },
+    freshness::parse_sources::FreshnessResults,
+    manifest::{dbt_objects::Source, Manifest},
+    rules::{
+        freshness::source_is_fresh,
+        rule_outcomes::{RuleOutcomes, RulePass},
+        suppression::Suppressible,
+    },
+};
+
+/// Applies the freshness rules to the sources in the manifest.
+/// Iterates over the manifest sources rather than the freshness results,
+/// so sources missing from `sources.json` are evaluated as well.
+/// # Errors
+/// Returns an error if a rule has an invalid configuration (e.g., an invalid selector)
+pub fn apply_freshness_source_rules<'a>(
+    config: &'a Config,
+    freshness: &'a FreshnessResults,
+    manifest: &'a Manifest,
+) -> anyhow::Result<RuleOutcomes<'a>> {
+    let Some(freshness_tests) = &config.freshness_tests else {
+        return Ok(RuleOutcomes::default());
+    };
+    let results = freshness.by_unique_id();
+
+    manifest
+        .sources
+        .values()
+        .filter(|source| config.in_scope(*source))
+        .flat_map(|source| freshness_tests.iter().map(move |rule| (source, rule)))
+        .try_fold(
+            RuleOutcomes::default(),
+            |mut acc, (source, rule)| -> anyhow::Result<_> {
+                if !should_run_test(source, rule.includes.as_ref(), rule.excludes.as_ref())
+                    || !rule.selection.is_selected(source, manifest)?
+                {
+                    return Ok(acc);
+                }
+
+                if let Some(applies) = &rule.applies_to {
+                    if !applies.source_objects.contains(&source.ruletarget()) {
+                        return Ok(acc);
+                    }
+                }
+
+                let result = results.get(source.get_unique_id().as_str()).copied();
+                let rule_row_result = match &rule.rule {
+                    FreshnessSpecificRuleConfig::SourceIsFresh { fail_on_warn } => {
+                        source_is_fresh(source, result, rule, *fail_on_warn)
+                    }
+                };
+
+                if let Some(rule_row) = rule_row_result {
+                    acc.push_finding(
+                        rule_row.with_unique_id(source.get_unique_id()),
+                        &rule.severity,
+                        source.disabled_rule(&rule.get_name(), rule.rule.as_str()),
+                    );
+                } else {
+                    acc.passes.push(RulePass::new(
+                        rule.get_name(),
+                        Source::get_object_type(),
+                        source.get_name(),
+                        source.get_unique_id(),
+                        Some(source.get_relative_path().clone()),
+                    ));
+                }
+
+                Ok(acc)
+            },
+        )
+}
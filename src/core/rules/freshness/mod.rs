@@ -0,0 +1,4 @@
+pub mod apply_freshness_source_rules;
+pub mod source_is_fresh;
+
+pub use source_is_fresh::source_is_fresh;
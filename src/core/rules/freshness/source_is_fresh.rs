@@ -0,0 +1,120 @@
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::freshness_rule::FreshnessRule,
+        freshness::parse_sources::{FreshnessStatus, SourceFreshnessResult},
+        manifest::dbt_objects::Source,
+    },
+};
+
+/// Fails sources whose last `dbt source freshness` status was `error` (or `warn` with `fail_on_warn`),
+/// whose check failed to run, or that have no freshness result at all.
+pub fn source_is_fresh(
+    source: &Source,
+    result: Option<&SourceFreshnessResult>,
+    rule: &FreshnessRule,
+    fail_on_warn: bool,
+) -> Option<RuleResult> {
+    let message = match result {
+        None => format!(
+            "{} has no freshness result, configure its freshness and run 'dbt source freshness'",
+            source.get_name()
+        ),
+        Some(result) => match result.status {
+            FreshnessStatus::Pass => return None,
+            FreshnessStatus::Warn if !fail_on_warn => return None,
+            FreshnessStatus::Warn | FreshnessStatus::Error => format!(
+                "{} freshness status is {}{}",
+                source.get_name(),
+                result.status.as_str(),
+                result
+                    .max_loaded_at
+                    .as_ref()
+                    .map_or_else(String::new, |loaded_at| format!(
+                        ", last loaded at {loaded_at}"
+                    ))
+            ),
+            FreshnessStatus::RuntimeError => format!(
+                "{} freshness check failed: {}",
+                source.get_name(),
+                result
+                    .error
+                    .as_deref()
+                    .and_then(|error| error.lines().next())
+                    .unwrap_or("unknown error")
+            ),
+        },
+    };
+
+    Some(RuleResult::new(
+        &rule.severity,
+        Source::get_object_type(),
+        rule.get_name(),
+        message,
+        Some(source.get_relative_path().clone()),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn source() -> Source {
+        serde_json::from_value(json!({
+            "database": "db",
+            "name": "orders",
+            "package_name": "test",
+            "original_file_path": "models/sources.yml",
+            "unique_id": "source.test.raw.orders",
+            "source_name": "raw"
+        }))
+        .unwrap()
+    }
+
+    fn result(status: &str) -> SourceFreshnessResult {
+        serde_json::from_value(json!({
+            "unique_id": "source.test.raw.orders",
+            "status": status,
+            "max_loaded_at": "2025-01-01T00:00:00+00:00",
+            "error": "Database Error\n  relation \"raw.orders\" does not exist"
+        }))
+        .unwrap()
+    }
+
+    fn rule() -> FreshnessRule {
+        serde_yaml::from_str("type: source_is_fresh").unwrap()
+    }
+
+    #[test]
+    fn test_statuses() {
+        let check = |status: &str, fail_on_warn: bool| {
+            source_is_fresh(&source(), Some(&result(status)), &rule(), fail_on_warn)
+                .map(|result| result.message)
+        };
+        assert!(check("pass", true).is_none());
+        assert!(check("warn", false).is_none());
+        assert_eq!(
+            check("warn", true).unwrap(),
+            "orders freshness status is warn, last loaded at 2025-01-01T00:00:00+00:00"
+        );
+        assert_eq!(
+            check("error", false).unwrap(),
+            "orders freshness status is error, last loaded at 2025-01-01T00:00:00+00:00"
+        );
+        assert_eq!(
+            check("runtime error", false).unwrap(),
+            "orders freshness check failed: Database Error"
+        );
+    }
+
+    #[test]
+    fn test_missing_result() {
+        assert_eq!(
+            source_is_fresh(&source(), None, &rule(), true)
+                .unwrap()
+                .message,
+            "orders has no freshness result, configure its freshness and run 'dbt source freshness'"
+        );
+    }
+}
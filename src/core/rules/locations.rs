@@ -0,0 +1,300 @@
+use std::collections::HashMap;
+use std::ops::Range;
+use std::path::Path;
+
+use crate::cli::table::RuleResult;
+use crate::core::config::severity::Severity;
+use crate::core::manifest::dbt_objects::nodes::node::Node;
+use crate::core::manifest::Manifest;
+
+/// Where in a YAML file the definition of an object can be found.
+enum YamlEntry<'a> {
+    /// `- name: <name>` in the top-level `section` list (e.g. `models`, `exposures`)
+    Resource { section: &'a str, name: &'a str },
+    /// `- name: <table>` in the `tables` list of the `- name: <source>` entry under `sources`
+    SourceTable { source: &'a str, table: &'a str },
+}
+
+/// A list item found in a YAML file.
+struct ListItem {
+    /// 0-based line index of the `name:` key of the item
+    line: usize,
+    /// 1-based column of the `name:` key of the item
+    column: usize,
+    /// Lines belonging to the item
+    lines: Range<usize>,
+}
+
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+fn is_blank_or_comment(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.is_empty() || trimmed.starts_with('#')
+}
+
+/// Value of a `name: <value>` key, without quotes and trailing comments.
+fn name_value(content: &str) -> Option<&str> {
+    let value = content.strip_prefix("name:")?;
+    let value = value.split(" #").next().unwrap_or(value).trim();
+    Some(value.trim_matches(|c| c == '"' || c == '\''))
+}
+
+/// Find the item named `name` in the list under `key`, searching within `range`.
+/// This is a line-based scan, which is enough for the layout of dbt property files.
+fn find_list_item(lines: &[&str], range: Range<usize>, key: &str, name: &str) -> Option<ListItem> {
+    let key_line = range
+        .clone()
+        .find(|&i| lines[i].trim() == format!("{key}:"))?;
+    let key_indent = indentation(lines[key_line]);
+
+    // The list ends at the first line dedented below the key (or at its level, not being a list item)
+    let section_end = (key_line + 1..range.end)
+        .find(|&i| {
+            let line = lines[i];
+            !is_blank_or_comment(line)
+                && (indentation(line) < key_indent
+                    || (indentation(line) == key_indent && !line.trim_start().starts_with('-')))
+        })
+        .unwrap_or(range.end);
+
+    let item_indent = (key_line + 1..section_end)
+        .map(|i| lines[i])
+        .find(|line| line.trim_start().starts_with('-'))
+        .map(indentation)?;
+    let item_starts: Vec<usize> = (key_line + 1..section_end)
+        .filter(|&i| indentation(lines[i]) == item_indent && lines[i].trim_start().starts_with('-'))
+        .collect();
+
+    item_starts.iter().enumerate().find_map(|(n, &start)| {
+        let end = item_starts.get(n + 1).copied().unwrap_or(section_end);
+        let first = &lines[start][item_indent + 1..];
+        let content_indent = item_indent + 1 + indentation(first);
+
+        (start..end).find_map(|i| {
+            let line = lines[i];
+            if i != start && (is_blank_or_comment(line) || indentation(line) != content_indent) {
+                return None;
+            }
+            let content = if i == start {
+                first.trim_start()
+            } else {
+                line.trim_start()
+            };
+            (name_value(content)? == name).then_some(ListItem {
+                line: i,
+                column: content_indent + 1,
+                lines: start..end,
+            })
+        })
+    })
+}
+
+/// 1-based line and column of the definition of an object in a YAML file
+fn locate_in_yaml(contents: &str, entry: &YamlEntry) -> Option<(usize, usize)> {
+    let lines: Vec<&str> = contents.lines().collect();
+    let item = match entry {
+        YamlEntry::Resource { section, name } => {
+            find_list_item(&lines, 0..lines.len(), section, name)?
+        }
+        YamlEntry::SourceTable { source, table } => {
+            let source = find_list_item(&lines, 0..lines.len(), "sources", source)?;
+            find_list_item(&lines, source.lines, "tables", table)?
+        }
+    };
+    Some((item.line + 1, item.column))
+}
+
+/// Property files list nodes under the plural of their resource type
+const fn node_section(node: &Node) -> Option<&'static str> {
+    match node {
+        Node::Model(_) => Some("models"),
+        Node::Seed(_) => Some("seeds"),
+        Node::Snapshot(_) => Some("snapshots"),
+        Node::Analysis(_) => Some("analyses"),
+        Node::Test(_) | Node::HookNode(_) | Node::SqlOperation(_) => None,
+    }
+}
+
+fn is_yaml(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("yml") || ext.eq_ignore_ascii_case("yaml"))
+}
+
+/// Reads (and caches) the files findings point at, relative to the project root.
+struct ProjectFiles<'a> {
+    entry_point: &'a str,
+    contents: HashMap<String, Option<String>>,
+}
+
+impl ProjectFiles<'_> {
+    fn get(&mut self, relative_path: &str) -> Option<&str> {
+        let entry_point = self.entry_point.trim_end_matches('/');
+        self.contents
+            .entry(relative_path.to_string())
+            .or_insert_with(|| {
+                std::fs::read_to_string(format!(
+                    "{entry_point}/{}",
+                    relative_path.trim_start_matches('/')
+                ))
+                .ok()
+            })
+            .as_deref()
+    }
+}
+
+fn locate_finding(
+    result: &RuleResult,
+    manifest: &Manifest,
+    files: &mut ProjectFiles,
+) -> Option<(usize, Option<usize>)> {
+    let unique_id = result.unique_id.as_ref()?;
+    let path = result.location_path()?;
+
+    // Findings pointing at a YAML file: the definition of the object in that file
+    if is_yaml(path) {
+        let entry = if let Some(node) = manifest.get_node(unique_id) {
+            YamlEntry::Resource {
+                section: node_section(node)?,
+                name: node.get_name(),
+            }
+        } else if let Some(source) = manifest.get_source(unique_id) {
+            YamlEntry::SourceTable {
+                source: source.get_source_name(),
+                table: source.get_name(),
+            }
+        } else if let Some(macro_obj) = manifest.macros.get(unique_id) {
+            YamlEntry::Resource {
+                section: "macros",
+                name: macro_obj.get_name(),
+            }
+        } else if let Some(exposure) = manifest.exposures.get(unique_id) {
+            YamlEntry::Resource {
+                section: "exposures",
+                name: exposure.get_name(),
+            }
+        } else if let Some(semantic_model) = manifest.semantic_models.get(unique_id) {
+            YamlEntry::Resource {
+                section: "semantic_models",
+                name: semantic_model.get_name(),
+            }
+        } else {
+            YamlEntry::Resource {
+                section: "unit_tests",
+                name: manifest.unit_tests.get(unique_id)?.get_name(),
+            }
+        };
+        let (line, column) = locate_in_yaml(files.get(path)?, &entry)?;
+        return Some((line, Some(column)));
+    }
+
+    // Lines set by SQL-based rules are relative to the code of the object.
+    // A macro file can contain several macros, so offset by the start of the macro in its file.
+    let line = result.line?;
+    match manifest.macros.get(unique_id) {
+        Some(macro_obj) => {
+            let contents = files.get(path)?;
+            let offset = contents.find(macro_obj.macro_sql.as_str())?;
+            let preceding_lines = contents[..offset].matches('\n').count();
+            Some((line + preceding_lines, result.column))
+        }
+        None => Some((line, result.column)),
+    }
+}
+
+/// Resolve the line (and column) of every finding in the file it points at.
+///
+/// Findings on YAML properties point at the definition of the object in its property file,
+/// findings of SQL-based rules at the line in the code the rule reported.
+/// Findings that cannot be located keep pointing at the file as a whole.
+pub fn locate_findings(
+    findings: &mut [(RuleResult, &Severity)],
+    manifest: &Manifest,
+    entry_point: &str,
+) {
+    let mut files = ProjectFiles {
+        entry_point,
+        contents: HashMap::new(),
+    };
+
+    for (result, _) in findings.iter_mut() {
+        if let Some((line, column)) = locate_finding(result, manifest, &mut files) {
+            result.line = Some(line);
+            result.column = column;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROPERTIES: &str = r#"version: 2
+
+models:
+  - name: stg_customers
+    description: "Customers"
+    columns:
+      - name: orders
+        description: "A column named like a model"
+  - description: "Name not on the first line"
+    name: 'orders' # trailing comment
+
+sources:
+- name: crm
+  tables:
+    - name: customers
+    - name: orders
+- name: payments
+  tables:
+    - name: orders
+"#;
+
+    #[test]
+    fn test_locate_model_entry() {
+        let entry = YamlEntry::Resource {
+            section: "models",
+            name: "stg_customers",
+        };
+        assert_eq!(locate_in_yaml(PROPERTIES, &entry), Some((4, 5)));
+
+        // Columns sharing the name of a model are skipped
+        let entry = YamlEntry::Resource {
+            section: "models",
+            name: "orders",
+        };
+        assert_eq!(locate_in_yaml(PROPERTIES, &entry), Some((10, 5)));
+
+        let entry = YamlEntry::Resource {
+            section: "models",
+            name: "unknown",
+        };
+        assert_eq!(locate_in_yaml(PROPERTIES, &entry), None);
+    }
+
+    #[test]
+    fn test_locate_source_table() {
+        let entry = YamlEntry::SourceTable {
+            source: "payments",
+            table: "orders",
+        };
+        assert_eq!(locate_in_yaml(PROPERTIES, &entry), Some((19, 7)));
+
+        let entry = YamlEntry::SourceTable {
+            source: "crm",
+            table: "orders",
+        };
+        assert_eq!(locate_in_yaml(PROPERTIES, &entry), Some((16, 7)));
+    }
+
+    #[test]
+    fn test_missing_section() {
+        let entry = YamlEntry::Resource {
+            section: "exposures",
+            name: "orders",
+        };
+        assert_eq!(locate_in_yaml(PROPERTIES, &entry), None);
+    }
+}
@@ -1,78 +1,203 @@
-use crate::cli::table::RuleResult;
+use std::collections::HashMap;
+
 use crate::core::config::manifest_rule::ManifestSpecificRuleConfig;
+use crate::core::rules::rule_config::max_chain_depth::ChainDepth;
 use crate::core::rules::rule_config::{
-    check_name_convention, child_map::is_not_orphaned, has_contract_enforced, has_description,
-    has_metadata_keys, has_refs, has_tags, has_unique_test, max_code_lines,
+    check_name_convention, child_map::is_not_orphaned, direct_source_reference, forbidden_keywords,
+    has_contract_enforced, has_description, has_metadata_keys, has_refs, has_relationship_test,
+    has_tags, has_unique_test, import_ctes, layer_dependencies, materialization, max_chain_depth,
+    max_children, max_code_lines, max_parents, no_distinct_without_comment,
+    no_hardcoded_references, no_order_by, no_redundant_dependencies, no_select_star,
+    primary_key_tested, test_coverage,
 };
 
-use crate::core::config::severity::Severity;
 use crate::core::config::{includes_excludes::should_run_test, Config};
 use crate::core::manifest::Manifest;
+use crate::core::rules::rule_outcomes::{RuleOutcomes, RulePass};
+use crate::core::rules::suppression::Suppressible;
 
 /// Applies node rules to the manifest.
 ///
 /// # Errors
 /// Returns an error if a rule has invalid configuration (e.g., invalid regex pattern).
+#[allow(clippy::too_many_lines)]
 pub fn apply_manifest_node_rules<'a>(
     manifest: &'a Manifest,
     config: &'a Config,
     _verbose: bool,
-) -> anyhow::Result<Vec<(RuleResult, &'a Severity)>> {
+) -> anyhow::Result<RuleOutcomes<'a>> {
+    // Chain depths are shared by all nodes, per `materializations` filter of `max_chain_depth` rules
+    let mut chain_depths: HashMap<Option<&[String]>, ChainDepth> = HashMap::new();
+
     let results = if let Some(manifest_tests) = &config.manifest_tests {
         manifest
             .nodes
             .values()
+            .filter(|node| config.in_scope(*node))
             .flat_map(|node| manifest_tests.iter().map(move |rule| (node, rule)))
-            .try_fold(Vec::new(), |mut acc, (node, rule)| -> anyhow::Result<_> {
-                // `applies_to` filtering has to be done from the manifest node side (only it contains the path)
-                let Some(applies) = rule.applies_to.as_ref() else {
-                    return Ok(acc);
-                };
-                if !should_run_test(node, rule.includes.as_ref(), rule.excludes.as_ref()) {
-                    return Ok(acc);
-                }
-
-                if !applies.node_objects.contains(&node.ruletarget()) {
-                    return Ok(acc);
-                }
-
-                let rule_row_result = match &rule.rule {
-                    ManifestSpecificRuleConfig::HasDescription {} => has_description(node, rule),
-                    ManifestSpecificRuleConfig::NameConvention { pattern } => {
-                        check_name_convention(node, rule, pattern)?
-                    }
-                    ManifestSpecificRuleConfig::HasTags {
-                        required_tags,
-                        criteria,
-                    } => has_tags(node, rule, required_tags, criteria),
-                    ManifestSpecificRuleConfig::IsNotOrphaned { allowed_references } => {
-                        is_not_orphaned(node, rule, allowed_references, manifest)
+            .try_fold(
+                RuleOutcomes::default(),
+                |mut acc, (node, rule)| -> anyhow::Result<_> {
+                    // `applies_to` filtering has to be done from the manifest node side (only it contains the path)
+                    let Some(applies) = rule.applies_to.as_ref() else {
+                        return Ok(acc);
+                    };
+                    if !should_run_test(node, rule.includes.as_ref(), rule.excludes.as_ref())
+                        || !rule.selection.is_selected(node, manifest)?
+                    {
+                        return Ok(acc);
                     }
-                    ManifestSpecificRuleConfig::HasUniqueTest { allowed_test_names } => {
-                        has_unique_test(node, rule, manifest, allowed_test_names)
-                    }
-                    ManifestSpecificRuleConfig::HasContractEnforced {} => {
-                        has_contract_enforced(node, rule)
-                    }
-                    ManifestSpecificRuleConfig::HasMetadataKeys {
-                        required_keys,
-                        custom_message,
-                    } => has_metadata_keys(node, rule, required_keys, custom_message.as_ref()),
-                    ManifestSpecificRuleConfig::MaxCodeLines { max_lines } => {
-                        max_code_lines(node, rule, *max_lines)
+
+                    if !applies.node_objects.contains(&node.ruletarget()) {
+                        return Ok(acc);
                     }
-                    ManifestSpecificRuleConfig::HasRefs {} => has_refs(node, rule),
-                };
 
-                if let Some(rule_row) = rule_row_result {
-                    acc.push((rule_row, &rule.severity));
-                }
+                    let rule_row_result = match &rule.rule {
+                        ManifestSpecificRuleConfig::HasDescription {} => {
+                            has_description(node, rule)
+                        }
+                        ManifestSpecificRuleConfig::NameConvention { pattern } => {
+                            check_name_convention(node, rule, pattern)?
+                        }
+                        ManifestSpecificRuleConfig::HasTags {
+                            required_tags,
+                            criteria,
+                        } => has_tags(node, rule, required_tags, criteria),
+                        ManifestSpecificRuleConfig::IsNotOrphaned { allowed_references } => {
+                            is_not_orphaned(node, rule, allowed_references, manifest)
+                        }
+                        ManifestSpecificRuleConfig::HasUniqueTest { allowed_test_names } => {
+                            has_unique_test(node, rule, manifest, allowed_test_names)
+                        }
+                        ManifestSpecificRuleConfig::HasContractEnforced {} => {
+                            has_contract_enforced(node, rule)
+                        }
+                        ManifestSpecificRuleConfig::HasMetadataKeys {
+                            required_keys,
+                            custom_message,
+                        } => has_metadata_keys(node, rule, required_keys, custom_message.as_ref()),
+                        ManifestSpecificRuleConfig::MaxCodeLines { max_lines } => {
+                            max_code_lines(node, rule, *max_lines)
+                        }
+                        ManifestSpecificRuleConfig::HasRefs {} => has_refs(node, rule),
+                        ManifestSpecificRuleConfig::LayerDependencies { layers } => {
+                            layer_dependencies(node, rule, layers, manifest)
+                        }
+                        ManifestSpecificRuleConfig::DirectSourceReference {
+                            allowed_paths,
+                            allowed_name_prefixes,
+                        } => direct_source_reference(
+                            node,
+                            rule,
+                            allowed_paths.as_ref(),
+                            allowed_name_prefixes.as_ref(),
+                            manifest,
+                        ),
+                        ManifestSpecificRuleConfig::MaxChildren {
+                            max_children: max,
+                            resource_types,
+                        } => max_children(node, rule, *max, resource_types, manifest),
+                        ManifestSpecificRuleConfig::MaxParents {
+                            max_parents: max,
+                            resource_types,
+                        } => max_parents(node, rule, *max, resource_types, manifest),
+                        ManifestSpecificRuleConfig::NoHardcodedReferences {} => {
+                            no_hardcoded_references(node, rule)
+                        }
+                        ManifestSpecificRuleConfig::NoRedundantDependencies {} => {
+                            no_redundant_dependencies(node, rule, manifest)
+                        }
+                        ManifestSpecificRuleConfig::MaxChainDepth {
+                            max_depth,
+                            materializations,
+                        } => {
+                            let chain_depth = chain_depths
+                                .entry(materializations.as_deref())
+                                .or_insert_with(|| {
+                                    ChainDepth::new(manifest, materializations.as_deref())
+                                });
+                            max_chain_depth(node, rule, *max_depth, chain_depth)
+                        }
+                        ManifestSpecificRuleConfig::Materialization {
+                            policies,
+                            require_unique_key,
+                            require_on_schema_change,
+                        } => materialization(
+                            node,
+                            rule,
+                            policies,
+                            *require_unique_key,
+                            *require_on_schema_change,
+                        ),
+                        ManifestSpecificRuleConfig::NoSelectStar {} => no_select_star(node, rule),
+                        ManifestSpecificRuleConfig::NoOrderBy {} => {
+                            no_order_by(node, rule, manifest)
+                        }
+                        ManifestSpecificRuleConfig::NoDistinctWithoutComment {} => {
+                            no_distinct_without_comment(node, rule)
+                        }
+                        ManifestSpecificRuleConfig::ForbiddenKeywords { keywords } => {
+                            forbidden_keywords(node, rule, keywords)
+                        }
+                        ManifestSpecificRuleConfig::ImportCtes {} => import_ctes(node, rule),
+                        ManifestSpecificRuleConfig::PrimaryKeyTested {
+                            meta_key,
+                            unique_test_names,
+                            not_null_test_names,
+                        } => primary_key_tested(
+                            node,
+                            rule,
+                            manifest,
+                            meta_key.as_ref(),
+                            unique_test_names,
+                            not_null_test_names,
+                        ),
+                        ManifestSpecificRuleConfig::TestCoverage {
+                            min_coverage,
+                            required_tests,
+                        } => {
+                            let (coverage, result) =
+                                test_coverage(node, rule, manifest, *min_coverage, required_tests);
+                            acc.column_coverage
+                                .insert(node.get_unique_id().clone(), coverage);
+                            result
+                        }
+                        ManifestSpecificRuleConfig::HasRelationshipTest {
+                            columns,
+                            test_names,
+                        } => has_relationship_test(node, rule, manifest, columns, test_names),
+                        // Sources only
+                        ManifestSpecificRuleConfig::SourceFanout { .. }
+                        | ManifestSpecificRuleConfig::SourceHasFreshness { .. } => return Ok(acc),
+                    };
+
+                    if let Some(rule_row) = rule_row_result {
+                        acc.push_finding(
+                            rule_row
+                                .with_patch_path_if(
+                                    rule.rule.checks_yaml_properties(),
+                                    node.get_patch_path(),
+                                )
+                                .with_unique_id(node.get_unique_id()),
+                            &rule.severity,
+                            node.disabled_rule(&rule.get_name(), rule.rule.as_str()),
+                        );
+                    } else {
+                        acc.passes.push(RulePass::new(
+                            rule.get_name(),
+                            node.get_object_type(),
+                            node.get_name(),
+                            node.get_unique_id(),
+                            Some(node.get_relative_path().clone()),
+                        ));
+                    }
 
-                Ok(acc)
-            })?
+                    Ok(acc)
+                },
+            )?
     } else {
         // No manifest tests defined in the configuration => no results
-        Vec::new()
+        RuleOutcomes::default()
     };
 
     Ok(results)
@@ -1,15 +1,17 @@
 use crate::core::rules::rule_config::{
-    check_name_convention, has_description, has_metadata_keys, has_refs, has_tags, has_unique_test,
-    is_not_orphaned, max_code_lines,
+    check_name_convention, forbidden_keywords, has_description, has_metadata_keys, has_refs,
+    has_relationship_test, has_tags, has_unique_test, is_not_orphaned, max_children,
+    max_code_lines, no_distinct_without_comment, no_select_star, primary_key_tested, source_fanout,
+    source_has_freshness, test_coverage,
 };
-use crate::{
-    cli::table::RuleResult,
-    core::{
-        config::{
-            includes_excludes::should_run_test, manifest_rule::ManifestSpecificRuleConfig,
-            severity::Severity, Config,
-        },
-        manifest::Manifest,
+use crate::core::{
+    config::{
+        includes_excludes::should_run_test, manifest_rule::ManifestSpecificRuleConfig, Config,
+    },
+    manifest::{dbt_objects::Source, Exposure, Macro, Manifest, SemanticModel, UnitTest},
+    rules::{
+        rule_outcomes::{RuleOutcomes, RulePass},
+        suppression::Suppressible,
     },
 };
 
@@ -25,7 +27,7 @@ pub fn apply_manifest_object_rules<'a>(
     manifest: &'a Manifest,
     config: &'a Config,
     verbose: bool,
-) -> anyhow::Result<Vec<(RuleResult, &'a Severity)>> {
+) -> anyhow::Result<RuleOutcomes<'a>> {
     Ok([
         apply_source_rules(manifest, config, verbose)?,
         apply_macro_rules(manifest, config, verbose)?,
@@ -34,7 +36,6 @@ pub fn apply_manifest_object_rules<'a>(
         apply_unit_test_rules(manifest, config, verbose)?,
     ]
     .into_iter()
-    .flatten()
     .collect())
 }
 
@@ -42,61 +43,143 @@ pub fn apply_manifest_object_rules<'a>(
 ///
 /// # Errors
 /// Returns an error if a rule has invalid configuration (e.g., invalid regex pattern).
+#[allow(clippy::too_many_lines)]
 fn apply_source_rules<'a>(
     manifest: &'a Manifest,
     config: &'a Config,
     _verbose: bool,
-) -> anyhow::Result<Vec<(RuleResult, &'a Severity)>> {
+) -> anyhow::Result<RuleOutcomes<'a>> {
     let results = if let Some(manifest_tests) = &config.manifest_tests {
         manifest
             .sources
             .values()
+            .filter(|source| config.in_scope(*source))
             .flat_map(|source| manifest_tests.iter().map(move |rule| (source, rule)))
-            .try_fold(Vec::new(), |mut acc, (source, rule)| -> anyhow::Result<_> {
-                if !should_run_test(source, rule.includes.as_ref(), rule.excludes.as_ref()) {
-                    return Ok(acc);
-                }
-
-                if let Some(applies) = &rule.applies_to {
-                    if !applies.source_objects.contains(&source.ruletarget()) {
+            .try_fold(
+                RuleOutcomes::default(),
+                |mut acc, (source, rule)| -> anyhow::Result<_> {
+                    if !should_run_test(source, rule.includes.as_ref(), rule.excludes.as_ref())
+                        || !rule.selection.is_selected(source, manifest)?
+                    {
                         return Ok(acc);
                     }
-                }
 
-                let rule_row_result = match &rule.rule {
-                    ManifestSpecificRuleConfig::HasDescription {} => has_description(source, rule),
-                    ManifestSpecificRuleConfig::NameConvention { pattern } => {
-                        check_name_convention(source, rule, pattern)?
-                    }
-                    ManifestSpecificRuleConfig::HasTags {
-                        required_tags,
-                        criteria,
-                    } => has_tags(source, rule, required_tags, criteria),
-                    ManifestSpecificRuleConfig::IsNotOrphaned { allowed_references } => {
-                        is_not_orphaned(source, rule, allowed_references, manifest)
+                    if let Some(applies) = &rule.applies_to {
+                        if !applies.source_objects.contains(&source.ruletarget()) {
+                            return Ok(acc);
+                        }
                     }
-                    ManifestSpecificRuleConfig::HasUniqueTest { allowed_test_names } => {
-                        has_unique_test(source, rule, manifest, allowed_test_names)
+
+                    let rule_row_result = match &rule.rule {
+                        ManifestSpecificRuleConfig::HasDescription {} => {
+                            has_description(source, rule)
+                        }
+                        ManifestSpecificRuleConfig::NameConvention { pattern } => {
+                            check_name_convention(source, rule, pattern)?
+                        }
+                        ManifestSpecificRuleConfig::HasTags {
+                            required_tags,
+                            criteria,
+                        } => has_tags(source, rule, required_tags, criteria),
+                        ManifestSpecificRuleConfig::IsNotOrphaned { allowed_references } => {
+                            is_not_orphaned(source, rule, allowed_references, manifest)
+                        }
+                        ManifestSpecificRuleConfig::HasUniqueTest { allowed_test_names } => {
+                            has_unique_test(source, rule, manifest, allowed_test_names)
+                        }
+                        ManifestSpecificRuleConfig::HasMetadataKeys {
+                            required_keys,
+                            custom_message,
+                        } => {
+                            has_metadata_keys(source, rule, required_keys, custom_message.as_ref())
+                        }
+                        ManifestSpecificRuleConfig::SourceFanout { max_references } => {
+                            source_fanout(source, rule, *max_references, manifest)
+                        }
+                        ManifestSpecificRuleConfig::MaxChildren {
+                            max_children: max,
+                            resource_types,
+                        } => max_children(source, rule, *max, resource_types, manifest),
+                        ManifestSpecificRuleConfig::HasRelationshipTest {
+                            columns,
+                            test_names,
+                        } => has_relationship_test(source, rule, manifest, columns, test_names),
+                        ManifestSpecificRuleConfig::SourceHasFreshness {
+                            max_warn_after,
+                            max_error_after,
+                        } => source_has_freshness(
+                            source,
+                            rule,
+                            max_warn_after.as_ref(),
+                            max_error_after.as_ref(),
+                        ),
+                        ManifestSpecificRuleConfig::TestCoverage {
+                            min_coverage,
+                            required_tests,
+                        } => {
+                            let (coverage, result) = test_coverage(
+                                source,
+                                rule,
+                                manifest,
+                                *min_coverage,
+                                required_tests,
+                            );
+                            acc.column_coverage
+                                .insert(source.get_unique_id().clone(), coverage);
+                            result
+                        }
+                        ManifestSpecificRuleConfig::PrimaryKeyTested {
+                            meta_key,
+                            unique_test_names,
+                            not_null_test_names,
+                        } => primary_key_tested(
+                            source,
+                            rule,
+                            manifest,
+                            meta_key.as_ref(),
+                            unique_test_names,
+                            not_null_test_names,
+                        ),
+
+                        // These can't be implemented for exposures
+                        ManifestSpecificRuleConfig::HasRefs {}
+                        | ManifestSpecificRuleConfig::LayerDependencies { .. }
+                        | ManifestSpecificRuleConfig::DirectSourceReference { .. }
+                        | ManifestSpecificRuleConfig::MaxParents { .. }
+                        | ManifestSpecificRuleConfig::NoRedundantDependencies {}
+                        | ManifestSpecificRuleConfig::MaxChainDepth { .. }
+                        | ManifestSpecificRuleConfig::Materialization { .. }
+                        | ManifestSpecificRuleConfig::NoHardcodedReferences {}
+                        | ManifestSpecificRuleConfig::NoSelectStar {}
+                        | ManifestSpecificRuleConfig::NoOrderBy {}
+                        | ManifestSpecificRuleConfig::NoDistinctWithoutComment {}
+                        | ManifestSpecificRuleConfig::ForbiddenKeywords { .. }
+                        | ManifestSpecificRuleConfig::ImportCtes {}
+                        | ManifestSpecificRuleConfig::MaxCodeLines { .. }
+                        | ManifestSpecificRuleConfig::HasContractEnforced {} => return Ok(acc), // Models only
+                    };
+
+                    if let Some(rule_row) = rule_row_result {
+                        acc.push_finding(
+                            rule_row.with_unique_id(source.get_unique_id()),
+                            &rule.severity,
+                            source.disabled_rule(&rule.get_name(), rule.rule.as_str()),
+                        );
+                    } else {
+                        acc.passes.push(RulePass::new(
+                            rule.get_name(),
+                            Source::get_object_type(),
+                            source.get_name(),
+                            source.get_unique_id(),
+                            Some(source.get_relative_path().clone()),
+                        ));
                     }
-                    ManifestSpecificRuleConfig::HasMetadataKeys {
-                        required_keys,
-                        custom_message,
-                    } => has_metadata_keys(source, rule, required_keys, custom_message.as_ref()),
-
-                    // These can't be implemented for exposures
-                    ManifestSpecificRuleConfig::HasRefs {}
-                    | ManifestSpecificRuleConfig::MaxCodeLines { .. }
-                    | ManifestSpecificRuleConfig::HasContractEnforced {} => return Ok(acc), // Models only
-                };
-
-                if let Some(rule_row) = rule_row_result {
-                    acc.push((rule_row, &rule.severity));
-                }
-
-                Ok(acc)
-            })?
+
+                    Ok(acc)
+                },
+            )?
     } else {
-        Vec::new()
+        RuleOutcomes::default()
     };
 
     Ok(results)
@@ -109,16 +192,19 @@ fn apply_macro_rules<'a>(
     manifest: &'a Manifest,
     config: &'a Config,
     _verbose: bool,
-) -> anyhow::Result<Vec<(RuleResult, &'a Severity)>> {
+) -> anyhow::Result<RuleOutcomes<'a>> {
     let results = if let Some(manifest_tests) = &config.manifest_tests {
         manifest
             .macros
             .values()
+            .filter(|macro_obj| config.in_scope(*macro_obj))
             .flat_map(|macro_obj| manifest_tests.iter().map(move |rule| (macro_obj, rule)))
             .try_fold(
-                Vec::new(),
+                RuleOutcomes::default(),
                 |mut acc, (macro_obj, rule)| -> anyhow::Result<_> {
-                    if !should_run_test(macro_obj, rule.includes.as_ref(), rule.excludes.as_ref()) {
+                    if !should_run_test(macro_obj, rule.includes.as_ref(), rule.excludes.as_ref())
+                        || !rule.selection.is_selected(macro_obj, manifest)?
+                    {
                         return Ok(acc);
                     }
 
@@ -147,23 +233,67 @@ fn apply_macro_rules<'a>(
                         ManifestSpecificRuleConfig::MaxCodeLines { max_lines } => {
                             max_code_lines(macro_obj, rule, *max_lines)
                         }
+                        ManifestSpecificRuleConfig::NoSelectStar {} => {
+                            no_select_star(macro_obj, rule)
+                        }
+                        ManifestSpecificRuleConfig::NoDistinctWithoutComment {} => {
+                            no_distinct_without_comment(macro_obj, rule)
+                        }
+                        ManifestSpecificRuleConfig::ForbiddenKeywords { keywords } => {
+                            forbidden_keywords(macro_obj, rule, keywords)
+                        }
                         // These can't be implemented for macros
                         ManifestSpecificRuleConfig::HasTags { .. }
                         | ManifestSpecificRuleConfig::IsNotOrphaned { .. }
                         | ManifestSpecificRuleConfig::HasUniqueTest { .. }
                         | ManifestSpecificRuleConfig::HasRefs {}
-                        | ManifestSpecificRuleConfig::HasContractEnforced {} => return Ok(acc), //
+                        | ManifestSpecificRuleConfig::LayerDependencies { .. }
+                        | ManifestSpecificRuleConfig::SourceFanout { .. }
+                        | ManifestSpecificRuleConfig::DirectSourceReference { .. }
+                        | ManifestSpecificRuleConfig::MaxParents { .. }
+                        | ManifestSpecificRuleConfig::NoRedundantDependencies {}
+                        | ManifestSpecificRuleConfig::MaxChainDepth { .. }
+                        | ManifestSpecificRuleConfig::Materialization { .. }
+                        | ManifestSpecificRuleConfig::PrimaryKeyTested { .. }
+                        | ManifestSpecificRuleConfig::TestCoverage { .. }
+                        | ManifestSpecificRuleConfig::HasRelationshipTest { .. }
+                        | ManifestSpecificRuleConfig::SourceHasFreshness { .. }
+                        | ManifestSpecificRuleConfig::MaxChildren { .. }
+                        | ManifestSpecificRuleConfig::HasContractEnforced {}
+                        // Macros have no children to decide on an order by, render query fragments
+                        // instead of import CTEs and commonly query relations like information_schema.
+                        // `applies_to: macros` is rejected for these when the config is loaded.
+                        | ManifestSpecificRuleConfig::NoHardcodedReferences {}
+                        | ManifestSpecificRuleConfig::NoOrderBy {}
+                        | ManifestSpecificRuleConfig::ImportCtes {} => return Ok(acc),
                     };
 
                     if let Some(rule_row) = rule_row_result {
-                        acc.push((rule_row, &rule.severity));
+                        acc.push_finding(
+                            rule_row
+                                .with_patch_path_if(
+                                    rule.rule.checks_yaml_properties(),
+                                    macro_obj.get_patch_path(),
+                                )
+                                .with_unique_id(macro_obj.get_unique_id()),
+                            &rule.severity,
+                            macro_obj.disabled_rule(&rule.get_name(), rule.rule.as_str()),
+                        );
+                    } else {
+                        acc.passes.push(RulePass::new(
+                            rule.get_name(),
+                            Macro::get_object_type(),
+                            macro_obj.get_name(),
+                            macro_obj.get_unique_id(),
+                            Some(macro_obj.get_relative_path().clone()),
+                        ));
                     }
 
                     Ok(acc)
                 },
             )?
     } else {
-        Vec::new()
+        RuleOutcomes::default()
     };
 
     Ok(results)
@@ -176,16 +306,19 @@ fn apply_exposure_rules<'a>(
     manifest: &'a Manifest,
     config: &'a Config,
     _verbose: bool,
-) -> anyhow::Result<Vec<(RuleResult, &'a Severity)>> {
+) -> anyhow::Result<RuleOutcomes<'a>> {
     let results = if let Some(manifest_tests) = &config.manifest_tests {
         manifest
             .exposures
             .values()
+            .filter(|exposure| config.in_scope(*exposure))
             .flat_map(|exposure| manifest_tests.iter().map(move |rule| (exposure, rule)))
             .try_fold(
-                Vec::new(),
+                RuleOutcomes::default(),
                 |mut acc, (exposure, rule)| -> anyhow::Result<_> {
-                    if !should_run_test(exposure, rule.includes.as_ref(), rule.excludes.as_ref()) {
+                    if !should_run_test(exposure, rule.includes.as_ref(), rule.excludes.as_ref())
+                        || !rule.selection.is_selected(exposure, manifest)?
+                    {
                         return Ok(acc);
                     }
 
@@ -219,19 +352,49 @@ fn apply_exposure_rules<'a>(
                         // These can't be implemented for exposures
                         ManifestSpecificRuleConfig::IsNotOrphaned { .. }
                         | ManifestSpecificRuleConfig::MaxCodeLines { .. }
+                        | ManifestSpecificRuleConfig::LayerDependencies { .. }
+                        | ManifestSpecificRuleConfig::SourceFanout { .. }
+                        | ManifestSpecificRuleConfig::DirectSourceReference { .. }
+                        | ManifestSpecificRuleConfig::MaxParents { .. }
+                        | ManifestSpecificRuleConfig::NoRedundantDependencies {}
+                        | ManifestSpecificRuleConfig::MaxChainDepth { .. }
+                        | ManifestSpecificRuleConfig::Materialization { .. }
+                        | ManifestSpecificRuleConfig::NoHardcodedReferences {}
+                        | ManifestSpecificRuleConfig::NoSelectStar {}
+                        | ManifestSpecificRuleConfig::NoOrderBy {}
+                        | ManifestSpecificRuleConfig::NoDistinctWithoutComment {}
+                        | ManifestSpecificRuleConfig::ForbiddenKeywords { .. }
+                        | ManifestSpecificRuleConfig::ImportCtes {}
+                        | ManifestSpecificRuleConfig::PrimaryKeyTested { .. }
+                        | ManifestSpecificRuleConfig::TestCoverage { .. }
+                        | ManifestSpecificRuleConfig::HasRelationshipTest { .. }
+                        | ManifestSpecificRuleConfig::SourceHasFreshness { .. }
+                        | ManifestSpecificRuleConfig::MaxChildren { .. }
                         | ManifestSpecificRuleConfig::HasUniqueTest { .. }
                         | ManifestSpecificRuleConfig::HasContractEnforced {} => return Ok(acc),
                     };
 
                     if let Some(rule_row) = rule_row_result {
-                        acc.push((rule_row, &rule.severity));
+                        acc.push_finding(
+                            rule_row.with_unique_id(exposure.get_unique_id()),
+                            &rule.severity,
+                            exposure.disabled_rule(&rule.get_name(), rule.rule.as_str()),
+                        );
+                    } else {
+                        acc.passes.push(RulePass::new(
+                            rule.get_name(),
+                            Exposure::get_object_type(),
+                            exposure.get_name(),
+                            exposure.get_unique_id(),
+                            Some(exposure.get_relative_path().clone()),
+                        ));
                     }
 
                     Ok(acc)
                 },
             )?
     } else {
-        Vec::new()
+        RuleOutcomes::default()
     };
 
     Ok(results)
@@ -245,49 +408,85 @@ fn apply_semantic_model_rules<'a>(
     manifest: &'a Manifest,
     config: &'a Config,
     _verbose: bool,
-) -> anyhow::Result<Vec<(RuleResult, &'a Severity)>> {
+) -> anyhow::Result<RuleOutcomes<'a>> {
     let results = if let Some(manifest_tests) = &config.manifest_tests {
         manifest
             .semantic_models
             .values()
+            .filter(|sm| config.in_scope(*sm))
             .flat_map(|sm| manifest_tests.iter().map(move |rule| (sm, rule)))
-            .try_fold(Vec::new(), |mut acc, (sm, rule)| -> anyhow::Result<_> {
-                if !should_run_test(sm, rule.includes.as_ref(), rule.excludes.as_ref()) {
-                    return Ok(acc);
-                }
-
-                if let Some(applies) = &rule.applies_to {
-                    if !applies.semantic_model_objects.contains(&sm.ruletarget()) {
+            .try_fold(
+                RuleOutcomes::default(),
+                |mut acc, (sm, rule)| -> anyhow::Result<_> {
+                    if !should_run_test(sm, rule.includes.as_ref(), rule.excludes.as_ref())
+                        || !rule.selection.is_selected(sm, manifest)?
+                    {
                         return Ok(acc);
                     }
-                }
 
-                let rule_row_result = match &rule.rule {
-                    ManifestSpecificRuleConfig::HasDescription {} => has_description(sm, rule),
-                    ManifestSpecificRuleConfig::NameConvention { pattern } => {
-                        check_name_convention(sm, rule, pattern)?
+                    if let Some(applies) = &rule.applies_to {
+                        if !applies.semantic_model_objects.contains(&sm.ruletarget()) {
+                            return Ok(acc);
+                        }
+                    }
+
+                    let rule_row_result = match &rule.rule {
+                        ManifestSpecificRuleConfig::HasDescription {} => has_description(sm, rule),
+                        ManifestSpecificRuleConfig::NameConvention { pattern } => {
+                            check_name_convention(sm, rule, pattern)?
+                        }
+                        ManifestSpecificRuleConfig::HasMetadataKeys {
+                            required_keys,
+                            custom_message,
+                        } => has_metadata_keys(sm, rule, required_keys, custom_message.as_ref()),
+                        ManifestSpecificRuleConfig::HasRefs {} => has_refs(sm, rule),
+                        // These can't be implemented for semantic models
+                        ManifestSpecificRuleConfig::HasTags { .. }
+                        | ManifestSpecificRuleConfig::MaxCodeLines { .. }
+                        | ManifestSpecificRuleConfig::LayerDependencies { .. }
+                        | ManifestSpecificRuleConfig::SourceFanout { .. }
+                        | ManifestSpecificRuleConfig::DirectSourceReference { .. }
+                        | ManifestSpecificRuleConfig::MaxParents { .. }
+                        | ManifestSpecificRuleConfig::NoRedundantDependencies {}
+                        | ManifestSpecificRuleConfig::MaxChainDepth { .. }
+                        | ManifestSpecificRuleConfig::Materialization { .. }
+                        | ManifestSpecificRuleConfig::NoHardcodedReferences {}
+                        | ManifestSpecificRuleConfig::NoSelectStar {}
+                        | ManifestSpecificRuleConfig::NoOrderBy {}
+                        | ManifestSpecificRuleConfig::NoDistinctWithoutComment {}
+                        | ManifestSpecificRuleConfig::ForbiddenKeywords { .. }
+                        | ManifestSpecificRuleConfig::ImportCtes {}
+                        | ManifestSpecificRuleConfig::PrimaryKeyTested { .. }
+                        | ManifestSpecificRuleConfig::TestCoverage { .. }
+                        | ManifestSpecificRuleConfig::HasRelationshipTest { .. }
+                        | ManifestSpecificRuleConfig::SourceHasFreshness { .. }
+                        | ManifestSpecificRuleConfig::MaxChildren { .. }
+                        | ManifestSpecificRuleConfig::IsNotOrphaned { .. }
+                        | ManifestSpecificRuleConfig::HasUniqueTest { .. }
+                        | ManifestSpecificRuleConfig::HasContractEnforced {} => return Ok(acc),
+                    };
+
+                    if let Some(rule_row) = rule_row_result {
+                        acc.push_finding(
+                            rule_row.with_unique_id(sm.get_unique_id()),
+                            &rule.severity,
+                            sm.disabled_rule(&rule.get_name(), rule.rule.as_str()),
+                        );
+                    } else {
+                        acc.passes.push(RulePass::new(
+                            rule.get_name(),
+                            SemanticModel::get_object_type(),
+                            sm.get_name(),
+                            sm.get_unique_id(),
+                            Some(sm.get_relative_path().clone()),
+                        ));
                     }
-                    ManifestSpecificRuleConfig::HasMetadataKeys {
-                        required_keys,
-                        custom_message,
-                    } => has_metadata_keys(sm, rule, required_keys, custom_message.as_ref()),
-                    ManifestSpecificRuleConfig::HasRefs {} => has_refs(sm, rule),
-                    // These can't be implemented for semantic models
-                    ManifestSpecificRuleConfig::HasTags { .. }
-                    | ManifestSpecificRuleConfig::MaxCodeLines { .. }
-                    | ManifestSpecificRuleConfig::IsNotOrphaned { .. }
-                    | ManifestSpecificRuleConfig::HasUniqueTest { .. }
-                    | ManifestSpecificRuleConfig::HasContractEnforced {} => return Ok(acc),
-                };
-
-                if let Some(rule_row) = rule_row_result {
-                    acc.push((rule_row, &rule.severity));
-                }
-
-                Ok(acc)
-            })?
+
+                    Ok(acc)
+                },
+            )?
     } else {
-        Vec::new()
+        RuleOutcomes::default()
     };
 
     Ok(results)
@@ -300,47 +499,83 @@ fn apply_unit_test_rules<'a>(
     manifest: &'a Manifest,
     config: &'a Config,
     _verbose: bool,
-) -> anyhow::Result<Vec<(RuleResult, &'a Severity)>> {
+) -> anyhow::Result<RuleOutcomes<'a>> {
     let results = if let Some(manifest_tests) = &config.manifest_tests {
         manifest
             .unit_tests
             .values()
+            .filter(|ut| config.in_scope(*ut))
             .flat_map(|ut| manifest_tests.iter().map(move |rule| (ut, rule)))
-            .try_fold(Vec::new(), |mut acc, (ut, rule)| -> anyhow::Result<_> {
-                if !should_run_test(ut, rule.includes.as_ref(), rule.excludes.as_ref()) {
-                    return Ok(acc);
-                }
-
-                if let Some(applies) = &rule.applies_to {
-                    if !applies.unit_test_objects.contains(&ut.ruletarget()) {
+            .try_fold(
+                RuleOutcomes::default(),
+                |mut acc, (ut, rule)| -> anyhow::Result<_> {
+                    if !should_run_test(ut, rule.includes.as_ref(), rule.excludes.as_ref())
+                        || !rule.selection.is_selected(ut, manifest)?
+                    {
                         return Ok(acc);
                     }
-                }
 
-                let rule_row_result = match &rule.rule {
-                    ManifestSpecificRuleConfig::HasDescription {} => has_description(ut, rule),
-                    ManifestSpecificRuleConfig::NameConvention { pattern } => {
-                        check_name_convention(ut, rule, pattern)?
+                    if let Some(applies) = &rule.applies_to {
+                        if !applies.unit_test_objects.contains(&ut.ruletarget()) {
+                            return Ok(acc);
+                        }
                     }
 
-                    // Unit Tests do not implement the following rules
-                    ManifestSpecificRuleConfig::MaxCodeLines { .. }
-                    | ManifestSpecificRuleConfig::HasTags { .. }
-                    | ManifestSpecificRuleConfig::HasRefs {}
-                    | ManifestSpecificRuleConfig::IsNotOrphaned { .. }
-                    | ManifestSpecificRuleConfig::HasUniqueTest { .. }
-                    | ManifestSpecificRuleConfig::HasContractEnforced {}
-                    | ManifestSpecificRuleConfig::HasMetadataKeys { .. } => return Ok(acc),
-                };
-
-                if let Some(rule_row) = rule_row_result {
-                    acc.push((rule_row, &rule.severity));
-                }
-
-                Ok(acc)
-            })?
+                    let rule_row_result = match &rule.rule {
+                        ManifestSpecificRuleConfig::HasDescription {} => has_description(ut, rule),
+                        ManifestSpecificRuleConfig::NameConvention { pattern } => {
+                            check_name_convention(ut, rule, pattern)?
+                        }
+
+                        // Unit Tests do not implement the following rules
+                        ManifestSpecificRuleConfig::MaxCodeLines { .. }
+                        | ManifestSpecificRuleConfig::HasTags { .. }
+                        | ManifestSpecificRuleConfig::HasRefs {}
+                        | ManifestSpecificRuleConfig::LayerDependencies { .. }
+                        | ManifestSpecificRuleConfig::SourceFanout { .. }
+                        | ManifestSpecificRuleConfig::DirectSourceReference { .. }
+                        | ManifestSpecificRuleConfig::MaxParents { .. }
+                        | ManifestSpecificRuleConfig::NoRedundantDependencies {}
+                        | ManifestSpecificRuleConfig::MaxChainDepth { .. }
+                        | ManifestSpecificRuleConfig::Materialization { .. }
+                        | ManifestSpecificRuleConfig::NoHardcodedReferences {}
+                        | ManifestSpecificRuleConfig::NoSelectStar {}
+                        | ManifestSpecificRuleConfig::NoOrderBy {}
+                        | ManifestSpecificRuleConfig::NoDistinctWithoutComment {}
+                        | ManifestSpecificRuleConfig::ForbiddenKeywords { .. }
+                        | ManifestSpecificRuleConfig::ImportCtes {}
+                        | ManifestSpecificRuleConfig::PrimaryKeyTested { .. }
+                        | ManifestSpecificRuleConfig::TestCoverage { .. }
+                        | ManifestSpecificRuleConfig::HasRelationshipTest { .. }
+                        | ManifestSpecificRuleConfig::SourceHasFreshness { .. }
+                        | ManifestSpecificRuleConfig::MaxChildren { .. }
+                        | ManifestSpecificRuleConfig::IsNotOrphaned { .. }
+                        | ManifestSpecificRuleConfig::HasUniqueTest { .. }
+                        | ManifestSpecificRuleConfig::HasContractEnforced {}
+                        | ManifestSpecificRuleConfig::HasMetadataKeys { .. } => return Ok(acc),
+                    };
+
+                    if let Some(rule_row) = rule_row_result {
+                        acc.push_finding(
+                            rule_row.with_unique_id(ut.get_unique_id()),
+                            &rule.severity,
+                            ut.disabled_rule(&rule.get_name(), rule.rule.as_str()),
+                        );
+                    } else {
+                        acc.passes.push(RulePass::new(
+                            rule.get_name(),
+                            UnitTest::get_object_type(),
+                            ut.get_name(),
+                            ut.get_unique_id(),
+                            Some(ut.get_relative_path().clone()),
+                        ));
+                    }
+
+                    Ok(acc)
+                },
+            )?
     } else {
-        Vec::new()
+        RuleOutcomes::default()
     };
 
     Ok(results)
@@ -1,4 +1,8 @@
 pub mod catalog;
 pub mod common_traits;
+pub mod freshness;
+pub mod locations;
 pub mod manifest;
 pub mod rule_config;
+pub mod rule_outcomes;
+pub mod suppression;
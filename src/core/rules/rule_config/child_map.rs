@@ -75,6 +75,7 @@ mod tests {
     use crate::core::config::severity::Severity;
     use crate::core::manifest::Manifest;
     use crate::core::rules::rule_config::child_map::{is_not_orphaned, ChildMappable};
+    use crate::core::selection::RuleSelection;
 
     struct MockTaggable {
         object_type: String,
@@ -110,6 +111,7 @@ mod tests {
             applies_to: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: IsNotOrphaned {
                 allowed_references: default_allowed_references(),
             },
@@ -138,6 +140,7 @@ mod tests {
             applies_to: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: IsNotOrphaned {
                 allowed_references: default_allowed_references(),
             },
@@ -165,6 +168,7 @@ mod tests {
             applies_to: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: IsNotOrphaned {
                 allowed_references: default_allowed_references(),
             },
@@ -25,6 +25,7 @@ pub fn has_contract_enforced<T: ContractAble>(
 
 #[cfg(test)]
 mod tests {
+    use crate::core::selection::RuleSelection;
     use crate::core::{
         config::{manifest_rule::ManifestSpecificRuleConfig, severity::Severity},
         manifest::dbt_objects::nodes::node::Contract,
@@ -58,6 +59,7 @@ mod tests {
             applies_to: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             description: None,
             rule: ManifestSpecificRuleConfig::HasContractEnforced {},
         };
@@ -34,6 +34,7 @@ mod tests {
     use crate::core::config::{
         applies_to::AppliesTo, manifest_rule::ManifestSpecificRuleConfig, severity::Severity,
     };
+    use crate::core::selection::RuleSelection;
 
     use super::*;
 
@@ -64,6 +65,7 @@ mod tests {
             applies_to: Some(AppliesTo::empty()),
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: ManifestSpecificRuleConfig::HasDescription {},
         };
         let node_with_desc = TestNode {
@@ -95,6 +97,7 @@ mod tests {
             applies_to: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: ManifestSpecificRuleConfig::HasDescription {},
         };
         let node_with_desc = TestNode {
@@ -126,6 +129,7 @@ mod tests {
             description: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             applies_to: None,
             rule: ManifestSpecificRuleConfig::HasDescription {},
         };
@@ -65,6 +65,7 @@ pub fn has_metadata_keys<T: HasMetadata>(
 #[cfg(test)]
 mod tests {
     use crate::core::config::{manifest_rule::ManifestSpecificRuleConfig, severity::Severity};
+    use crate::core::selection::RuleSelection;
 
     use super::*;
 
@@ -116,6 +117,7 @@ mod tests {
             applies_to: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: ManifestSpecificRuleConfig::HasMetadataKeys {
                 required_keys: vec!["key1".to_string(), "key2".to_string()],
                 custom_message: None,
@@ -157,6 +159,7 @@ mod tests {
             applies_to: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: ManifestSpecificRuleConfig::HasMetadataKeys {
                 required_keys: vec!["key1".to_string(), "key2".to_string()],
                 custom_message: None,
@@ -192,6 +195,7 @@ mod tests {
             applies_to: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: ManifestSpecificRuleConfig::HasMetadataKeys {
                 required_keys: vec!["key1".to_string(), "key2".to_string()],
                 custom_message: None,
@@ -223,6 +227,7 @@ mod tests {
 
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: ManifestSpecificRuleConfig::HasMetadataKeys {
                 required_keys: vec!["key1".to_string(), "key2".to_string()],
                 custom_message: Some("Custom missing keys message.".to_string()),
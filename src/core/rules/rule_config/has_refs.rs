@@ -36,6 +36,7 @@ mod tests {
     use super::*;
     use crate::core::config::manifest_rule::ManifestSpecificRuleConfig;
     use crate::core::config::severity::Severity;
+    use crate::core::selection::RuleSelection;
 
     struct TestCanReference {
         name: String,
@@ -72,6 +73,7 @@ mod tests {
             applies_to: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: ManifestSpecificRuleConfig::HasRefs {},
             name: Some("has_refs".to_string()),
             severity: Severity::Error,
@@ -103,6 +105,7 @@ mod tests {
             applies_to: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: ManifestSpecificRuleConfig::HasRefs {},
             name: Some("has_refs".to_string()),
             severity: Severity::Error,
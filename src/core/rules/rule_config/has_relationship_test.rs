@@ -0,0 +1,225 @@
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::{
+            check_config_options::default_allowed_test_names, includes_excludes::PathPatterns,
+            manifest_rule::ManifestRule,
+        },
+        manifest::{
+            dbt_objects::{nodes::test::normalize_column_name, Node},
+            Manifest,
+        },
+        rules::common_traits::Columnable,
+    },
+};
+
+use super::{
+    has_unique_test::TestAble,
+    primary_key_tested::{primary_key, HasPrimaryKey},
+};
+
+/// Whether the `to` of a relationships test, e.g. `ref('customers')` or `source('raw', 'customers')`,
+/// exists in the manifest. `None` if it can not be resolved statically, e.g. when it uses Jinja.
+fn relation_exists(to: &str, manifest: &Manifest) -> Option<bool> {
+    let (function, arguments) = to.trim().strip_suffix(')')?.split_once('(')?;
+    let arguments = arguments
+        .split(',')
+        .map(str::trim)
+        // Keyword arguments like `v=2` or `version=2`
+        .filter(|argument| !argument.is_empty() && !argument.contains('='))
+        .map(|argument| {
+            argument
+                .strip_prefix('\'')
+                .and_then(|argument| argument.strip_suffix('\''))
+                .or_else(|| {
+                    argument
+                        .strip_prefix('"')
+                        .and_then(|argument| argument.strip_suffix('"'))
+                })
+        })
+        .collect::<Option<Vec<&str>>>()?;
+
+    let is_ref_target = |node: &Node, package: Option<&str>, name: &str| {
+        matches!(node, Node::Model(_) | Node::Seed(_) | Node::Snapshot(_))
+            && node.get_name() == name
+            && package.is_none_or(|package| node.get_base().package_name == package)
+    };
+    match (function.trim(), arguments.as_slice()) {
+        ("ref", [name]) => Some(
+            manifest
+                .nodes
+                .values()
+                .any(|node| is_ref_target(node, None, name)),
+        ),
+        ("ref", [package, name]) => Some(
+            manifest
+                .nodes
+                .values()
+                .any(|node| is_ref_target(node, Some(package), name)),
+        ),
+        ("source", [source_name, name]) => Some(
+            manifest
+                .sources
+                .values()
+                .any(|source| source.source_name == *source_name && source.name == *name),
+        ),
+        _ => None,
+    }
+}
+
+/// Columns matching `columns` (e.g. `*_id`), other than the primary key, need a relationships test
+/// that refers to an existing model or source.
+pub fn has_relationship_test<T: HasPrimaryKey + Columnable>(
+    item: &T,
+    rule: &ManifestRule,
+    manifest: &Manifest,
+    columns: &PathPatterns,
+    test_names: &[String],
+) -> Option<RuleResult> {
+    let tests = item.get_tests(manifest);
+    let primary_key: Vec<String> = primary_key(item, &tests, None, &default_allowed_test_names())
+        .unwrap_or_default()
+        .iter()
+        .map(|column| normalize_column_name(column))
+        .collect();
+
+    let mut foreign_keys: Vec<&String> = item
+        .get_column_names()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|column| {
+            let column = normalize_column_name(column);
+            columns.is_match(&column) && !primary_key.contains(&column)
+        })
+        .collect();
+    foreign_keys.sort();
+
+    let mut untested = vec![];
+    let mut unresolved = vec![];
+    for column in foreign_keys {
+        let relationship_tests: Vec<_> = tests
+            .iter()
+            .filter(|test| {
+                test.is_any_of(test_names)
+                    && test.get_tested_columns().is_some_and(|tested| {
+                        tested.iter().any(|tested| {
+                            normalize_column_name(tested) == normalize_column_name(column)
+                        })
+                    })
+            })
+            .collect();
+        if relationship_tests.is_empty() {
+            untested.push(column.as_str());
+        }
+        for test in relationship_tests {
+            let to = test
+                .test_metadata
+                .as_ref()
+                .and_then(|metadata| metadata.kwargs.as_ref())
+                .and_then(|kwargs| kwargs.get("to"))
+                .and_then(|to| to.as_str());
+            if let Some(to) = to {
+                if relation_exists(to, manifest) == Some(false) {
+                    unresolved.push(format!("{column} to {to}"));
+                }
+            }
+        }
+    }
+
+    let mut issues = vec![];
+    if !untested.is_empty() {
+        issues.push(format!(
+            "has no relationships test on {}",
+            untested.join(", ")
+        ));
+    }
+    if !unresolved.is_empty() {
+        issues.push(format!(
+            "has relationships tests to relations that do not exist: {}",
+            unresolved.join(", ")
+        ));
+    }
+    if issues.is_empty() {
+        return None;
+    }
+
+    Some(RuleResult::new(
+        &rule.severity,
+        TestAble::get_object_type(item),
+        rule.get_name(),
+        format!(
+            "{} {}",
+            TestAble::get_object_string(item),
+            issues.join(" and ")
+        ),
+        TestAble::get_relative_path(item).cloned(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::manifest::dbt_objects::Source;
+    use serde_json::json;
+
+    fn manifest() -> Manifest {
+        let model: Node = serde_json::from_value(json!({
+            "resource_type": "model",
+            "database": "db",
+            "schema": "public",
+            "name": "customers",
+            "package_name": "test",
+            "path": "customers.sql",
+            "original_file_path": "models/customers.sql",
+            "unique_id": "model.test.customers",
+            "fqn": ["test", "customers"],
+            "alias": "customers",
+            "checksum": {"name": "sha256", "checksum": "abc"},
+            "depends_on": {"nodes": []}
+        }))
+        .unwrap();
+        let source: Source = serde_json::from_value(json!({
+            "database": "db",
+            "name": "stores",
+            "resource_type": "source",
+            "package_name": "test",
+            "original_file_path": "models/sources.yml",
+            "unique_id": "source.test.raw.stores",
+            "source_name": "raw"
+        }))
+        .unwrap();
+
+        let mut manifest = Manifest::default();
+        manifest
+            .nodes
+            .insert("model.test.customers".to_string(), model);
+        manifest
+            .sources
+            .insert("source.test.raw.stores".to_string(), source);
+        manifest
+    }
+
+    #[test]
+    fn test_relation_exists() {
+        let manifest = manifest();
+        assert_eq!(relation_exists("ref('customers')", &manifest), Some(true));
+        assert_eq!(
+            relation_exists("ref(\"test\", \"customers\", v=2)", &manifest),
+            Some(true)
+        );
+        assert_eq!(
+            relation_exists("ref('other_package', 'customers')", &manifest),
+            Some(false)
+        );
+        assert_eq!(relation_exists("ref('clients')", &manifest), Some(false));
+        assert_eq!(
+            relation_exists("source('raw', 'stores')", &manifest),
+            Some(true)
+        );
+        assert_eq!(
+            relation_exists("source('raw', 'customers')", &manifest),
+            Some(false)
+        );
+        assert_eq!(relation_exists("ref(var('customers'))", &manifest), None);
+    }
+}
@@ -91,6 +91,7 @@ pub fn has_tags<T: Tagable>(
 mod tests {
     use super::*;
     use crate::core::config::{manifest_rule::ManifestSpecificRuleConfig, severity::Severity};
+    use crate::core::selection::RuleSelection;
     struct TestTagable {
         tags: Option<Tags>,
         object_type: String,
@@ -127,6 +128,7 @@ mod tests {
             applies_to: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: ManifestSpecificRuleConfig::HasTags {
                 criteria: HasTagsCriteria::All,
                 required_tags: required_tags.clone(),
@@ -156,6 +158,7 @@ mod tests {
             applies_to: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: ManifestSpecificRuleConfig::HasTags {
                 criteria: HasTagsCriteria::All,
                 required_tags: required_tags.clone(),
@@ -182,6 +185,7 @@ mod tests {
             applies_to: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: ManifestSpecificRuleConfig::HasTags {
                 criteria: HasTagsCriteria::OneOf,
                 required_tags: required_tags.clone(),
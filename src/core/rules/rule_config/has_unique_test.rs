@@ -27,11 +27,8 @@ pub fn has_unique_test<T: TestAble>(
     let unique_tests_found = testable
         .get_tests(manifest)
         .into_iter()
-        .filter(|test| {
-            // If there is a name and it matches => count it as a unique test
-            test.get_metadata_name()
-                .is_some_and(|test_name| allowed_test_names.iter().any(|name| name == &test_name))
-        })
+        // If there is a name and it matches => count it as a unique test
+        .filter(|test| test.is_any_of(allowed_test_names))
         .count();
 
     if unique_tests_found == 0 {
@@ -53,6 +50,7 @@ pub fn has_unique_test<T: TestAble>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::selection::RuleSelection;
     use crate::core::{
         config::{
             check_config_options::default_allowed_test_names,
@@ -124,6 +122,7 @@ mod tests {
             applies_to: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: ManifestSpecificRuleConfig::HasUniqueTest {
                 allowed_test_names: default_allowed_test_names(),
             },
@@ -139,6 +138,35 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_has_unique_test_from_package() {
+        let mut test = create_mock_test("unique_combination_of_columns", "model.my_model");
+        if let Some(metadata) = test.test_metadata.as_mut() {
+            metadata.namespace = Some("dbt_utils".to_string());
+        }
+        let manifest = create_test_manifest(vec![test]);
+        let rule = ManifestRule {
+            name: Some(String::new()),
+            severity: Severity::Error,
+            description: None,
+            applies_to: None,
+            includes: None,
+            excludes: None,
+            selection: RuleSelection::default(),
+            rule: ManifestSpecificRuleConfig::HasUniqueTest {
+                allowed_test_names: default_allowed_test_names(),
+            },
+        };
+        let testable = MockTestable {
+            unique_id: "model.my_model".to_string(),
+            object_type: "model".to_string(),
+            object_string: "my_model".to_string(),
+            relative_path: Some("models/my_model.sql".to_string()),
+        };
+        let result = has_unique_test(&testable, &rule, &manifest, &default_allowed_test_names());
+        assert!(result.is_none());
+    }
+
     #[test]
     fn test_has_unique_test_not_found() {
         let manifest = create_test_manifest(vec![]);
@@ -149,6 +177,7 @@ mod tests {
             applies_to: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: ManifestSpecificRuleConfig::HasUniqueTest {
                 allowed_test_names: default_allowed_test_names(),
             },
@@ -187,6 +216,7 @@ mod tests {
             applies_to: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: ManifestSpecificRuleConfig::HasUniqueTest {
                 allowed_test_names: default_allowed_test_names(),
             },
@@ -219,6 +249,7 @@ mod tests {
             applies_to: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: ManifestSpecificRuleConfig::HasUniqueTest {
                 allowed_test_names: vec!["custom_unique_test".to_string()],
             },
@@ -0,0 +1,182 @@
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::{check_config_options::Layer, manifest_rule::ManifestRule},
+        manifest::Manifest,
+    },
+};
+
+// Objects that can be assigned to a layer of the DAG (e.g. staging, intermediate, marts).
+// Models, snapshots and analyses are checked, sources and seeds only appear as upstream objects.
+pub trait Layerable {
+    fn get_unique_id(&self) -> &str;
+    fn get_name(&self) -> &str;
+    fn get_fqn(&self) -> &[String];
+    fn get_relative_path(&self) -> &String;
+    fn get_object_type(&self) -> &str;
+    fn get_depends_on_nodes(&self) -> &[String] {
+        &[]
+    }
+}
+
+fn layer_of<'a, T: Layerable + ?Sized>(layers: &'a [Layer], object: &T) -> Option<&'a Layer> {
+    let resource_type = object.get_unique_id().split('.').next().unwrap_or_default();
+    layers.iter().find(|layer| {
+        layer.matches(
+            object.get_relative_path(),
+            object.get_fqn(),
+            object.get_name(),
+            resource_type,
+        )
+    })
+}
+
+fn get_upstream<'a>(manifest: &'a Manifest, unique_id: &str) -> Option<&'a dyn Layerable> {
+    manifest
+        .nodes
+        .get(unique_id)
+        .map(|node| node as &dyn Layerable)
+        .or_else(|| {
+            manifest
+                .sources
+                .get(unique_id)
+                .map(|source| source as &dyn Layerable)
+        })
+}
+
+pub fn layer_dependencies<T: Layerable>(
+    item: &T,
+    rule: &ManifestRule,
+    layers: &[Layer],
+    manifest: &Manifest,
+) -> Option<RuleResult> {
+    // Objects outside of any layer are not restricted
+    let layer = layer_of(layers, item)?;
+
+    let violations: Vec<String> = item
+        .get_depends_on_nodes()
+        .iter()
+        .filter_map(|unique_id| get_upstream(manifest, unique_id))
+        .filter_map(|upstream| {
+            let upstream_layer = layer_of(layers, upstream)?;
+            (!layer.allows(upstream_layer))
+                .then(|| format!("{} (layer '{}')", upstream.get_name(), upstream_layer.name))
+        })
+        .collect();
+
+    if violations.is_empty() {
+        return None;
+    }
+
+    let allowed = layer
+        .allowed_upstream
+        .as_ref()
+        .filter(|allowed| !allowed.is_empty())
+        .map_or_else(|| "none".to_string(), |allowed| allowed.join(", "));
+    Some(RuleResult::new(
+        &rule.severity,
+        item.get_object_type(),
+        rule.get_name(),
+        format!(
+            "{} in layer '{}' depends on {}, allowed upstream layers are: {allowed}",
+            item.get_name(),
+            layer.name,
+            violations.join(", ")
+        ),
+        Some(item.get_relative_path().clone()),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::manifest_rule::ManifestSpecificRuleConfig;
+
+    struct TestObject {
+        unique_id: String,
+        name: String,
+        path: String,
+        depends_on: Vec<String>,
+    }
+
+    impl Layerable for TestObject {
+        fn get_unique_id(&self) -> &str {
+            &self.unique_id
+        }
+
+        fn get_name(&self) -> &str {
+            &self.name
+        }
+
+        fn get_fqn(&self) -> &[String] {
+            &[]
+        }
+
+        fn get_relative_path(&self) -> &String {
+            &self.path
+        }
+
+        fn get_object_type(&self) -> &'static str {
+            "Model"
+        }
+
+        fn get_depends_on_nodes(&self) -> &[String] {
+            &self.depends_on
+        }
+    }
+
+    fn rule(layers: &str) -> ManifestRule {
+        ManifestRule::for_test(ManifestSpecificRuleConfig::LayerDependencies {
+            layers: serde_yaml::from_str(layers).unwrap(),
+        })
+    }
+
+    fn layers(rule: &ManifestRule) -> &[Layer] {
+        match &rule.rule {
+            ManifestSpecificRuleConfig::LayerDependencies { layers } => layers,
+            _ => unreachable!(),
+        }
+    }
+
+    fn model(name: &str, path: &str) -> TestObject {
+        TestObject {
+            unique_id: format!("model.test.{name}"),
+            name: name.to_string(),
+            path: path.to_string(),
+            depends_on: vec![],
+        }
+    }
+
+    const LAYERS: &str = r#"
+- name: staging
+  name_prefixes: ["stg_"]
+- name: marts
+  paths: ["models/marts/**"]
+  allowed_upstream: ["staging", "marts"]
+"#;
+
+    #[test]
+    fn test_layer_assignment() {
+        let rule = rule(LAYERS);
+        let layers = layers(&rule);
+
+        let staging = model("stg_orders", "models/staging/stg_orders.sql");
+        assert_eq!(layer_of(layers, &staging).unwrap().name, "staging");
+        let mart = model("orders", "models/marts/finance/orders.sql");
+        assert_eq!(layer_of(layers, &mart).unwrap().name, "marts");
+        // The first matching layer wins
+        let staging_in_marts = model("stg_legacy", "models/marts/stg_legacy.sql");
+        assert_eq!(layer_of(layers, &staging_in_marts).unwrap().name, "staging");
+        assert!(layer_of(layers, &model("other", "models/other.sql")).is_none());
+    }
+
+    #[test]
+    fn test_model_outside_of_layers_passes() {
+        let rule = rule(LAYERS);
+        let item = TestObject {
+            depends_on: vec!["model.test.orders".to_string()],
+            ..model("other", "models/other.sql")
+        };
+        assert!(layer_dependencies(&item, &rule, layers(&rule), &Manifest::default()).is_none());
+    }
+}
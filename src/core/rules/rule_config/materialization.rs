@@ -0,0 +1,194 @@
+use crate::{
+    cli::table::RuleResult,
+    core::config::{check_config_options::MaterializationPolicy, manifest_rule::ManifestRule},
+};
+
+// Models with a `materialized` config and its related settings
+pub trait Materializable {
+    fn get_name(&self) -> &str;
+    fn get_object_type(&self) -> &str;
+    fn get_relative_path(&self) -> &String;
+    fn get_fqn(&self) -> &[String];
+    /// The `materialized` config, or dbt's default when it is not set
+    fn get_materialized_or_default(&self) -> &str;
+    fn get_incremental_strategy(&self) -> Option<&str>;
+    fn has_unique_key(&self) -> bool;
+    /// Whether the config key is set by the user (in the model or `dbt_project.yml`), not defaulted by dbt
+    fn has_explicit_config(&self, key: &str) -> bool;
+}
+
+pub fn materialization<T: Materializable>(
+    item: &T,
+    rule: &ManifestRule,
+    policies: &[MaterializationPolicy],
+    require_unique_key: bool,
+    require_on_schema_change: bool,
+) -> Option<RuleResult> {
+    let materialized = item.get_materialized_or_default();
+    let mut problems = vec![];
+
+    if let Some(policy) = policies
+        .iter()
+        .find(|policy| policy.matches(item.get_relative_path(), item.get_fqn(), item.get_name()))
+    {
+        if !policy.allowed.iter().any(|allowed| allowed == materialized) {
+            problems.push(format!(
+                "is materialized as '{materialized}' (allowed: {})",
+                policy.allowed.join(", ")
+            ));
+        }
+    }
+
+    if materialized == "incremental" {
+        let mut missing = vec![];
+        // The append strategy only inserts new rows and doesn't use a unique key
+        if require_unique_key
+            && item.get_incremental_strategy() != Some("append")
+            && !item.has_unique_key()
+        {
+            missing.push("a unique_key");
+        }
+        if require_on_schema_change && !item.has_explicit_config("on_schema_change") {
+            missing.push("an explicit on_schema_change");
+        }
+        if !missing.is_empty() {
+            problems.push(format!("is incremental without {}", missing.join(" and ")));
+        }
+    }
+
+    if problems.is_empty() {
+        return None;
+    }
+
+    Some(RuleResult::new(
+        &rule.severity,
+        item.get_object_type(),
+        rule.get_name(),
+        format!("{} {}", item.get_name(), problems.join(" and ")),
+        Some(item.get_relative_path().clone()),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::manifest_rule::ManifestSpecificRuleConfig;
+    use crate::core::manifest::dbt_objects::nodes::node::DEFAULT_MATERIALIZATION;
+
+    #[derive(Default)]
+    struct TestModel {
+        name: String,
+        path: String,
+        materialized: Option<String>,
+        incremental_strategy: Option<String>,
+        unique_key: bool,
+        on_schema_change: bool,
+    }
+
+    impl Materializable for TestModel {
+        fn get_name(&self) -> &str {
+            &self.name
+        }
+
+        fn get_object_type(&self) -> &'static str {
+            "Model"
+        }
+
+        fn get_relative_path(&self) -> &String {
+            &self.path
+        }
+
+        fn get_fqn(&self) -> &[String] {
+            &[]
+        }
+
+        fn get_materialized_or_default(&self) -> &str {
+            self.materialized
+                .as_deref()
+                .unwrap_or(DEFAULT_MATERIALIZATION)
+        }
+
+        fn get_incremental_strategy(&self) -> Option<&str> {
+            self.incremental_strategy.as_deref()
+        }
+
+        fn has_unique_key(&self) -> bool {
+            self.unique_key
+        }
+
+        fn has_explicit_config(&self, key: &str) -> bool {
+            key == "on_schema_change" && self.on_schema_change
+        }
+    }
+
+    fn check(item: &TestModel) -> Option<String> {
+        let rule = ManifestRule::for_test(ManifestSpecificRuleConfig::Materialization {
+            policies: serde_yaml::from_str(
+                r#"
+- name_prefixes: ["stg_"]
+  allowed: ["view"]
+- paths: ["models/marts/**"]
+  allowed: ["table", "incremental"]
+"#,
+            )
+            .unwrap(),
+            require_unique_key: true,
+            require_on_schema_change: true,
+        });
+        let ManifestSpecificRuleConfig::Materialization { policies, .. } = &rule.rule else {
+            unreachable!()
+        };
+        materialization(item, &rule, policies, true, true).map(|result| result.message)
+    }
+
+    #[test]
+    fn test_policies() {
+        // Models without a materialization are views
+        let staging = TestModel {
+            name: "stg_orders".to_string(),
+            path: "models/staging/stg_orders.sql".to_string(),
+            ..Default::default()
+        };
+        assert!(check(&staging).is_none());
+
+        let mart = TestModel {
+            name: "orders".to_string(),
+            path: "models/marts/orders.sql".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            check(&mart).unwrap(),
+            "orders is materialized as 'view' (allowed: table, incremental)"
+        );
+
+        // Models outside of any policy may use any materialization
+        let other = TestModel {
+            name: "scratch".to_string(),
+            path: "models/scratch.sql".to_string(),
+            materialized: Some("table".to_string()),
+            ..Default::default()
+        };
+        assert!(check(&other).is_none());
+    }
+
+    #[test]
+    fn test_incremental_settings() {
+        let incremental = TestModel {
+            name: "events".to_string(),
+            path: "models/marts/events.sql".to_string(),
+            materialized: Some("incremental".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            check(&incremental).unwrap(),
+            "events is incremental without a unique_key and an explicit on_schema_change"
+        );
+
+        let append = TestModel {
+            incremental_strategy: Some("append".to_string()),
+            on_schema_change: true,
+            ..incremental
+        };
+        assert!(check(&append).is_none());
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    cli::table::RuleResult,
+    core::{config::manifest_rule::ManifestRule, manifest::Manifest},
+};
+
+use super::max_dependencies::HasLineage;
+
+/// Longest upstream chains of the models in a manifest, computed on demand and shared by all evaluated models.
+/// Build one per `materializations` filter and run, so every model is only visited once.
+pub struct ChainDepth<'a> {
+    manifest: &'a Manifest,
+    materializations: Option<&'a [String]>,
+    // Longest chain above a model: its length and the parent it continues with
+    memo: HashMap<&'a str, (usize, Option<&'a str>)>,
+    visiting: HashSet<&'a str>,
+}
+
+impl<'a> ChainDepth<'a> {
+    pub fn new(manifest: &'a Manifest, materializations: Option<&'a [String]>) -> Self {
+        Self {
+            manifest,
+            materializations,
+            memo: HashMap::new(),
+            visiting: HashSet::new(),
+        }
+    }
+
+    fn counts(&self, unique_id: &str) -> bool {
+        unique_id.starts_with("model.") && self.has_counted_materialization(unique_id)
+    }
+
+    /// Whether the node has one of the `materializations`, always true without a filter
+    fn has_counted_materialization(&self, unique_id: &str) -> bool {
+        let Some(node) = self.manifest.nodes.get(unique_id) else {
+            return false;
+        };
+        self.materializations.is_none_or(|materializations| {
+            materializations
+                .iter()
+                .any(|m| m == node.get_materialized_or_default())
+        })
+    }
+
+    /// Number of models in the longest chain of counted models directly above `unique_id`
+    fn upstream(&mut self, unique_id: &'a str) -> (usize, Option<&'a str>) {
+        if let Some(depth) = self.memo.get(unique_id) {
+            return *depth;
+        }
+        // Cycles can't exist in a valid manifest, but never recurse forever
+        if !self.visiting.insert(unique_id) {
+            return (0, None);
+        }
+
+        let manifest = self.manifest;
+        let mut longest = (0, None);
+        for parent in manifest.parent_map.get(unique_id).into_iter().flatten() {
+            if !self.counts(parent) {
+                continue;
+            }
+            let depth = self.upstream(parent).0 + 1;
+            if depth > longest.0 {
+                longest = (depth, Some(parent.as_str()));
+            }
+        }
+
+        self.visiting.remove(unique_id);
+        self.memo.insert(unique_id, longest);
+        longest
+    }
+
+    fn chain(&mut self, unique_id: &'a str) -> Vec<&'a str> {
+        let mut chain = vec![];
+        let mut current = self.upstream(unique_id).1;
+        while let Some(parent) = current {
+            chain.push(parent);
+            current = self.upstream(parent).1;
+        }
+        chain.reverse();
+        chain
+    }
+}
+
+/// The depth of a model is the number of models in the longest upstream chain ending in it, including itself.
+///
+/// With `materializations`, only models with one of these materializations count, including the model itself,
+/// e.g. a table ends a chain of views as it is read from the warehouse instead of recomputed.
+pub fn max_chain_depth<'a, T: HasLineage>(
+    item: &'a T,
+    rule: &ManifestRule,
+    max_depth: usize,
+    chain_depth: &mut ChainDepth<'a>,
+) -> Option<RuleResult> {
+    let manifest = chain_depth.manifest;
+    // The model itself only counts with one of the `materializations`, like the models above it
+    let counted = chain_depth.has_counted_materialization(item.get_unique_id());
+    let depth = chain_depth.upstream(item.get_unique_id()).0 + usize::from(counted);
+    if depth <= max_depth {
+        return None;
+    }
+
+    let chain: Vec<&str> = chain_depth
+        .chain(item.get_unique_id())
+        .into_iter()
+        .filter_map(|unique_id| manifest.nodes.get(unique_id))
+        .map(|node| node.get_name().as_str())
+        .chain(counted.then_some(item.get_object_string()))
+        .collect();
+    Some(RuleResult::new(
+        &rule.severity,
+        item.get_object_type(),
+        rule.get_name(),
+        format!(
+            "{} has a chain depth of {depth} ({}), the maximum is {max_depth}",
+            item.get_object_string(),
+            chain.join(" -> ")
+        ),
+        item.get_relative_path().cloned(),
+    ))
+}
@@ -5,6 +5,9 @@ pub trait HasCode {
     fn get_name(&self) -> &str;
     fn get_relative_path(&self) -> Option<&String>;
     fn get_object_type(&self) -> &str;
+    fn get_unique_id(&self) -> Option<&str> {
+        None
+    }
 }
 
 // Models, Macros, Snapshots can all contain code.
@@ -31,19 +34,26 @@ pub fn max_code_lines<T: HasCode>(
             max_length
         )
     };
-    Some(RuleResult::new(
+    let result = RuleResult::new(
         &rule.severity,
         object_with_code.get_object_type(),
         rule.get_name(),
         message,
         object_with_code.get_relative_path().cloned(),
-    ))
+    );
+    // Point at the first line exceeding the maximum, relative to the start of the code
+    Some(if code_lines == 0 {
+        result
+    } else {
+        result.with_location(max_length + 1, None)
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::config::{manifest_rule::ManifestSpecificRuleConfig, severity::Severity};
+    use crate::core::selection::RuleSelection;
 
     struct TestNode {
         name: String,
@@ -74,6 +84,7 @@ mod tests {
             applies_to: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             description: None,
             rule: ManifestSpecificRuleConfig::MaxCodeLines { max_lines: 5 },
         };
@@ -101,6 +112,7 @@ mod tests {
             applies_to: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             description: None,
             rule: ManifestSpecificRuleConfig::MaxCodeLines { max_lines: 5 },
         };
@@ -122,6 +134,7 @@ mod tests {
             applies_to: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             description: None,
             rule: ManifestSpecificRuleConfig::MaxCodeLines { max_lines: 5 },
         };
@@ -142,6 +155,7 @@ mod tests {
             applies_to: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             description: None,
             rule: ManifestSpecificRuleConfig::MaxCodeLines { max_lines: 5 },
         };
@@ -162,6 +176,7 @@ mod tests {
             applies_to: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             description: None,
             rule: ManifestSpecificRuleConfig::MaxCodeLines { max_lines: 5 },
         };
@@ -182,6 +197,7 @@ mod tests {
             applies_to: None,
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             description: None,
             rule: ManifestSpecificRuleConfig::MaxCodeLines { max_lines: 5 },
         };
@@ -192,9 +208,8 @@ mod tests {
         };
         let result = max_code_lines(&test_node, &rule, 5);
         assert!(result.is_some());
-        assert!(result
-            .unwrap()
-            .message
-            .contains("exceeds the maximum allowed"));
+        let result = result.unwrap();
+        assert!(result.message.contains("exceeds the maximum allowed"));
+        assert_eq!(result.line, Some(6));
     }
 }
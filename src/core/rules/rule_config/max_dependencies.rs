@@ -0,0 +1,158 @@
+use crate::{
+    cli::table::RuleResult,
+    core::{config::manifest_rule::ManifestRule, manifest::Manifest},
+};
+
+// Objects with direct parents and children in the DAG, looked up in the `parent_map` and `child_map`.
+pub trait HasLineage {
+    fn get_unique_id(&self) -> &str;
+    fn get_object_type(&self) -> &str;
+    fn get_object_string(&self) -> &str;
+    fn get_relative_path(&self) -> Option<&String> {
+        None
+    }
+}
+
+// Only dependencies of the given resource types (`model`, `source`, ...) count towards the maximum
+fn count_dependencies(
+    map: &std::collections::HashMap<String, Vec<String>>,
+    unique_id: &str,
+    resource_types: &[String],
+) -> usize {
+    map.get(unique_id).map_or(0, |dependencies| {
+        dependencies
+            .iter()
+            .filter(|dependency| {
+                let resource_type = dependency.split('.').next().unwrap_or_default();
+                resource_types.iter().any(|rt| rt == resource_type)
+            })
+            .count()
+    })
+}
+
+fn check_max<T: HasLineage>(
+    item: &T,
+    rule: &ManifestRule,
+    count: usize,
+    max: usize,
+    kind: &str,
+) -> Option<RuleResult> {
+    if count <= max {
+        return None;
+    }
+
+    Some(RuleResult::new(
+        &rule.severity,
+        item.get_object_type(),
+        rule.get_name(),
+        format!(
+            "{} has {count} direct {kind}, the maximum is {max}",
+            item.get_object_string()
+        ),
+        item.get_relative_path().cloned(),
+    ))
+}
+
+pub fn max_children<T: HasLineage>(
+    item: &T,
+    rule: &ManifestRule,
+    max_children: usize,
+    resource_types: &[String],
+    manifest: &Manifest,
+) -> Option<RuleResult> {
+    let count = count_dependencies(&manifest.child_map, item.get_unique_id(), resource_types);
+    check_max(item, rule, count, max_children, "children")
+}
+
+pub fn max_parents<T: HasLineage>(
+    item: &T,
+    rule: &ManifestRule,
+    max_parents: usize,
+    resource_types: &[String],
+    manifest: &Manifest,
+) -> Option<RuleResult> {
+    let count = count_dependencies(&manifest.parent_map, item.get_unique_id(), resource_types);
+    check_max(item, rule, count, max_parents, "parents")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::check_config_options::default_counted_resource_types;
+    use crate::core::config::manifest_rule::ManifestSpecificRuleConfig;
+
+    struct TestModel;
+
+    impl HasLineage for TestModel {
+        fn get_unique_id(&self) -> &'static str {
+            "model.test.orders"
+        }
+
+        fn get_object_type(&self) -> &'static str {
+            "Model"
+        }
+
+        fn get_object_string(&self) -> &'static str {
+            "orders"
+        }
+    }
+
+    fn manifest() -> Manifest {
+        let mut manifest = Manifest::default();
+        manifest.child_map.insert(
+            "model.test.orders".to_string(),
+            vec![
+                "model.test.orders_daily".to_string(),
+                "model.test.orders_weekly".to_string(),
+                "snapshot.test.orders_snapshot".to_string(),
+                "test.test.unique_orders_id".to_string(),
+                "test.test.not_null_orders_id".to_string(),
+            ],
+        );
+        manifest.parent_map.insert(
+            "model.test.orders".to_string(),
+            vec!["model.test.stg_orders".to_string()],
+        );
+        manifest
+    }
+
+    #[test]
+    fn test_max_children() {
+        let rule = ManifestRule::for_test(ManifestSpecificRuleConfig::MaxChildren {
+            max_children: 2,
+            resource_types: default_counted_resource_types(),
+        });
+        let result = max_children(
+            &TestModel,
+            &rule,
+            2,
+            &default_counted_resource_types(),
+            &manifest(),
+        )
+        .unwrap();
+        // Tests are not counted by default
+        assert_eq!(
+            result.message,
+            "orders has 3 direct children, the maximum is 2"
+        );
+
+        let models = vec!["model".to_string()];
+        assert!(max_children(&TestModel, &rule, 2, &models, &manifest()).is_none());
+    }
+
+    #[test]
+    fn test_max_parents() {
+        let rule = ManifestRule::for_test(ManifestSpecificRuleConfig::MaxParents {
+            max_parents: 1,
+            resource_types: default_counted_resource_types(),
+        });
+        let types = default_counted_resource_types();
+        assert!(max_parents(&TestModel, &rule, 1, &types, &manifest()).is_none());
+        let result = max_parents(&TestModel, &rule, 0, &types, &manifest()).unwrap();
+        assert_eq!(
+            result.message,
+            "orders has 1 direct parents, the maximum is 0"
+        );
+        assert!(max_parents(&TestModel, &rule, 0, &types, &Manifest::default()).is_none());
+    }
+}
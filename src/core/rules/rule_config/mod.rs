@@ -3,17 +3,44 @@ pub mod has_contract_enforced;
 pub mod has_description;
 pub mod has_metadata_keys;
 pub mod has_refs;
+pub mod has_relationship_test;
 pub mod has_tags;
 pub mod has_unique_test;
+pub mod layer_dependencies;
+pub mod materialization;
+pub mod max_chain_depth;
 pub mod max_code_lines;
+pub mod max_dependencies;
 pub mod name_convention;
+pub mod no_hardcoded_references;
+pub mod no_redundant_dependencies;
+pub mod primary_key_tested;
+pub mod source_has_freshness;
+pub mod source_references;
+pub mod sql;
+pub mod sql_style;
+pub mod test_coverage;
 
 pub use child_map::is_not_orphaned;
 pub use has_contract_enforced::has_contract_enforced;
 pub use has_description::has_description;
 pub use has_metadata_keys::has_metadata_keys;
 pub use has_refs::has_refs;
+pub use has_relationship_test::has_relationship_test;
 pub use has_tags::has_tags;
 pub use has_unique_test::has_unique_test;
+pub use layer_dependencies::layer_dependencies;
+pub use materialization::materialization;
+pub use max_chain_depth::max_chain_depth;
 pub use max_code_lines::max_code_lines;
+pub use max_dependencies::{max_children, max_parents};
 pub use name_convention::check_name_convention;
+pub use no_hardcoded_references::no_hardcoded_references;
+pub use no_redundant_dependencies::no_redundant_dependencies;
+pub use primary_key_tested::primary_key_tested;
+pub use source_has_freshness::source_has_freshness;
+pub use source_references::{direct_source_reference, source_fanout};
+pub use sql_style::{
+    forbidden_keywords, import_ctes, no_distinct_without_comment, no_order_by, no_select_star,
+};
+pub use test_coverage::test_coverage;
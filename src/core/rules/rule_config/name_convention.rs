@@ -54,6 +54,7 @@ mod tests {
     use crate::core::config::{
         applies_to::AppliesTo, manifest_rule::ManifestSpecificRuleConfig, severity::Severity,
     };
+    use crate::core::selection::RuleSelection;
 
     use super::*;
     struct TestItem {
@@ -82,6 +83,7 @@ mod tests {
             applies_to: Some(AppliesTo::empty()),
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: ManifestSpecificRuleConfig::NameConvention {
                 pattern: "snake_case".to_string(),
             },
@@ -117,6 +119,7 @@ mod tests {
             applies_to: Some(AppliesTo::empty()),
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: ManifestSpecificRuleConfig::NameConvention {
                 pattern: "PascalCase".to_string(),
             },
@@ -152,6 +155,7 @@ mod tests {
             applies_to: Some(AppliesTo::empty()),
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: ManifestSpecificRuleConfig::NameConvention {
                 pattern: "kebab-case".to_string(),
             },
@@ -187,6 +191,7 @@ mod tests {
             applies_to: Some(AppliesTo::empty()),
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: ManifestSpecificRuleConfig::NameConvention {
                 pattern: "camelCase".to_string(),
             },
@@ -222,6 +227,7 @@ mod tests {
             applies_to: Some(AppliesTo::empty()),
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: ManifestSpecificRuleConfig::NameConvention {
                 pattern: r"^[A-Z]{3}-[0-9]{4}$".to_string(),
             },
@@ -257,6 +263,7 @@ mod tests {
             applies_to: Some(AppliesTo::empty()),
             includes: None,
             excludes: None,
+            selection: RuleSelection::default(),
             rule: ManifestSpecificRuleConfig::NameConvention {
                 pattern: "(*invalid_regex".to_string(),
             },
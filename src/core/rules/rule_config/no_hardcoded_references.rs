@@ -0,0 +1,142 @@
+use crate::{cli::table::RuleResult, core::config::manifest_rule::ManifestRule};
+
+use super::{
+    max_code_lines::HasCode,
+    sql::{SqlCode, Token, TokenKind},
+};
+
+// Functions that use FROM in their arguments, e.g. `extract(day from created_at)`
+const FROM_FUNCTIONS: [&str; 5] = ["extract", "trim", "substring", "overlay", "position"];
+
+/// Relations after FROM or JOIN that are written as `schema.table` or `database.schema.table`
+/// instead of using `ref()` or `source()`, with their line and column.
+pub fn find_hardcoded_references(code: &str) -> Vec<(String, usize, usize)> {
+    let tokens = SqlCode::parse(code).tokens;
+    let mut references = vec![];
+    // Whether each open parenthesis belongs to a function with FROM in its arguments
+    let mut parentheses: Vec<bool> = vec![];
+
+    for (index, token) in tokens.iter().enumerate() {
+        let previous = index.checked_sub(1).and_then(|i| tokens.get(i));
+        if token.is_symbol('(') {
+            parentheses.push(
+                FROM_FUNCTIONS
+                    .iter()
+                    .any(|function| previous.is_some_and(|previous| previous.is_keyword(function))),
+            );
+            continue;
+        }
+        if token.is_symbol(')') {
+            parentheses.pop();
+            continue;
+        }
+
+        let is_from = token.is_keyword("from");
+        if !is_from && !token.is_keyword("join") {
+            continue;
+        }
+        // `extract(day from created_at)` and `a is distinct from b`
+        if is_from
+            && (parentheses.last() == Some(&true)
+                || previous.is_some_and(|previous| previous.is_keyword("distinct")))
+        {
+            continue;
+        }
+
+        let Some(Token {
+            kind: TokenKind::Word(relation),
+            line,
+            column,
+        }) = tokens.get(index + 1)
+        else {
+            continue;
+        };
+        // Relations built with Jinja (`prod.{{ var('table') }}`) and function calls
+        let dynamic = tokens
+            .get(index + 2)
+            .is_some_and(|next| next.is_jinja() || next.is_symbol('('));
+        if relation.contains('.')
+            && !relation.starts_with('.')
+            && !relation.ends_with('.')
+            && !dynamic
+        {
+            references.push((relation.clone(), *line, *column));
+        }
+    }
+    references
+}
+
+pub fn no_hardcoded_references<T: HasCode>(item: &T, rule: &ManifestRule) -> Option<RuleResult> {
+    let references = find_hardcoded_references(item.get_code()?);
+    let (_, line, column) = references.first()?;
+
+    Some(
+        RuleResult::new(
+            &rule.severity,
+            item.get_object_type(),
+            rule.get_name(),
+            format!(
+                "{} has hard-coded relation references: {}, use ref() or source() instead",
+                item.get_name(),
+                references
+                    .iter()
+                    .map(|(relation, line, _)| format!("{relation} (line {line})"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            item.get_relative_path().cloned(),
+        )
+        .with_location(*line, Some(*column)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn relations(code: &str) -> Vec<(String, usize)> {
+        find_hardcoded_references(code)
+            .into_iter()
+            .map(|(relation, line, _)| (relation, line))
+            .collect()
+    }
+
+    #[test]
+    fn test_finds_hardcoded_relations() {
+        let code = "select *\nfrom analytics.prod.orders o\nleft join prod.\"Customers\" c on o.id = c.id\nJOIN orders_cte using (id)";
+        assert_eq!(
+            relations(code),
+            vec![
+                ("analytics.prod.orders".to_string(), 2),
+                ("prod.\"Customers\"".to_string(), 3),
+            ]
+        );
+        assert_eq!(find_hardcoded_references(code)[0].2, 6);
+    }
+
+    #[test]
+    fn test_ignores_jinja_comments_and_strings() {
+        let code = r"
+{# from analytics.prod.orders #}
+{% set relation = 'analytics.prod.orders' %}
+-- select * from analytics.prod.orders
+/* join analytics.prod.customers */
+select 'from prod.orders' as note
+from {{ ref('orders') }} as o
+join {{ target.schema }}.customers c on true
+join prod.{{ var('table') }} t on true
+join {{ source('raw', 'payments') }}
+-- join prod.customers";
+        assert!(relations(code).is_empty());
+    }
+
+    #[test]
+    fn test_ignores_from_in_expressions() {
+        let code = "select extract(day from o.created_at), trim(both ' ' from o.name), o.a is distinct from o.b\nfrom (select * from {{ ref('orders') }}) o\ncross join lateral flatten(input => o.items)\njoin my_db.my_function(1) f on true";
+        assert!(relations(code).is_empty());
+
+        // Subqueries are still checked
+        let code = "select * from {{ ref('a') }} where id in (select id from prod.ids)";
+        assert_eq!(relations(code), vec![("prod.ids".to_string(), 1)]);
+    }
+}
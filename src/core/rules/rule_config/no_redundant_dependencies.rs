@@ -0,0 +1,167 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::{
+    cli::table::RuleResult,
+    core::{config::manifest_rule::ManifestRule, manifest::Manifest},
+};
+
+use super::max_dependencies::HasLineage;
+
+fn display_name<'a>(manifest: &'a Manifest, unique_id: &'a str) -> String {
+    if let Some(node) = manifest.nodes.get(unique_id) {
+        return node.get_name().clone();
+    }
+    if let Some(source) = manifest.sources.get(unique_id) {
+        return format!("{}.{}", source.get_source_name(), source.get_name());
+    }
+    unique_id.to_string()
+}
+
+/// Shortest path from `target` down to one of the `starts` following the `parent_map`,
+/// returned from `target` to the start it was reached from.
+fn upstream_path<'a>(
+    manifest: &'a Manifest,
+    starts: &[&'a str],
+    target: &str,
+) -> Option<Vec<&'a str>> {
+    let mut previous: HashMap<&str, &str> = HashMap::new();
+    let mut visited: HashSet<&str> = starts.iter().copied().collect();
+    let mut queue: VecDeque<&str> = starts.iter().copied().collect();
+
+    while let Some(current) = queue.pop_front() {
+        for parent in manifest.parent_map.get(current).into_iter().flatten() {
+            let parent = parent.as_str();
+            if !visited.insert(parent) {
+                continue;
+            }
+            previous.insert(parent, current);
+            if parent == target {
+                let mut path = vec![parent];
+                let mut step = parent;
+                while let Some(next) = previous.get(step) {
+                    path.push(next);
+                    step = next;
+                }
+                return Some(path);
+            }
+            queue.push_back(parent);
+        }
+    }
+    None
+}
+
+/// Flags direct dependencies that are also reached through another dependency,
+/// e.g. `orders` selecting from `stg_customers` and from `int_customers`, which is built on `stg_customers`.
+pub fn no_redundant_dependencies<T: HasLineage>(
+    item: &T,
+    rule: &ManifestRule,
+    manifest: &Manifest,
+) -> Option<RuleResult> {
+    let mut parents: Vec<&str> = manifest
+        .parent_map
+        .get(item.get_unique_id())
+        .into_iter()
+        .flatten()
+        .map(String::as_str)
+        .collect();
+    parents.sort_unstable();
+    parents.dedup();
+
+    let redundant: Vec<String> = parents
+        .iter()
+        .filter_map(|parent| {
+            let others: Vec<&str> = parents.iter().copied().filter(|p| p != parent).collect();
+            let path = upstream_path(manifest, &others, parent)?;
+            let path: Vec<String> = path
+                .into_iter()
+                .map(|unique_id| display_name(manifest, unique_id))
+                .collect();
+            Some(format!(
+                "{} (already via {} -> {})",
+                display_name(manifest, parent),
+                path.join(" -> "),
+                item.get_object_string()
+            ))
+        })
+        .collect();
+
+    if redundant.is_empty() {
+        return None;
+    }
+
+    Some(RuleResult::new(
+        &rule.severity,
+        item.get_object_type(),
+        rule.get_name(),
+        format!(
+            "{} has redundant dependencies on {}",
+            item.get_object_string(),
+            redundant.join(", ")
+        ),
+        item.get_relative_path().cloned(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::manifest_rule::ManifestSpecificRuleConfig;
+
+    struct TestModel(&'static str);
+
+    impl HasLineage for TestModel {
+        fn get_unique_id(&self) -> &'static str {
+            self.0
+        }
+
+        fn get_object_type(&self) -> &'static str {
+            "Model"
+        }
+
+        fn get_object_string(&self) -> &'static str {
+            self.0.rsplit('.').next().unwrap()
+        }
+    }
+
+    fn manifest(edges: &[(&str, &[&str])]) -> Manifest {
+        let mut manifest = Manifest::default();
+        for (child, parents) in edges {
+            manifest.parent_map.insert(
+                (*child).to_string(),
+                parents.iter().map(ToString::to_string).collect(),
+            );
+        }
+        manifest
+    }
+
+    #[test]
+    fn test_rejoining_dependency() {
+        let rule = ManifestRule::for_test(ManifestSpecificRuleConfig::NoRedundantDependencies {});
+        let manifest = manifest(&[
+            ("model.test.orders", &["model.test.a", "model.test.c"]),
+            ("model.test.c", &["model.test.b"]),
+            ("model.test.b", &["model.test.a"]),
+        ]);
+        let result =
+            no_redundant_dependencies(&TestModel("model.test.orders"), &rule, &manifest).unwrap();
+        assert_eq!(
+            result.message,
+            "orders has redundant dependencies on model.test.a (already via model.test.a -> model.test.b -> model.test.c -> orders)"
+        );
+    }
+
+    #[test]
+    fn test_independent_parents_and_cycles() {
+        let rule = ManifestRule::for_test(ManifestSpecificRuleConfig::NoRedundantDependencies {});
+        let manifest = manifest(&[
+            ("model.test.orders", &["model.test.a", "model.test.b"]),
+            ("model.test.a", &["model.test.b_source"]),
+            // A cycle upstream must not loop forever
+            ("model.test.b", &["model.test.c"]),
+            ("model.test.c", &["model.test.b"]),
+        ]);
+        assert!(
+            no_redundant_dependencies(&TestModel("model.test.orders"), &rule, &manifest).is_none()
+        );
+    }
+}
@@ -0,0 +1,286 @@
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::manifest_rule::ManifestRule,
+        manifest::{
+            dbt_objects::nodes::test::{normalize_column_name, string_list, Test},
+            Manifest,
+        },
+    },
+};
+
+use super::{has_metadata_keys::HasMetadata, has_unique_test::TestAble};
+
+pub trait HasPrimaryKey: TestAble + HasMetadata {
+    /// The `primary_key` dbt resolved for the object, empty when it could not determine one
+    fn get_primary_key(&self) -> Option<&Vec<String>>;
+}
+
+// Compares column lists regardless of order, case and quoting
+fn same_columns(left: &[String], right: &[String]) -> bool {
+    let normalize = |columns: &[String]| {
+        let mut columns: Vec<String> = columns
+            .iter()
+            .map(|column| normalize_column_name(column))
+            .collect();
+        columns.sort();
+        columns
+    };
+    normalize(left) == normalize(right)
+}
+
+/// Determines the primary key from, in order: the configured meta key,
+/// the `primary_key` of the model and a model level uniqueness test on multiple columns.
+pub fn primary_key<T: HasPrimaryKey>(
+    item: &T,
+    tests: &[&Test],
+    meta_key: Option<&String>,
+    unique_test_names: &[String],
+) -> Option<Vec<String>> {
+    let from_meta = meta_key.and_then(|key| {
+        item.get_metadata()
+            .and_then(|meta| meta.0.get(key))
+            .and_then(string_list)
+    });
+    let declared = item
+        .get_primary_key()
+        .filter(|columns| !columns.is_empty())
+        .cloned();
+    let from_tests = || {
+        tests
+            .iter()
+            .filter(|test| test.is_any_of(unique_test_names))
+            .filter_map(|test| test.get_tested_columns())
+            .find(|columns| columns.len() > 1)
+    };
+
+    from_meta.or(declared).or_else(from_tests)
+}
+
+/// Requires a uniqueness test on exactly the primary key columns and a `not_null` test on each of them.
+pub fn primary_key_tested<T: HasPrimaryKey>(
+    item: &T,
+    rule: &ManifestRule,
+    manifest: &Manifest,
+    meta_key: Option<&String>,
+    unique_test_names: &[String],
+    not_null_test_names: &[String],
+) -> Option<RuleResult> {
+    let tests = item.get_tests(manifest);
+    let result = |message: String| {
+        Some(RuleResult::new(
+            &rule.severity,
+            TestAble::get_object_type(item),
+            rule.get_name(),
+            message,
+            TestAble::get_relative_path(item).cloned(),
+        ))
+    };
+
+    let Some(primary_key) = primary_key(item, &tests, meta_key, unique_test_names) else {
+        return result(format!(
+            "{} has no primary key, define one with a primary key constraint, unique and not_null tests{}",
+            TestAble::get_object_string(item),
+            meta_key.map_or_else(String::new, |key| format!(" or the '{key}' meta key")),
+        ));
+    };
+
+    let unique_tested = tests.iter().any(|test| {
+        test.is_any_of(unique_test_names)
+            && test
+                .get_tested_columns()
+                .is_some_and(|columns| same_columns(&columns, &primary_key))
+    });
+    let not_null_missing: Vec<&String> = primary_key
+        .iter()
+        .filter(|column| {
+            !tests.iter().any(|test| {
+                test.is_any_of(not_null_test_names)
+                    && test
+                        .get_tested_columns()
+                        .is_some_and(|columns| same_columns(&columns, std::slice::from_ref(column)))
+            })
+        })
+        .collect();
+
+    let mut missing = vec![];
+    if !unique_tested {
+        missing.push("a uniqueness test".to_string());
+    }
+    for column in not_null_missing {
+        missing.push(format!("not_null on {column}"));
+    }
+    if missing.is_empty() {
+        return None;
+    }
+
+    result(format!(
+        "{} primary key ({}) is missing tests: {}",
+        TestAble::get_object_string(item),
+        primary_key.join(", "),
+        missing.join(", ")
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        config::{
+            check_config_options::{default_allowed_test_names, default_not_null_test_names},
+            manifest_rule::ManifestSpecificRuleConfig,
+        },
+        manifest::dbt_objects::{nodes::test::TestMetadata, Meta, Node},
+    };
+    use serde_json::{json, Value};
+
+    struct MockModel {
+        unique_id: String,
+        name: String,
+        primary_key: Option<Vec<String>>,
+        meta: Option<Meta>,
+    }
+
+    impl TestAble for MockModel {
+        fn get_unique_id(&self) -> &String {
+            &self.unique_id
+        }
+        fn get_object_string(&self) -> &String {
+            &self.name
+        }
+        fn get_object_type(&self) -> String {
+            "Model".to_string()
+        }
+        fn get_relative_path(&self) -> Option<&String> {
+            None
+        }
+    }
+
+    impl HasMetadata for MockModel {
+        fn get_metadata(&self) -> Option<&Meta> {
+            self.meta.as_ref()
+        }
+        fn get_object_type(&self) -> &'static str {
+            "Model"
+        }
+        fn get_object_string(&self) -> &str {
+            &self.name
+        }
+        fn get_relative_path(&self) -> Option<&String> {
+            None
+        }
+    }
+
+    impl HasPrimaryKey for MockModel {
+        fn get_primary_key(&self) -> Option<&Vec<String>> {
+            self.primary_key.as_ref()
+        }
+    }
+
+    fn model(primary_key: &[&str], meta: Option<Meta>) -> MockModel {
+        MockModel {
+            unique_id: "model.test.orders".to_string(),
+            name: "orders".to_string(),
+            primary_key: Some(primary_key.iter().map(ToString::to_string).collect()),
+            meta,
+        }
+    }
+
+    fn test(name: &str, namespace: Option<&str>, column: Option<&str>, kwargs: Value) -> Node {
+        let mut test = Test::default();
+        test.base.unique_id = format!("test.test.{name}.{}", column.unwrap_or("model"));
+        test.attached_node = Some("model.test.orders".to_string());
+        test.column_name = column.map(ToString::to_string);
+        test.test_metadata = Some(TestMetadata {
+            name: name.to_string(),
+            kwargs: Some(kwargs),
+            namespace: namespace.map(ToString::to_string),
+        });
+        Node::Test(test)
+    }
+
+    fn manifest(tests: Vec<Node>) -> Manifest {
+        let mut manifest = Manifest::default();
+        for test in tests {
+            manifest.nodes.insert(test.get_unique_id().clone(), test);
+        }
+        manifest
+    }
+
+    fn check(item: &MockModel, manifest: &Manifest, meta_key: Option<&String>) -> Option<String> {
+        let rule = ManifestRule::for_test(ManifestSpecificRuleConfig::PrimaryKeyTested {
+            meta_key: None,
+            unique_test_names: default_allowed_test_names(),
+            not_null_test_names: default_not_null_test_names(),
+        });
+        primary_key_tested(
+            item,
+            &rule,
+            manifest,
+            meta_key,
+            &default_allowed_test_names(),
+            &default_not_null_test_names(),
+        )
+        .map(|result| result.message)
+    }
+
+    #[test]
+    fn test_single_column_primary_key() {
+        let tested = manifest(vec![
+            test(
+                "unique",
+                None,
+                Some("order_id"),
+                json!({"column_name": "order_id"}),
+            ),
+            test(
+                "not_null",
+                None,
+                Some("order_id"),
+                json!({"column_name": "order_id"}),
+            ),
+        ]);
+        assert!(check(&model(&["order_id"], None), &tested, None).is_none());
+
+        // A unique test on another column does not count
+        let untested = manifest(vec![test("unique", None, Some("id"), json!({}))]);
+        assert_eq!(
+            check(&model(&["order_id"], None), &untested, None).unwrap(),
+            "orders primary key (order_id) is missing tests: a uniqueness test, not_null on order_id"
+        );
+    }
+
+    #[test]
+    fn test_primary_key_from_unique_combination_of_columns() {
+        let tests = manifest(vec![
+            test(
+                "unique_combination_of_columns",
+                Some("dbt_utils"),
+                None,
+                json!({"combination_of_columns": ["order_id", "line_number"]}),
+            ),
+            test("not_null", None, Some("order_id"), json!({})),
+        ]);
+        assert_eq!(
+            check(&model(&[], None), &tests, None).unwrap(),
+            "orders primary key (order_id, line_number) is missing tests: not_null on line_number"
+        );
+    }
+
+    #[test]
+    fn test_primary_key_from_meta() {
+        let meta = Meta(json!({"primary_key": ["order_id", "line_number"]}));
+        let tests = manifest(vec![test("unique", None, Some("order_id"), json!({}))]);
+        let key = "primary_key".to_string();
+        // The unique test only covers part of the key
+        assert_eq!(
+            check(&model(&["order_id"], Some(meta)), &tests, Some(&key)).unwrap(),
+            "orders primary key (order_id, line_number) is missing tests: a uniqueness test, not_null on order_id, not_null on line_number"
+        );
+
+        assert_eq!(
+            check(&model(&[], None), &manifest(vec![]), Some(&key)).unwrap(),
+            "orders has no primary key, define one with a primary key constraint, unique and not_null tests or the 'primary_key' meta key"
+        );
+    }
+}
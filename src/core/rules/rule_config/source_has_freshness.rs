@@ -0,0 +1,145 @@
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::manifest_rule::ManifestRule,
+        manifest::dbt_objects::{freshness::FreshnessThreshold, Source},
+    },
+};
+
+// A threshold longer than the allowed maximum, e.g. "error_after of 365 day(s) exceeds the maximum of 7 day(s)"
+fn exceeds(
+    name: &str,
+    threshold: Option<&FreshnessThreshold>,
+    max: Option<&FreshnessThreshold>,
+) -> Option<String> {
+    let (threshold, max) = (threshold?, max?);
+    (threshold.minutes()? > max.minutes()?)
+        .then(|| format!("{name} of {threshold} exceeds the maximum of {max}"))
+}
+
+/// Requires a `loaded_at_field` (or `loaded_at_query`) and a `warn_after` or `error_after` threshold,
+/// optionally no longer than `max_warn_after` and `max_error_after`.
+pub fn source_has_freshness(
+    source: &Source,
+    rule: &ManifestRule,
+    max_warn_after: Option<&FreshnessThreshold>,
+    max_error_after: Option<&FreshnessThreshold>,
+) -> Option<RuleResult> {
+    let mut issues = vec![];
+    if source.get_loaded_at().is_none() {
+        issues.push("no loaded_at_field".to_string());
+    }
+
+    let freshness = source.get_freshness();
+    let warn_after = freshness.and_then(|freshness| freshness.warn_after());
+    let error_after = freshness.and_then(|freshness| freshness.error_after());
+    if warn_after.is_none() && error_after.is_none() {
+        issues.push("no warn_after or error_after threshold".to_string());
+    }
+    issues.extend(exceeds("warn_after", warn_after, max_warn_after));
+    issues.extend(exceeds("error_after", error_after, max_error_after));
+
+    if issues.is_empty() {
+        return None;
+    }
+    Some(RuleResult::new(
+        &rule.severity,
+        Source::get_object_type(),
+        rule.get_name(),
+        format!("{} freshness: {}", source.get_name(), issues.join("; ")),
+        Some(source.get_relative_path().clone()),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::manifest_rule::ManifestSpecificRuleConfig;
+    use serde_json::{json, Value};
+
+    fn source(properties: &Value) -> Source {
+        let mut source = json!({
+            "database": "db",
+            "name": "orders",
+            "package_name": "test",
+            "original_file_path": "models/sources.yml",
+            "unique_id": "source.test.raw.orders",
+            "source_name": "raw"
+        });
+        source
+            .as_object_mut()
+            .unwrap()
+            .extend(properties.as_object().unwrap().clone());
+        serde_json::from_value(source).unwrap()
+    }
+
+    fn threshold(count: u64, period: &str) -> FreshnessThreshold {
+        serde_json::from_value(json!({"count": count, "period": period})).unwrap()
+    }
+
+    fn check(source: &Source, max_error_after: Option<&FreshnessThreshold>) -> Option<String> {
+        let rule = ManifestRule::for_test(ManifestSpecificRuleConfig::SourceHasFreshness {
+            max_warn_after: None,
+            max_error_after: None,
+        });
+        source_has_freshness(source, &rule, None, max_error_after).map(|result| result.message)
+    }
+
+    #[test]
+    fn test_missing_freshness() {
+        // dbt writes empty thresholds when freshness is not configured
+        let unset = source(&json!({
+            "loaded_at_field": null,
+            "freshness": {
+                "warn_after": {"count": null, "period": null},
+                "error_after": {"count": null, "period": null},
+                "filter": null
+            }
+        }));
+        assert_eq!(
+            check(&unset, None).unwrap(),
+            "orders freshness: no loaded_at_field; no warn_after or error_after threshold"
+        );
+    }
+
+    #[test]
+    fn test_freshness_from_config() {
+        let configured = source(&json!({
+            "config": {
+                "loaded_at_field": "_loaded_at",
+                "freshness": {"error_after": {"count": 2, "period": "day"}}
+            }
+        }));
+        assert!(check(&configured, None).is_none());
+        assert!(check(&configured, Some(&threshold(48, "hour"))).is_none());
+        assert_eq!(
+            check(&configured, Some(&threshold(1, "day"))).unwrap(),
+            "orders freshness: error_after of 2 day(s) exceeds the maximum of 1 day(s)"
+        );
+    }
+
+    #[test]
+    fn test_empty_top_level_freshness_falls_back_to_config() {
+        let configured = source(&json!({
+            "loaded_at_field": "_loaded_at",
+            "freshness": {
+                "warn_after": {"count": null, "period": null},
+                "error_after": {"count": null, "period": null},
+                "filter": null
+            },
+            "config": {
+                "freshness": {"warn_after": {"count": 12, "period": "hour"}}
+            }
+        }));
+        assert_eq!(
+            configured
+                .get_freshness()
+                .unwrap()
+                .warn_after()
+                .unwrap()
+                .minutes(),
+            Some(12 * 60)
+        );
+        assert!(check(&configured, None).is_none());
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::{includes_excludes::PathPatterns, manifest_rule::ManifestRule},
+        manifest::Manifest,
+    },
+};
+
+use super::{child_map::ChildMappable, has_refs::CanReference};
+
+/// Sources should be selected by a single (staging) model, which all other models build upon.
+pub fn source_fanout<T: ChildMappable>(
+    source: &T,
+    rule: &ManifestRule,
+    max_references: usize,
+    manifest: &Manifest,
+) -> Option<RuleResult> {
+    let mut models: Vec<&str> = source
+        .get_childs(manifest)
+        .into_iter()
+        .filter_map(|unique_id| manifest.nodes.get(unique_id))
+        .filter(|node| node.get_unique_id().starts_with("model."))
+        .map(|node| node.get_name().as_str())
+        .collect();
+
+    if models.len() <= max_references {
+        return None;
+    }
+
+    models.sort_unstable();
+    Some(RuleResult::new(
+        &rule.severity,
+        source.get_object_type(),
+        rule.get_name(),
+        format!(
+            "{} is selected by {} models ({}), the maximum is {max_references}",
+            source.get_object_string(),
+            models.len(),
+            models.join(", ")
+        ),
+        source.get_relative_path().cloned(),
+    ))
+}
+
+/// Only models matching the allowed paths or name prefixes may `source()` a table directly.
+/// Without either, models prefixed with `stg_` are allowed.
+pub fn direct_source_reference<T: CanReference>(
+    item: &T,
+    rule: &ManifestRule,
+    allowed_paths: Option<&PathPatterns>,
+    allowed_name_prefixes: Option<&Vec<String>>,
+    manifest: &Manifest,
+) -> Option<RuleResult> {
+    let name = item.get_object_string();
+    let allowed = match (allowed_paths, allowed_name_prefixes) {
+        (None, None) => name.starts_with("stg_"),
+        (paths, prefixes) => {
+            paths.is_some_and(|paths| {
+                item.get_relative_path()
+                    .is_some_and(|path| paths.is_match(path))
+            }) || prefixes
+                .into_iter()
+                .flatten()
+                .any(|prefix| name.starts_with(prefix.as_str()))
+        }
+    };
+    if allowed {
+        return None;
+    }
+
+    let sources: Vec<String> = item
+        .get_depends_on_nodes()
+        .iter()
+        .filter_map(|unique_id| manifest.sources.get(unique_id))
+        .map(|source| format!("{}.{}", source.get_source_name(), source.get_name()))
+        .collect();
+    if sources.is_empty() {
+        return None;
+    }
+
+    Some(RuleResult::new(
+        &rule.severity,
+        item.get_object_type(),
+        rule.get_name(),
+        format!(
+            "{name} selects directly from source(s) {}, use a staging model instead",
+            sources.join(", ")
+        ),
+        item.get_relative_path().cloned(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::manifest_rule::ManifestSpecificRuleConfig;
+
+    struct TestModel {
+        name: String,
+        path: String,
+        depends_on: Vec<String>,
+    }
+
+    impl CanReference for TestModel {
+        fn get_depends_on_nodes(&self) -> &[String] {
+            &self.depends_on
+        }
+
+        fn get_object_type(&self) -> &'static str {
+            "Model"
+        }
+
+        fn get_object_string(&self) -> &str {
+            &self.name
+        }
+
+        fn get_relative_path(&self) -> Option<&String> {
+            Some(&self.path)
+        }
+    }
+
+    fn model(name: &str, path: &str) -> TestModel {
+        TestModel {
+            name: name.to_string(),
+            path: path.to_string(),
+            // Sources missing from the manifest are ignored
+            depends_on: vec!["source.test.raw.orders".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_allowed_models_pass() {
+        let rule = ManifestRule::for_test(ManifestSpecificRuleConfig::DirectSourceReference {
+            allowed_paths: None,
+            allowed_name_prefixes: None,
+        });
+        let manifest = Manifest::default();
+        let staging = model("stg_orders", "models/staging/stg_orders.sql");
+        assert!(direct_source_reference(&staging, &rule, None, None, &manifest).is_none());
+
+        let legacy = model("orders", "models/legacy/orders.sql");
+        let paths: PathPatterns = vec!["models/legacy/".to_string()].into();
+        assert!(direct_source_reference(&legacy, &rule, Some(&paths), None, &manifest).is_none());
+        let prefixes = vec!["ord".to_string()];
+        assert!(
+            direct_source_reference(&legacy, &rule, None, Some(&prefixes), &manifest).is_none()
+        );
+    }
+
+    #[test]
+    fn test_models_without_sources_pass() {
+        let rule = ManifestRule::for_test(ManifestSpecificRuleConfig::DirectSourceReference {
+            allowed_paths: None,
+            allowed_name_prefixes: None,
+        });
+        let item = TestModel {
+            depends_on: vec![],
+            ..model("orders", "models/marts/orders.sql")
+        };
+        assert!(direct_source_reference(&item, &rule, None, None, &Manifest::default()).is_none());
+    }
+}
@@ -0,0 +1,199 @@
+use std::collections::HashSet;
+
+// Lightweight tokenizer for the raw SQL of models, snapshots and macros.
+// Not a SQL parser: it only knows enough to find keywords, relations and Jinja calls.
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TokenKind {
+    /// Identifiers and keywords, including dots and quotes (`analytics."prod".orders`)
+    Word(String),
+    /// Content of a Jinja expression (`{{ ... }}`) without the braces
+    Jinja(String),
+    Symbol(char),
+}
+
+#[derive(Debug)]
+pub struct Token {
+    pub kind: TokenKind,
+    /// 1-based line and column in the raw code
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Token {
+    pub fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(&self.kind, TokenKind::Word(word) if word.eq_ignore_ascii_case(keyword))
+    }
+
+    pub fn is_symbol(&self, symbol: char) -> bool {
+        self.kind == TokenKind::Symbol(symbol)
+    }
+
+    pub const fn is_jinja(&self) -> bool {
+        matches!(self.kind, TokenKind::Jinja(_))
+    }
+
+    /// `{{ ref(...) }}` or `{{ source(...) }}`, returned without the braces
+    pub fn relation_call(&self) -> Option<&str> {
+        let TokenKind::Jinja(content) = &self.kind else {
+            return None;
+        };
+        let content = content.trim_matches(|c: char| c == '-' || c.is_whitespace());
+        let function = content.split('(').next()?.trim();
+        matches!(function, "ref" | "source").then_some(content)
+    }
+}
+
+/// Tokens of the code, with comments, string literals and Jinja statements (`{% ... %}`) left out.
+#[derive(Debug, Default)]
+pub struct SqlCode {
+    pub tokens: Vec<Token>,
+    /// Lines with (part of) a SQL or Jinja comment
+    pub comment_lines: HashSet<usize>,
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '$' | '.' | '"' | '`' | '[' | ']')
+}
+
+struct Scanner {
+    chars: Vec<char>,
+    index: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Scanner {
+    fn starts_with(&self, pattern: &str) -> bool {
+        pattern
+            .chars()
+            .enumerate()
+            .all(|(offset, c)| self.chars.get(self.index + offset) == Some(&c))
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let c = *self.chars.get(self.index)?;
+        self.index += 1;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    /// Consumes everything up to and including `end`, returns what was in between
+    fn consume_until(&mut self, end: &str) -> String {
+        let mut content = String::new();
+        while self.index < self.chars.len() && !self.starts_with(end) {
+            content.extend(self.advance());
+        }
+        for _ in end.chars() {
+            self.advance();
+        }
+        content
+    }
+}
+
+impl SqlCode {
+    pub fn parse(code: &str) -> Self {
+        let mut scanner = Scanner {
+            chars: code.chars().collect(),
+            index: 0,
+            line: 1,
+            column: 1,
+        };
+        let mut sql = Self::default();
+
+        while let Some(&c) = scanner.chars.get(scanner.index) {
+            let (line, column) = (scanner.line, scanner.column);
+            if scanner.starts_with("{{") {
+                scanner.consume_until("{{");
+                let content = scanner.consume_until("}}");
+                sql.tokens.push(Token {
+                    kind: TokenKind::Jinja(content),
+                    line,
+                    column,
+                });
+            } else if scanner.starts_with("{%") {
+                scanner.consume_until("%}");
+            } else if scanner.starts_with("{#") || scanner.starts_with("/*") {
+                let end = if c == '{' { "#}" } else { "*/" };
+                scanner.consume_until(end);
+                sql.comment_lines.extend(line..=scanner.line);
+            } else if scanner.starts_with("--") {
+                scanner.consume_until("\n");
+                sql.comment_lines.insert(line);
+            } else if c == '\'' {
+                scanner.advance();
+                // '' escapes a quote inside a string
+                while let Some(c) = scanner.advance() {
+                    if c == '\'' && !scanner.starts_with("'") {
+                        break;
+                    }
+                    if c == '\'' {
+                        scanner.advance();
+                    }
+                }
+            } else if is_identifier_char(c) {
+                let mut word = String::new();
+                while scanner
+                    .chars
+                    .get(scanner.index)
+                    .is_some_and(|c| is_identifier_char(*c))
+                {
+                    word.extend(scanner.advance());
+                }
+                sql.tokens.push(Token {
+                    kind: TokenKind::Word(word),
+                    line,
+                    column,
+                });
+            } else {
+                scanner.advance();
+                if !c.is_whitespace() {
+                    sql.tokens.push(Token {
+                        kind: TokenKind::Symbol(c),
+                        line,
+                        column,
+                    });
+                }
+            }
+        }
+        sql
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let code = "{{ config(materialized='table') }}\n-- orders\nselect o.id, 'it''s' as note /* a\nb */\nfrom {{ ref('orders') }} o {% if true %}where 1{% endif %}";
+        let sql = SqlCode::parse(code);
+        let words: Vec<&str> = sql
+            .tokens
+            .iter()
+            .filter_map(|token| match &token.kind {
+                TokenKind::Word(word) => Some(word.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            words,
+            vec!["select", "o.id", "as", "note", "from", "o", "where", "1"]
+        );
+        assert_eq!(sql.comment_lines, HashSet::from([2, 3, 4]));
+
+        let from = sql
+            .tokens
+            .iter()
+            .find(|token| token.is_keyword("FROM"))
+            .unwrap();
+        assert_eq!((from.line, from.column), (5, 1));
+        let relations: Vec<&str> = sql.tokens.iter().filter_map(Token::relation_call).collect();
+        assert_eq!(relations, vec!["ref('orders')"]);
+    }
+}
@@ -0,0 +1,408 @@
+use crate::{
+    cli::table::RuleResult,
+    core::{config::manifest_rule::ManifestRule, manifest::Manifest},
+};
+
+use super::{
+    max_code_lines::HasCode,
+    sql::{SqlCode, Token, TokenKind},
+};
+
+fn format_lines(lines: &[usize]) -> String {
+    let lines: Vec<String> = lines.iter().map(ToString::to_string).collect();
+    match lines.as_slice() {
+        [line] => format!("line {line}"),
+        lines => format!("lines {}", lines.join(", ")),
+    }
+}
+
+fn finding<T: HasCode>(
+    item: &T,
+    rule: &ManifestRule,
+    message: String,
+    first: &Token,
+) -> RuleResult {
+    RuleResult::new(
+        &rule.severity,
+        item.get_object_type(),
+        rule.get_name(),
+        message,
+        item.get_relative_path().cloned(),
+    )
+    .with_location(first.line, Some(first.column))
+}
+
+fn starts_select_list(token: Option<&Token>) -> bool {
+    token.is_some_and(|token| {
+        token.is_keyword("select") || token.is_keyword("distinct") || token.is_symbol(',')
+    })
+}
+
+/// `select *` and `select orders.*`, except for import CTEs (`select * from {{ ref('orders') }}`)
+/// and a final `select * from final` at the end of the model.
+pub fn no_select_star<T: HasCode>(item: &T, rule: &ManifestRule) -> Option<RuleResult> {
+    let tokens = SqlCode::parse(item.get_code()?).tokens;
+    let stars: Vec<&Token> = tokens
+        .iter()
+        .enumerate()
+        .filter(|(index, token)| {
+            if !token.is_symbol('*') {
+                return false;
+            }
+            let before = |offset: usize| index.checked_sub(offset).and_then(|i| tokens.get(i));
+            let qualified = matches!(before(1), Some(Token { kind: TokenKind::Word(word), .. }) if word.ends_with('.'));
+            let in_select_list = if qualified {
+                starts_select_list(before(2))
+            } else {
+                starts_select_list(before(1))
+            };
+            if !in_select_list || !tokens.get(index + 1).is_some_and(|t| t.is_keyword("from")) {
+                return in_select_list;
+            }
+
+            let relation = tokens.get(index + 2);
+            let rest = &tokens[(index + 3).min(tokens.len())..];
+            let import_cte = relation.is_some_and(|t| t.relation_call().is_some())
+                && rest.first().is_none_or(|t| t.is_symbol(')'));
+            let final_select = matches!(relation, Some(Token { kind: TokenKind::Word(_), .. }))
+                && rest.iter().all(|t| t.is_symbol(';'));
+            !import_cte && !final_select
+        })
+        .map(|(_, token)| token)
+        .collect();
+
+    let first = stars.first()?;
+    let lines: Vec<usize> = stars.iter().map(|token| token.line).collect();
+    Some(finding(
+        item,
+        rule,
+        format!(
+            "{} uses select * on {}, select the columns explicitly",
+            item.get_name(),
+            format_lines(&lines)
+        ),
+        first,
+    ))
+}
+
+/// ORDER BY outside of window functions and aggregates, in models that are used by other models.
+pub fn no_order_by<T: HasCode>(
+    item: &T,
+    rule: &ManifestRule,
+    manifest: &Manifest,
+) -> Option<RuleResult> {
+    // Ordering the final model of the DAG is fine, it is the one being queried
+    let children = manifest.child_map.get(item.get_unique_id()?)?;
+    if !children
+        .iter()
+        .any(|child| child.starts_with("model.") || child.starts_with("snapshot."))
+    {
+        return None;
+    }
+
+    let tokens = SqlCode::parse(item.get_code()?).tokens;
+    // Whether each open parenthesis contains a query, as opposed to `over (order by ...)`
+    let mut parentheses: Vec<bool> = vec![];
+    let mut order_bys = vec![];
+    for (index, token) in tokens.iter().enumerate() {
+        if token.is_symbol('(') {
+            parentheses.push(
+                tokens
+                    .get(index + 1)
+                    .is_some_and(|next| next.is_keyword("select") || next.is_keyword("with")),
+            );
+        } else if token.is_symbol(')') {
+            parentheses.pop();
+        } else if token.is_keyword("order")
+            && tokens
+                .get(index + 1)
+                .is_some_and(|next| next.is_keyword("by"))
+            && parentheses.last().is_none_or(|query| *query)
+        {
+            order_bys.push(token);
+        }
+    }
+
+    let first = order_bys.first()?;
+    let lines: Vec<usize> = order_bys.iter().map(|token| token.line).collect();
+    Some(finding(
+        item,
+        rule,
+        format!(
+            "{} is used by other models but has an order by on {}, only order the final model",
+            item.get_name(),
+            format_lines(&lines)
+        ),
+        first,
+    ))
+}
+
+/// `select distinct` without a comment on the same line or the line above explaining why.
+pub fn no_distinct_without_comment<T: HasCode>(
+    item: &T,
+    rule: &ManifestRule,
+) -> Option<RuleResult> {
+    let sql = SqlCode::parse(item.get_code()?);
+    let distincts: Vec<&Token> = sql
+        .tokens
+        .iter()
+        .enumerate()
+        .filter(|(index, token)| {
+            token.is_keyword("distinct")
+                && index
+                    .checked_sub(1)
+                    .is_some_and(|i| sql.tokens[i].is_keyword("select"))
+                && !sql.comment_lines.contains(&token.line)
+                && !sql.comment_lines.contains(&(token.line - 1))
+        })
+        .map(|(_, token)| token)
+        .collect();
+
+    let first = distincts.first()?;
+    let lines: Vec<usize> = distincts.iter().map(|token| token.line).collect();
+    Some(finding(
+        item,
+        rule,
+        format!(
+            "{} uses select distinct without a comment explaining why on {}",
+            item.get_name(),
+            format_lines(&lines)
+        ),
+        first,
+    ))
+}
+
+/// Keywords and function names that may not be used, e.g. `nvl` or `cross join` (case insensitive).
+pub fn forbidden_keywords<T: HasCode>(
+    item: &T,
+    rule: &ManifestRule,
+    keywords: &[String],
+) -> Option<RuleResult> {
+    let tokens = SqlCode::parse(item.get_code()?).tokens;
+    let mut found: Vec<(&Token, &str)> = vec![];
+    for keyword in keywords {
+        let words: Vec<&str> = keyword.split_whitespace().collect();
+        if words.is_empty() {
+            continue;
+        }
+        for (index, token) in tokens.iter().enumerate() {
+            let matches = words.iter().enumerate().all(|(offset, word)| {
+                tokens
+                    .get(index + offset)
+                    .is_some_and(|token| token.is_keyword(word))
+            });
+            if matches {
+                found.push((token, keyword));
+            }
+        }
+    }
+    found.sort_by_key(|(token, _)| (token.line, token.column));
+
+    let (first, _) = found.first()?;
+    Some(finding(
+        item,
+        rule,
+        format!(
+            "{} uses forbidden keywords: {}",
+            item.get_name(),
+            found
+                .iter()
+                .map(|(token, keyword)| format!("{keyword} (line {})", token.line))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        first,
+    ))
+}
+
+struct Cte<'a> {
+    name: &'a Token,
+    relations: usize,
+    joins: bool,
+}
+
+/// All `ref()` and `source()` calls are in import CTEs at the top of the model,
+/// each selecting from a single relation: `orders as (select * from {{ ref('stg_orders') }})`.
+pub fn import_ctes<T: HasCode>(item: &T, rule: &ManifestRule) -> Option<RuleResult> {
+    let tokens = SqlCode::parse(item.get_code()?).tokens;
+    let mut issues: Vec<(String, &Token)> = vec![];
+    let mut depth = 0usize;
+    let mut cte: Option<Cte> = None;
+    let mut seen_logical_cte = false;
+
+    for (index, token) in tokens.iter().enumerate() {
+        let before = |offset: usize| index.checked_sub(offset).and_then(|i| tokens.get(i));
+        if token.is_symbol('(') {
+            if depth == 0 && before(1).is_some_and(|t| t.is_keyword("as")) {
+                if let Some(
+                    name @ Token {
+                        kind: TokenKind::Word(_),
+                        ..
+                    },
+                ) = before(2)
+                {
+                    cte = Some(Cte {
+                        name,
+                        relations: 0,
+                        joins: false,
+                    });
+                }
+            }
+            depth += 1;
+        } else if token.is_symbol(')') {
+            depth = depth.saturating_sub(1);
+            if depth > 0 {
+                continue;
+            }
+            let Some(finished) = cte.take() else {
+                continue;
+            };
+            let TokenKind::Word(name) = &finished.name.kind else {
+                continue;
+            };
+            if finished.relations == 0 {
+                seen_logical_cte = true;
+                continue;
+            }
+            if seen_logical_cte {
+                issues.push((
+                    format!("import CTE '{name}' should be defined before the other CTEs"),
+                    finished.name,
+                ));
+            }
+            if finished.relations > 1 || finished.joins {
+                issues.push((
+                    format!("import CTE '{name}' should select from a single relation"),
+                    finished.name,
+                ));
+            }
+        } else if let Some(call) = token.relation_call() {
+            match cte.as_mut() {
+                Some(cte) if depth > 0 => cte.relations += 1,
+                _ => issues.push((format!("{call} is used outside of an import CTE"), token)),
+            }
+        } else if token.is_keyword("join") {
+            if let Some(cte) = cte.as_mut() {
+                cte.joins = true;
+            }
+        }
+    }
+
+    let (_, first) = issues.first()?;
+    Some(finding(
+        item,
+        rule,
+        format!(
+            "{} does not follow the import CTE structure: {}",
+            item.get_name(),
+            issues
+                .iter()
+                .map(|(issue, token)| format!("{issue} (line {})", token.line))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        first,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::manifest_rule::ManifestSpecificRuleConfig;
+
+    struct TestModel(&'static str);
+
+    impl HasCode for TestModel {
+        fn get_code(&self) -> Option<&str> {
+            Some(self.0)
+        }
+        fn get_name(&self) -> &'static str {
+            "orders"
+        }
+        fn get_relative_path(&self) -> Option<&String> {
+            None
+        }
+        fn get_object_type(&self) -> &'static str {
+            "Model"
+        }
+        fn get_unique_id(&self) -> Option<&str> {
+            Some("model.test.orders")
+        }
+    }
+
+    fn message(result: Option<RuleResult>) -> String {
+        result.expect("expected a finding").message
+    }
+
+    #[test]
+    fn test_no_select_star() {
+        let rule = ManifestRule::for_test(ManifestSpecificRuleConfig::NoSelectStar {});
+        let code = "with orders as (\n    select * from {{ ref('stg_orders') }}\n),\n\nfinal as (\n    select o.*, count(*) as n from orders o\n)\n\nselect * from final";
+        assert_eq!(
+            message(no_select_star(&TestModel(code), &rule)),
+            "orders uses select * on line 6, select the columns explicitly"
+        );
+        assert!(no_select_star(&TestModel("select a * b as c from x"), &rule).is_none());
+        assert!(no_select_star(&TestModel("select * from x where true"), &rule).is_some());
+    }
+
+    #[test]
+    fn test_no_order_by() {
+        let rule = ManifestRule::for_test(ManifestSpecificRuleConfig::NoOrderBy {});
+        let code = "select id, row_number() over (partition by a order by b) as n\nfrom {{ ref('stg_orders') }}\norder by id";
+        let mut manifest = Manifest::default();
+        // Final models may be ordered
+        assert!(no_order_by(&TestModel(code), &rule, &manifest).is_none());
+
+        manifest.child_map.insert(
+            "model.test.orders".to_string(),
+            vec!["model.test.orders_report".to_string()],
+        );
+        assert_eq!(
+            message(no_order_by(&TestModel(code), &rule, &manifest)),
+            "orders is used by other models but has an order by on line 3, only order the final model"
+        );
+        let subquery = "select * from (select * from x order by id) as sub";
+        assert!(no_order_by(&TestModel(subquery), &rule, &manifest).is_some());
+    }
+
+    #[test]
+    fn test_no_distinct_without_comment() {
+        let rule = ManifestRule::for_test(ManifestSpecificRuleConfig::NoDistinctWithoutComment {});
+        let code = "-- events are sent twice by the tracker\nselect distinct id from a\nunion all\nselect distinct id from b -- also duplicated\nunion all\nselect distinct id from c\nwhere a is distinct from b";
+        assert_eq!(
+            message(no_distinct_without_comment(&TestModel(code), &rule)),
+            "orders uses select distinct without a comment explaining why on line 6"
+        );
+    }
+
+    #[test]
+    fn test_forbidden_keywords() {
+        let code = "select NVL(a, 0), getdate() as now\nfrom x\ncross  join y";
+        let keywords = vec![
+            "getdate".to_string(),
+            "nvl".to_string(),
+            "cross join".to_string(),
+        ];
+        let rule = ManifestRule::for_test(ManifestSpecificRuleConfig::ForbiddenKeywords {
+            keywords: keywords.clone(),
+        });
+        assert_eq!(
+            message(forbidden_keywords(&TestModel(code), &rule, &keywords)),
+            "orders uses forbidden keywords: nvl (line 1), getdate (line 1), cross join (line 3)"
+        );
+    }
+
+    #[test]
+    fn test_import_ctes() {
+        let rule = ManifestRule::for_test(ManifestSpecificRuleConfig::ImportCtes {});
+        let good = "with orders as (\n    select * from {{ ref('stg_orders') }}\n),\ncustomers as (\n    select * from {{ source('raw', 'customers') }} where not deleted\n),\njoined as (\n    select * from orders join customers using (customer_id)\n)\nselect * from joined";
+        assert!(import_ctes(&TestModel(good), &rule).is_none());
+
+        let bad = "with joined as (\n    select * from {{ ref('stg_orders') }} o\n    join {{ ref('stg_customers') }} c using (customer_id)\n),\nlogic as (select 1 as a),\npayments as (select * from {{ ref('stg_payments') }})\nselect * from joined\nleft join {{ ref('stg_refunds') }} using (order_id)";
+        assert_eq!(
+            message(import_ctes(&TestModel(bad), &rule)),
+            "orders does not follow the import CTE structure: import CTE 'joined' should select from a single relation (line 1), import CTE 'payments' should be defined before the other CTEs (line 6), ref('stg_refunds') is used outside of an import CTE (line 8)"
+        );
+    }
+}
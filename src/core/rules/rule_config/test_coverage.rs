@@ -0,0 +1,271 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::{check_config_options::RequiredColumnTests, manifest_rule::ManifestRule},
+        manifest::{dbt_objects::nodes::test::normalize_column_name, Manifest},
+        rules::common_traits::Columnable,
+    },
+};
+
+use super::has_unique_test::TestAble;
+
+/// Number of documented columns of an object and how many of them have at least one test
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnCoverage {
+    pub tested: usize,
+    pub documented: usize,
+}
+
+/// Coverage of all objects evaluated by `test_coverage`, by unique id
+/// so objects checked by multiple rules are only counted once
+pub fn project_coverage(coverage: &BTreeMap<String, ColumnCoverage>) -> ColumnCoverage {
+    coverage
+        .values()
+        .fold(ColumnCoverage::default(), |total, object| ColumnCoverage {
+            tested: total.tested + object.tested,
+            documented: total.documented + object.documented,
+        })
+}
+
+/// Checks the share of documented columns with at least one test against `min_coverage` (a percentage).
+///
+/// Columns matching the `required_tests` patterns need those tests as well.
+/// Also returns the coverage of the object for the project wide summary.
+pub fn test_coverage<T: TestAble + Columnable>(
+    item: &T,
+    rule: &ManifestRule,
+    manifest: &Manifest,
+    min_coverage: usize,
+    required_tests: &[RequiredColumnTests],
+) -> (ColumnCoverage, Option<RuleResult>) {
+    let mut columns: Vec<&String> = item.get_column_names().unwrap_or_default();
+    columns.sort();
+    let tests = item.get_tests(manifest);
+    let tested_columns: HashSet<String> = tests
+        .iter()
+        .filter_map(|test| test.get_tested_columns())
+        .flatten()
+        .map(|column| normalize_column_name(&column))
+        .collect();
+    let is_tested = |column: &str| tested_columns.contains(&normalize_column_name(column));
+
+    let untested: Vec<&String> = columns
+        .iter()
+        .copied()
+        .filter(|column| !is_tested(column))
+        .collect();
+    let coverage = ColumnCoverage {
+        tested: columns.len() - untested.len(),
+        documented: columns.len(),
+    };
+
+    let mut issues = vec![];
+    if coverage.tested * 100 < min_coverage * coverage.documented {
+        issues.push(format!(
+            "{} of {} documented columns are tested ({}%, the minimum is {min_coverage}%), untested: {}",
+            coverage.tested,
+            coverage.documented,
+            coverage.tested * 100 / coverage.documented,
+            untested
+                .iter()
+                .map(|column| column.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    for column in &columns {
+        let missing: Vec<&str> = required_tests
+            .iter()
+            .filter(|required| required.columns.is_match(&normalize_column_name(column)))
+            .flat_map(|required| &required.tests)
+            .filter(|name| {
+                !tests.iter().any(|test| {
+                    test.is_any_of(std::slice::from_ref(name))
+                        && test.get_tested_columns().is_some_and(|tested| {
+                            tested.iter().any(|tested| {
+                                normalize_column_name(tested) == normalize_column_name(column)
+                            })
+                        })
+                })
+            })
+            .map(String::as_str)
+            .collect();
+        if !missing.is_empty() {
+            issues.push(format!("{column} is missing {}", missing.join(", ")));
+        }
+    }
+
+    if issues.is_empty() {
+        return (coverage, None);
+    }
+    let result = RuleResult::new(
+        &rule.severity,
+        TestAble::get_object_type(item),
+        rule.get_name(),
+        format!(
+            "{} test coverage: {}",
+            TestAble::get_object_string(item),
+            issues.join("; ")
+        ),
+        TestAble::get_relative_path(item).cloned(),
+    );
+    (coverage, Some(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{
+        config::{includes_excludes::PathPatterns, manifest_rule::ManifestSpecificRuleConfig},
+        manifest::dbt_objects::{
+            nodes::test::{Test, TestMetadata},
+            Node,
+        },
+    };
+    use serde_json::json;
+
+    struct MockModel {
+        unique_id: String,
+        name: String,
+        columns: Vec<String>,
+    }
+
+    impl TestAble for MockModel {
+        fn get_unique_id(&self) -> &String {
+            &self.unique_id
+        }
+        fn get_object_string(&self) -> &String {
+            &self.name
+        }
+        fn get_object_type(&self) -> String {
+            "Model".to_string()
+        }
+        fn get_relative_path(&self) -> Option<&String> {
+            None
+        }
+    }
+
+    impl Columnable for MockModel {
+        fn get_column_names(&self) -> Option<Vec<&String>> {
+            Some(self.columns.iter().collect())
+        }
+        fn get_columns_with_descriptions(&self) -> Option<Vec<(&String, &String)>> {
+            None
+        }
+        fn get_object_type(&self) -> &'static str {
+            "Model"
+        }
+        fn get_object_string(&self) -> &str {
+            &self.name
+        }
+    }
+
+    fn model(columns: &[&str]) -> MockModel {
+        MockModel {
+            unique_id: "model.test.orders".to_string(),
+            name: "orders".to_string(),
+            columns: columns.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    fn manifest(tests: &[(&str, &str)]) -> Manifest {
+        let mut manifest = Manifest::default();
+        for (name, column) in tests {
+            let mut test = Test::default();
+            test.base.name = (*name).to_string();
+            test.base.unique_id = format!("test.test.{name}_orders_{column}");
+            test.attached_node = Some("model.test.orders".to_string());
+            test.test_metadata = Some(TestMetadata {
+                name: (*name).to_string(),
+                kwargs: Some(json!({"column_name": column})),
+                namespace: None,
+            });
+            manifest
+                .nodes
+                .insert(test.base.unique_id.clone(), Node::Test(test));
+        }
+        manifest
+    }
+
+    #[test]
+    fn test_coverage_threshold() {
+        let rule = ManifestRule::for_test(ManifestSpecificRuleConfig::TestCoverage {
+            min_coverage: 100,
+            required_tests: vec![],
+        });
+        let item = model(&["order_id", "customer_id", "amount"]);
+        let manifest = manifest(&[("unique", "order_id"), ("not_null", "ORDER_ID")]);
+
+        let (coverage, result) = test_coverage(&item, &rule, &manifest, 50, &[]);
+        assert_eq!(
+            coverage,
+            ColumnCoverage {
+                tested: 1,
+                documented: 3
+            }
+        );
+        assert_eq!(
+            result.unwrap().message,
+            "orders test coverage: 1 of 3 documented columns are tested (33%, the minimum is 50%), untested: amount, customer_id"
+        );
+        assert!(test_coverage(&item, &rule, &manifest, 30, &[]).1.is_none());
+
+        // Objects without documented columns pass
+        assert!(test_coverage(&model(&[]), &rule, &manifest, 100, &[])
+            .1
+            .is_none());
+    }
+
+    #[test]
+    fn test_required_tests_per_column_pattern() {
+        let rule = ManifestRule::for_test(ManifestSpecificRuleConfig::TestCoverage {
+            min_coverage: 100,
+            required_tests: vec![],
+        });
+        let item = model(&["order_id", "customer_id", "amount"]);
+        let manifest = manifest(&[
+            ("not_null", "order_id"),
+            ("unique", "order_id"),
+            ("accepted_range", "amount"),
+        ]);
+        let required = vec![RequiredColumnTests {
+            columns: PathPatterns::from(vec!["*_id".to_string()]),
+            tests: vec!["not_null".to_string(), "relationships".to_string()],
+        }];
+
+        let (_, result) = test_coverage(&item, &rule, &manifest, 0, &required);
+        assert_eq!(
+            result.unwrap().message,
+            "orders test coverage: customer_id is missing not_null, relationships; order_id is missing relationships"
+        );
+    }
+
+    #[test]
+    fn test_project_coverage() {
+        let coverage = BTreeMap::from([
+            (
+                "model.test.orders".to_string(),
+                ColumnCoverage {
+                    tested: 1,
+                    documented: 3,
+                },
+            ),
+            (
+                "source.test.raw.orders".to_string(),
+                ColumnCoverage {
+                    tested: 2,
+                    documented: 2,
+                },
+            ),
+        ]);
+        assert_eq!(
+            project_coverage(&coverage),
+            ColumnCoverage {
+                tested: 3,
+                documented: 5
+            }
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::cli::table::RuleResult;
+use crate::core::config::severity::Severity;
+use crate::core::manifest::dbt_objects::meta::DisabledRule;
+use crate::core::rules::rule_config::test_coverage::ColumnCoverage;
+use crate::core::rules::suppression::SuppressedFinding;
+
+/// A rule that was evaluated against an object without producing a finding.
+/// Reporters such as `JUnit` need these to show passing checks next to the failing ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RulePass {
+    pub rule_name: String,
+    pub object_type: String,
+    pub object_name: String,
+    pub unique_id: String,
+    pub relative_path: Option<String>,
+}
+
+impl RulePass {
+    pub fn new(
+        rule_name: impl Into<String>,
+        object_type: impl Into<String>,
+        object_name: impl Into<String>,
+        unique_id: impl Into<String>,
+        relative_path: Option<String>,
+    ) -> Self {
+        Self {
+            rule_name: rule_name.into(),
+            object_type: object_type.into(),
+            object_name: object_name.into(),
+            unique_id: unique_id.into(),
+            relative_path,
+        }
+    }
+}
+
+/// Everything the rule appliers produce:
+/// the findings (failed evaluations) and the evaluations that passed.
+#[derive(Debug, Default)]
+pub struct RuleOutcomes<'a> {
+    pub findings: Vec<(RuleResult, &'a Severity)>,
+    pub passes: Vec<RulePass>,
+    /// Findings on objects that disabled the rule through their `meta`
+    pub suppressed: Vec<SuppressedFinding>,
+    /// Column test coverage of the objects evaluated by `test_coverage`, by unique id
+    pub column_coverage: BTreeMap<String, ColumnCoverage>,
+}
+
+impl<'a> RuleOutcomes<'a> {
+    pub fn extend(&mut self, other: Self) {
+        self.findings.extend(other.findings);
+        self.passes.extend(other.passes);
+        self.suppressed.extend(other.suppressed);
+        self.column_coverage.extend(other.column_coverage);
+    }
+
+    /// Record a finding, unless the object disabled the rule
+    pub fn push_finding(
+        &mut self,
+        result: RuleResult,
+        severity: &'a Severity,
+        disabled: Option<DisabledRule>,
+    ) {
+        match disabled {
+            Some(disabled) => self.suppressed.push(SuppressedFinding {
+                result,
+                reason: disabled.reason,
+            }),
+            None => self.findings.push((result, severity)),
+        }
+    }
+
+    /// Unique ids of all objects a rule was evaluated against
+    pub fn evaluated_unique_ids(&self) -> HashSet<String> {
+        self.findings
+            .iter()
+            .map(|(result, _)| &result.unique_id)
+            .chain(
+                self.suppressed
+                    .iter()
+                    .map(|finding| &finding.result.unique_id),
+            )
+            .flatten()
+            .chain(self.passes.iter().map(|pass| &pass.unique_id))
+            .cloned()
+            .collect()
+    }
+
+    /// Total number of (object, rule) evaluations
+    pub const fn evaluated(&self) -> usize {
+        self.findings.len() + self.passes.len()
+    }
+}
+
+impl FromIterator<Self> for RuleOutcomes<'_> {
+    fn from_iter<I: IntoIterator<Item = Self>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::default(), |mut acc, outcomes| {
+            acc.extend(outcomes);
+            acc
+        })
+    }
+}
@@ -0,0 +1,22 @@
+use crate::cli::table::RuleResult;
+use crate::core::manifest::dbt_objects::meta::DisabledRule;
+use crate::core::manifest::dbt_objects::Meta;
+
+/// Objects that can opt out of rules through `meta: {dbtective: {disable: [...]}}`
+pub trait Suppressible {
+    /// All `meta` blocks of the object, e.g. both `meta` and `config.meta`
+    fn get_metas(&self) -> Vec<&Meta>;
+
+    fn disabled_rule(&self, rule_name: &str, rule_type: &str) -> Option<DisabledRule> {
+        self.get_metas()
+            .into_iter()
+            .find_map(|meta| meta.disabled_rule(rule_name, rule_type))
+    }
+}
+
+/// A finding that is not reported, because the object disabled the rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuppressedFinding {
+    pub result: RuleResult,
+    pub reason: Option<String>,
+}
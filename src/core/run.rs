@@ -1,74 +1,293 @@
 use crate::cli::commands::RunOptions;
-use crate::cli::table::{show_results_and_exit, RuleResult};
+use crate::cli::github::show_results_as_github;
+use crate::cli::json::show_results_as_json;
+use crate::cli::junit::show_results_as_junit;
+use crate::cli::output::RunInfo;
+use crate::cli::sarif::show_results_as_sarif;
+use crate::cli::table::show_results_and_exit;
+use crate::core::baseline::Baseline;
 use crate::core::catalog::parse_catalog::Catalog;
+use crate::core::config::changed_files::ChangedFiles;
+use crate::core::config::modified_state::ModifiedState;
 use crate::core::config::parse_config::resolve_config_path;
-use crate::core::config::severity::Severity;
 use crate::core::config::Config;
+use crate::core::freshness::parse_sources::FreshnessResults;
 use crate::core::manifest::Manifest;
 use crate::core::rules::catalog::{
     apply_catalog_node_rules::apply_catalog_node_rules,
     apply_catalog_source_rules::apply_catalog_source_rules,
 };
+use crate::core::rules::freshness::apply_freshness_source_rules::apply_freshness_source_rules;
+use crate::core::rules::locations::locate_findings;
 use crate::core::rules::manifest::{
     apply_manifest_node_rules::apply_manifest_node_rules,
     apply_other_manifest_object_rules::apply_manifest_object_rules,
 };
+use crate::core::rules::rule_config::test_coverage::{project_coverage, ColumnCoverage};
+use crate::core::rules::rule_outcomes::RuleOutcomes;
+use crate::core::rules::suppression::SuppressedFinding;
+use crate::core::selection::resolve_selection;
 use crate::core::utils::unwrap_or_exit;
 use log::debug;
+use owo_colors::OwoColorize;
+use std::collections::BTreeMap;
 use std::time::Instant;
 
-#[must_use]
-pub fn run(options: &RunOptions, verbose: bool) -> i32 {
-    let start = Instant::now();
+/// Configuration and dbt artifacts the rules are evaluated against.
+pub struct Artifacts {
+    pub config: Config,
+    pub manifest: Manifest,
+    pub catalog: Option<Catalog>,
+    pub freshness: Option<FreshnessResults>,
+}
 
-    let config_path = resolve_config_path(&options.entry_point, options.config_file.as_ref());
+/// Load the configuration, manifest and (unless `only_manifest`) catalog of a dbt project.
+/// Exits the process if any of them cannot be loaded.
+///
+/// The freshness results are only loaded when `freshness_tests` are configured,
+/// the freshness rules are skipped with a warning when the sources file does not exist.
+pub fn load_artifacts(
+    entry_point: &str,
+    config_file: Option<&String>,
+    manifest_file: &str,
+    catalog_file: &str,
+    sources_file: &str,
+    only_manifest: bool,
+) -> Artifacts {
+    let config_path = resolve_config_path(entry_point, config_file);
     let config = unwrap_or_exit(Config::from_file(config_path));
 
     debug!("Loaded configuration: {config:#?}");
 
-    // Store all findings in a result vector
-    let mut findings: Vec<(RuleResult, &Severity)> = Vec::new();
-
-    // Manifest-based rules
-    let manifest_path =
-        std::path::PathBuf::from(format!("{}/{}", options.entry_point, options.manifest_file));
+    let manifest_path = std::path::PathBuf::from(format!("{entry_point}/{manifest_file}"));
     let manifest = unwrap_or_exit(Manifest::from_file(&manifest_path));
 
-    // Manifest-node object rules
-    findings.extend(unwrap_or_exit(apply_manifest_node_rules(
-        &manifest, &config, verbose,
-    )));
-    // Manifest-non-node object rules (source macro exposures semantic_models unit_tests)
-    findings.extend(unwrap_or_exit(apply_manifest_object_rules(
-        &manifest, &config, verbose,
-    )));
-
     // Catalog-based rules (need both manifest and catalog)
     // This can error in the following case:
     // The manifest has been rebuild using a `dbt` command,
     // yet the `catalog.json` has not been updated with `dbt docs generate`
-    let catalog = if options.only_manifest {
+    let catalog = if only_manifest {
         None
     } else {
-        let catalog_path =
-            std::path::PathBuf::from(format!("{}/{}", options.entry_point, options.catalog_file));
+        let catalog_path = std::path::PathBuf::from(format!("{entry_point}/{catalog_file}"));
         Some(unwrap_or_exit(Catalog::from_file(&catalog_path)))
     };
 
-    if let Some(ref catalog) = catalog {
-        findings.extend(unwrap_or_exit(apply_catalog_node_rules(
-            &config, catalog, &manifest, verbose,
+    // `sources.json` is written by `dbt source freshness`, which needs a connection like the catalog
+    // It is optional: without it the freshness rules are skipped, but an invalid file still exits
+    let sources_path = std::path::PathBuf::from(format!("{entry_point}/{sources_file}"));
+    let freshness = if only_manifest || config.freshness_tests.is_none() {
+        None
+    } else if sources_path.exists() {
+        Some(unwrap_or_exit(FreshnessResults::from_file(&sources_path)))
+    } else {
+        eprintln!(
+            "{} {}",
+            "Warning:".yellow().bold(),
+            format!(
+                "No sources file found at {}, skipping the freshness rules. Generate it using 'dbt source freshness'.",
+                sources_path.display()
+            )
+            .yellow()
+        );
+        None
+    };
+
+    Artifacts {
+        config,
+        manifest,
+        catalog,
+        freshness,
+    }
+}
+
+/// Apply all configured rules and resolve the locations of the findings.
+/// Exits the process if a rule has an invalid configuration.
+pub fn evaluate_rules<'a>(
+    artifacts: &'a Artifacts,
+    entry_point: &str,
+    verbose: bool,
+) -> RuleOutcomes<'a> {
+    let Artifacts {
+        config,
+        manifest,
+        catalog,
+        freshness,
+    } = artifacts;
+
+    // Store all findings and passed evaluations
+    let mut outcomes = RuleOutcomes::default();
+
+    // Manifest-node object rules
+    outcomes.extend(unwrap_or_exit(apply_manifest_node_rules(
+        manifest, config, verbose,
+    )));
+    // Manifest-non-node object rules (source macro exposures semantic_models unit_tests)
+    outcomes.extend(unwrap_or_exit(apply_manifest_object_rules(
+        manifest, config, verbose,
+    )));
+
+    if let Some(catalog) = catalog {
+        outcomes.extend(unwrap_or_exit(apply_catalog_node_rules(
+            config, catalog, manifest, verbose,
         )));
-        findings.extend(unwrap_or_exit(apply_catalog_source_rules(
-            &config, catalog, &manifest, verbose,
+        outcomes.extend(unwrap_or_exit(apply_catalog_source_rules(
+            config, catalog, manifest, verbose,
         )));
     }
+    if let Some(freshness) = freshness {
+        outcomes.extend(unwrap_or_exit(apply_freshness_source_rules(
+            config, freshness, manifest,
+        )));
+    }
+
+    locate_findings(&mut outcomes.findings, manifest, entry_point);
+    outcomes
+}
 
-    show_results_and_exit(
-        &findings,
-        verbose,
-        options.entry_point.as_ref(),
-        options.disable_hyperlinks,
-        Some(start.elapsed()),
-    )
+/// Summarize the findings suppressed through `meta`, listing them with their justification in verbose mode.
+/// Printed to stderr, so machine-readable output on stdout stays valid.
+fn report_suppressed(suppressed: &[SuppressedFinding], verbose: bool) {
+    if suppressed.is_empty() {
+        return;
+    }
+    eprintln!(
+        "{} {} finding(s) suppressed through `meta.dbtective.disable`.",
+        "Suppressed:".yellow().bold(),
+        suppressed.len()
+    );
+    if verbose {
+        for SuppressedFinding { result, reason } in suppressed {
+            eprintln!(
+                "  - {} ({}): {} [reason: {}]",
+                result.rule_name,
+                result.unique_id.as_deref().unwrap_or(&result.object_type),
+                result.message,
+                reason.as_deref().unwrap_or("none given")
+            );
+        }
+    }
+}
+
+/// Summarize the column test coverage of the objects evaluated by `test_coverage` rules.
+/// Printed to stderr, so machine-readable output on stdout stays valid.
+fn report_coverage(coverage: &BTreeMap<String, ColumnCoverage>) {
+    let ColumnCoverage { tested, documented } = project_coverage(coverage);
+    if documented == 0 {
+        return;
+    }
+    eprintln!(
+        "{} {tested} of {documented} documented column(s) in {} object(s) have at least one test ({}.{}%).",
+        "Coverage:".yellow().bold(),
+        coverage.len(),
+        tested * 100 / documented,
+        tested * 1000 / documented % 10,
+    );
+}
+
+#[must_use]
+pub fn run(options: &RunOptions, verbose: bool) -> i32 {
+    let start = Instant::now();
+
+    let mut artifacts = load_artifacts(
+        &options.entry_point,
+        options.config_file.as_ref(),
+        &options.manifest_file,
+        &options.catalog_file,
+        &options.sources_file,
+        options.only_manifest,
+    );
+    if let Some(git_ref) = &options.changed_since {
+        let changed_files = unwrap_or_exit(ChangedFiles::from_git(&options.entry_point, git_ref));
+        if changed_files.is_empty() {
+            eprintln!(
+                "{} No files changed since {git_ref}, no objects are evaluated.",
+                "Changed:".yellow().bold(),
+            );
+        } else {
+            eprintln!(
+                "{} {} file(s) changed since {git_ref}, only objects defined in them are evaluated.",
+                "Changed:".yellow().bold(),
+                changed_files.len()
+            );
+        }
+        artifacts.config.changed_files = Some(changed_files);
+    }
+    if let Some(state) = &options.state {
+        let previous = unwrap_or_exit(Manifest::from_file(state));
+        let modified_state = ModifiedState::compare(&artifacts.manifest, &previous);
+        if modified_state.is_empty() {
+            eprintln!(
+                "{} No objects new or modified compared to {state}, no objects are evaluated.",
+                "State:".yellow().bold(),
+            );
+        } else {
+            eprintln!(
+                "{} {} object(s) new or modified compared to {state}, only these are evaluated.",
+                "State:".yellow().bold(),
+                modified_state.len()
+            );
+        }
+        artifacts.config.modified_state = Some(modified_state);
+    }
+    if options.select.is_some() || options.exclude.is_some() || options.selector.is_some() {
+        let selected = unwrap_or_exit(resolve_selection(
+            options.select.as_deref(),
+            options.exclude.as_deref(),
+            options.selector.as_deref(),
+            &artifacts.manifest,
+        ));
+        debug!("Selected {} object(s)", selected.len());
+        artifacts.config.selected = Some(selected);
+    }
+    let Artifacts {
+        config, manifest, ..
+    } = &artifacts;
+    let mut outcomes = evaluate_rules(&artifacts, &options.entry_point, verbose);
+
+    report_suppressed(&outcomes.suppressed, verbose);
+    report_coverage(&outcomes.column_coverage);
+
+    // Only findings missing from the baseline fail the run
+    if let Some(baseline_file) = &options.baseline {
+        let baseline = unwrap_or_exit(Baseline::from_file(baseline_file));
+        let evaluated = outcomes.evaluated_unique_ids();
+        let comparison = baseline.filter_findings(&mut outcomes.findings, &evaluated);
+        comparison.report(baseline_file);
+    }
+
+    let findings = &outcomes.findings;
+    let run_info = RunInfo {
+        dbt_version: &manifest.metadata.dbt_version,
+        project_name: manifest.metadata.project_name.as_deref(),
+        manifest_generated_at: Some(&manifest.metadata.generated_at),
+        duration: Some(start.elapsed()),
+    };
+
+    match options.output_format.as_str() {
+        "github" => {
+            show_results_as_github(findings, &options.entry_point, options.output_file.as_ref())
+        }
+        "json" => show_results_as_json(findings, &run_info, options.output_file.as_ref()),
+        "junit" => show_results_as_junit(
+            &outcomes,
+            config,
+            &options.entry_point,
+            run_info.duration,
+            options.output_file.as_ref(),
+        ),
+        "sarif" => show_results_as_sarif(
+            findings,
+            config,
+            &options.entry_point,
+            options.output_file.as_ref(),
+        ),
+        _ => show_results_and_exit(
+            findings,
+            verbose,
+            options.entry_point.as_ref(),
+            options.disable_hyperlinks,
+            run_info.duration,
+        ),
+    }
 }
@@ -0,0 +1,385 @@
+pub mod selectors;
+pub mod spec;
+
+use anyhow::Result;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::OnceLock;
+
+use crate::core::config::glob::Glob;
+use crate::core::config::includes_excludes::IncludeExcludable;
+use crate::core::manifest::dbt_objects::nodes::node::Node;
+use crate::core::manifest::Manifest;
+use selectors::SelectorResolver;
+use spec::{Criterion, Depth, SelectionMethod, SelectionSpec};
+
+/// The properties of a manifest object that selection methods match on.
+struct SelectableObject<'a> {
+    unique_id: &'a str,
+    name: &'a str,
+    resource_type: &'static str,
+    package: &'a str,
+    fqn: Vec<&'a str>,
+    path: &'a str,
+    tags: &'a [String],
+    materialized: Option<&'a str>,
+}
+
+const fn node_resource_type(node: &Node) -> &'static str {
+    match node {
+        Node::Analysis(_) => "analysis",
+        Node::Seed(_) => "seed",
+        Node::Model(_) => "model",
+        Node::Test(_) => "test",
+        Node::Snapshot(_) => "snapshot",
+        Node::HookNode(_) => "operation",
+        Node::SqlOperation(_) => "sql_operation",
+    }
+}
+
+fn fqn_or_name<'a>(fqn: Option<&'a Vec<String>>, package: &'a str, name: &'a str) -> Vec<&'a str> {
+    fqn.map_or_else(
+        || vec![package, name],
+        |fqn| fqn.iter().map(String::as_str).collect(),
+    )
+}
+
+fn selectable_objects(manifest: &Manifest) -> Vec<SelectableObject<'_>> {
+    let nodes = manifest.nodes.values().map(|node| {
+        let base = node.get_base();
+        SelectableObject {
+            unique_id: &base.unique_id,
+            name: &base.name,
+            resource_type: node_resource_type(node),
+            package: &base.package_name,
+            fqn: base.fqn.iter().map(String::as_str).collect(),
+            path: &base.original_file_path,
+            tags: base.tags.as_deref().unwrap_or_default(),
+            materialized: node.get_materialized(),
+        }
+    });
+    let sources = manifest.sources.values().map(|source| SelectableObject {
+        unique_id: &source.unique_id,
+        name: &source.name,
+        resource_type: "source",
+        package: &source.package_name,
+        fqn: fqn_or_name(source.fqn.as_ref(), &source.package_name, &source.name),
+        path: &source.original_file_path,
+        tags: source.tags.as_deref().unwrap_or_default(),
+        materialized: None,
+    });
+    let macros = manifest.macros.values().map(|macro_obj| SelectableObject {
+        unique_id: &macro_obj.unique_id,
+        name: &macro_obj.name,
+        resource_type: "macro",
+        package: &macro_obj.package_name,
+        fqn: vec![&macro_obj.package_name, &macro_obj.name],
+        path: &macro_obj.original_file_path,
+        tags: &[],
+        materialized: None,
+    });
+    let exposures = manifest
+        .exposures
+        .values()
+        .map(|exposure| SelectableObject {
+            unique_id: &exposure.unique_id,
+            name: &exposure.name,
+            resource_type: "exposure",
+            package: &exposure.package_name,
+            fqn: fqn_or_name(
+                exposure.fqn.as_ref(),
+                &exposure.package_name,
+                &exposure.name,
+            ),
+            path: &exposure.original_file_path,
+            tags: exposure.tags.as_deref().unwrap_or_default(),
+            materialized: None,
+        });
+    let semantic_models = manifest
+        .semantic_models
+        .values()
+        .map(|sm| SelectableObject {
+            unique_id: &sm.unique_id,
+            name: &sm.name,
+            resource_type: "semantic_model",
+            package: &sm.package_name,
+            fqn: fqn_or_name(sm.fqn.as_ref(), &sm.package_name, &sm.name),
+            path: &sm.original_file_path,
+            tags: &[],
+            materialized: None,
+        });
+    let unit_tests = manifest.unit_tests.values().map(|ut| SelectableObject {
+        unique_id: &ut.unique_id,
+        name: &ut.name,
+        resource_type: "unit_test",
+        package: &ut.package_name,
+        fqn: fqn_or_name(ut.fqn.as_ref(), &ut.package_name, &ut.name),
+        path: &ut.original_file_path,
+        tags: &[],
+        materialized: None,
+    });
+
+    nodes
+        .chain(sources)
+        .chain(macros)
+        .chain(exposures)
+        .chain(semantic_models)
+        .chain(unit_tests)
+        .collect()
+}
+
+/// The value of a criterion, compiled once to match every object against
+struct ValueMatcher {
+    glob: Glob,
+    /// The `.` separated parts of the value, to match a prefix of an fqn
+    fqn_parts: Vec<Glob>,
+    /// The value as a directory, to match every file in it
+    directory: String,
+}
+
+impl ValueMatcher {
+    fn new(value: &str) -> Self {
+        let path = value
+            .replace('\\', "/")
+            .trim_start_matches("./")
+            .trim_end_matches('/')
+            .to_string();
+        Self {
+            glob: Glob::new(&path),
+            fqn_parts: value.split('.').map(Glob::new).collect(),
+            directory: format!("{path}/"),
+        }
+    }
+
+    fn matches(&self, value: &str) -> bool {
+        self.glob.is_match(value)
+    }
+
+    /// Like dbt, an fqn selector matches the name of an object or a prefix of its fqn
+    /// (e.g. `jaffle_shop.staging` selects everything in the staging folder of `jaffle_shop`).
+    /// The name is matched separately, since the fqn of a versioned model ends with its version.
+    fn matches_fqn(&self, fqn: &[&str], name: &str) -> bool {
+        if self.matches(name) || fqn.last().is_some_and(|last| self.matches(last)) {
+            return true;
+        }
+        self.fqn_parts.len() <= fqn.len()
+            && self
+                .fqn_parts
+                .iter()
+                .zip(fqn)
+                .all(|(part, fqn_part)| part.is_match(fqn_part))
+    }
+
+    /// A path selector matches a file, or every file in a directory
+    fn matches_path(&self, path: &str) -> bool {
+        self.matches(path) || path.replace('\\', "/").starts_with(&self.directory)
+    }
+}
+
+impl Criterion {
+    fn matches(&self, object: &SelectableObject, value: &ValueMatcher) -> bool {
+        match self.method {
+            SelectionMethod::Fqn => value.matches_fqn(&object.fqn, object.name),
+            SelectionMethod::Tag => object.tags.iter().any(|tag| value.matches(tag)),
+            SelectionMethod::Path => value.matches_path(object.path),
+            SelectionMethod::Package => value.matches(object.package),
+            SelectionMethod::ResourceType => object.resource_type == self.value,
+            SelectionMethod::Materialized => object
+                .materialized
+                .is_some_and(|materialized| value.matches(materialized)),
+        }
+    }
+}
+
+/// Walk `edges` (the parent or child map) from `start`, up to `depth` steps away
+fn walk(
+    edges: &HashMap<String, Vec<String>>,
+    start: &HashSet<String>,
+    depth: Depth,
+) -> HashSet<String> {
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut queue: VecDeque<(&str, usize)> = start.iter().map(|id| (id.as_str(), 0)).collect();
+
+    while let Some((unique_id, distance)) = queue.pop_front() {
+        if depth.is_some_and(|depth| distance >= depth) {
+            continue;
+        }
+        for next in edges.get(unique_id).into_iter().flatten() {
+            if seen.insert(next.clone()) {
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    seen
+}
+
+fn resolve_criterion(
+    criterion: &Criterion,
+    objects: &[SelectableObject],
+    manifest: &Manifest,
+) -> HashSet<String> {
+    let value = ValueMatcher::new(&criterion.value);
+    let matched: HashSet<String> = objects
+        .iter()
+        .filter(|object| criterion.matches(object, &value))
+        .map(|object| object.unique_id.to_string())
+        .collect();
+
+    let mut selected = matched.clone();
+    if criterion.childrens_parents {
+        let descendants = walk(&manifest.child_map, &matched, None);
+        let with_descendants: HashSet<String> = matched.union(&descendants).cloned().collect();
+        selected.extend(walk(&manifest.parent_map, &with_descendants, None));
+        selected.extend(with_descendants);
+    }
+    if let Some(depth) = criterion.parents {
+        selected.extend(walk(&manifest.parent_map, &matched, depth));
+    }
+    if let Some(depth) = criterion.children {
+        selected.extend(walk(&manifest.child_map, &matched, depth));
+    }
+    selected
+}
+
+impl SelectionSpec {
+    fn resolve(&self, objects: &[SelectableObject], manifest: &Manifest) -> HashSet<String> {
+        self.unions
+            .iter()
+            .flat_map(|intersection| {
+                intersection
+                    .iter()
+                    .map(|criterion| resolve_criterion(criterion, objects, manifest))
+                    .reduce(|acc, selected| acc.intersection(&selected).cloned().collect())
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+}
+
+/// Resolve `--select`/`--exclude`/`--selector` style arguments to the unique ids of the selected objects.
+///
+/// Without `select` or `selector` everything is selected. When both are given only objects
+/// matching both are selected, `exclude` is removed from the selection.
+/// # Errors
+/// Returns an error if a selector is invalid or the named selector is not defined in the manifest
+pub fn resolve_selection<S: AsRef<str>>(
+    select: Option<&[S]>,
+    exclude: Option<&[S]>,
+    selector: Option<&str>,
+    manifest: &Manifest,
+) -> Result<HashSet<String>> {
+    let objects = selectable_objects(manifest);
+
+    let mut selected = match select {
+        Some(select) => SelectionSpec::parse(select)?.resolve(&objects, manifest),
+        None => objects
+            .iter()
+            .map(|object| object.unique_id.to_string())
+            .collect(),
+    };
+    if let Some(selector) = selector {
+        let named = SelectorResolver::new(&objects, manifest).resolve(selector)?;
+        selected.retain(|unique_id| named.contains(unique_id));
+    }
+    if let Some(exclude) = exclude {
+        let excluded = SelectionSpec::parse(exclude)?.resolve(&objects, manifest);
+        selected.retain(|unique_id| !excluded.contains(unique_id));
+    }
+    Ok(selected)
+}
+
+/// dbt-style `select`, `exclude` and `selector` of a rule definition.
+/// The selection is resolved against the manifest on first use.
+#[derive(Debug, Default, Deserialize)]
+pub struct RuleSelection {
+    pub select: Option<Vec<String>>,
+    pub exclude: Option<Vec<String>>,
+    /// Name of a selector from the project's `selectors.yml`
+    pub selector: Option<String>,
+    #[serde(skip)]
+    selected: OnceLock<HashSet<String>>,
+}
+
+impl RuleSelection {
+    /// Check the syntax of the selectors, without a manifest
+    /// # Errors
+    /// Returns an error if a selector is invalid
+    pub fn validate(&self) -> Result<()> {
+        for selectors in [&self.select, &self.exclude].into_iter().flatten() {
+            SelectionSpec::parse(selectors)?;
+        }
+        Ok(())
+    }
+
+    /// Whether the rule applies to the object, always true if the rule has no `select`, `exclude` or `selector`
+    /// # Errors
+    /// Returns an error if a selector is invalid or the named selector is not defined in the manifest
+    pub fn is_selected<T: IncludeExcludable>(
+        &self,
+        object: &T,
+        manifest: &Manifest,
+    ) -> Result<bool> {
+        if self.select.is_none() && self.exclude.is_none() && self.selector.is_none() {
+            return Ok(true);
+        }
+        if let Some(selected) = self.selected.get() {
+            return Ok(selected.contains(object.get_unique_id()));
+        }
+
+        let selected = resolve_selection(
+            self.select.as_deref(),
+            self.exclude.as_deref(),
+            self.selector.as_deref(),
+            manifest,
+        )
+        .map_err(|err| {
+            anyhow::anyhow!(
+                "Invalid `select`, `exclude` or `selector` in rule configuration: {err}"
+            )
+        })?;
+        Ok(self
+            .selected
+            .get_or_init(|| selected)
+            .contains(object.get_unique_id()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches_fqn(fqn: &[&str], name: &str, value: &str) -> bool {
+        ValueMatcher::new(value).matches_fqn(fqn, name)
+    }
+
+    fn matches_path(path: &str, value: &str) -> bool {
+        ValueMatcher::new(value).matches_path(path)
+    }
+
+    #[test]
+    fn test_matches_fqn() {
+        let fqn = ["jaffle_shop", "staging", "crm", "stg_customers"];
+        assert!(matches_fqn(&fqn, "stg_customers", "stg_customers"));
+        assert!(matches_fqn(&fqn, "stg_customers", "stg_*"));
+        assert!(matches_fqn(&fqn, "stg_customers", "jaffle_shop"));
+        assert!(matches_fqn(&fqn, "stg_customers", "jaffle_shop.staging"));
+        assert!(!matches_fqn(&fqn, "stg_customers", "jaffle_shop.marts"));
+        assert!(!matches_fqn(&fqn, "stg_customers", "staging"));
+
+        // Versioned models end their fqn with the version
+        let fqn = ["jaffle_shop", "marts", "customers", "v2"];
+        assert!(matches_fqn(&fqn, "customers", "customers"));
+    }
+
+    #[test]
+    fn test_matches_path() {
+        let path = "models/staging/crm/stg_customers.sql";
+        assert!(matches_path(path, "models/staging"));
+        assert!(matches_path(path, "./models/staging/"));
+        assert!(matches_path(path, "models/staging/crm/stg_customers.sql"));
+        assert!(matches_path(path, "models/*/crm/*.sql"));
+        assert!(matches_path(path, "models/**/*.sql"));
+        assert!(!matches_path(path, "models/*.sql"));
+        assert!(!matches_path(path, "models/stag"));
+    }
+}
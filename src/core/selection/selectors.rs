@@ -0,0 +1,292 @@
+use anyhow::{Context, Result};
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+
+use super::spec::{Criterion, SelectionMethod, SelectionSpec};
+use super::{resolve_criterion, SelectableObject};
+use crate::core::manifest::Manifest;
+
+/// A selector definition from `selectors.yml`, as stored in the manifest.
+/// See <https://docs.getdbt.com/reference/node-selection/yaml-selectors>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelectorDefinition {
+    /// CLI style selection, e.g. `definition: "tag:nightly +orders"`
+    Spec(SelectionSpec),
+    Criterion(Criterion),
+    /// `method: selector` includes another named selector
+    Selector(String),
+    /// Everything selected by any of `items`, except for `exclude`
+    Union {
+        items: Vec<Self>,
+        exclude: Vec<Self>,
+    },
+    /// Everything selected by all of `items`, except for `exclude`
+    Intersection {
+        items: Vec<Self>,
+        exclude: Vec<Self>,
+    },
+}
+
+fn as_list<'a>(value: &'a Value, key: &str) -> Result<&'a Vec<Value>> {
+    value
+        .as_array()
+        .with_context(|| format!("Expected a list for `{key}`, got: {value}"))
+}
+
+/// Split the items of a `union` or `intersection` into the selected and excluded definitions
+fn parse_items(items: &[Value]) -> Result<(Vec<SelectorDefinition>, Vec<SelectorDefinition>)> {
+    let mut selected = Vec::new();
+    let mut excluded = Vec::new();
+    for item in items {
+        match item.get("exclude") {
+            Some(exclude) if item.as_object().is_some_and(|item| item.len() == 1) => {
+                for definition in as_list(exclude, "exclude")? {
+                    excluded.push(SelectorDefinition::parse(definition)?);
+                }
+            }
+            _ => selected.push(SelectorDefinition::parse(item)?),
+        }
+    }
+    Ok((selected, excluded))
+}
+
+fn parse_flag(definition: &Map<String, Value>, key: &str) -> Result<bool> {
+    definition.get(key).map_or(Ok(false), |value| {
+        value
+            .as_bool()
+            .with_context(|| format!("Expected true or false for `{key}`, got: {value}"))
+    })
+}
+
+fn parse_depth(definition: &Map<String, Value>, key: &str) -> Result<Option<usize>> {
+    definition
+        .get(key)
+        .map(|value| {
+            value
+                .as_u64()
+                .and_then(|depth| usize::try_from(depth).ok())
+                .with_context(|| format!("Expected a positive number for `{key}`, got: {value}"))
+        })
+        .transpose()
+}
+
+/// A `method`/`value` definition, or its `tag: nightly` shorthand
+fn parse_method(
+    definition: &Map<String, Value>,
+    method: &str,
+    value: &Value,
+) -> Result<SelectorDefinition> {
+    let value = value
+        .as_str()
+        .with_context(|| format!("Expected a string value for method '{method}', got: {value}"))?;
+    if method == "selector" {
+        if ["parents", "children", "childrens_parents", "exclude"]
+            .iter()
+            .any(|key| definition.contains_key(*key))
+        {
+            anyhow::bail!(
+                "Graph operators and `exclude` are not supported with `method: selector`"
+            );
+        }
+        return Ok(SelectorDefinition::Selector(value.to_string()));
+    }
+
+    let criterion = Criterion {
+        method: SelectionMethod::from_name(method, &format!("{method}:{value}"))?,
+        value: value.to_string(),
+        parents: parse_flag(definition, "parents")?
+            .then(|| parse_depth(definition, "parents_depth"))
+            .transpose()?,
+        children: parse_flag(definition, "children")?
+            .then(|| parse_depth(definition, "children_depth"))
+            .transpose()?,
+        childrens_parents: parse_flag(definition, "childrens_parents")?,
+    };
+
+    match definition.get("exclude") {
+        Some(exclude) => Ok(SelectorDefinition::Union {
+            items: vec![SelectorDefinition::Criterion(criterion)],
+            exclude: as_list(exclude, "exclude")?
+                .iter()
+                .map(SelectorDefinition::parse)
+                .collect::<Result<_>>()?,
+        }),
+        None => Ok(SelectorDefinition::Criterion(criterion)),
+    }
+}
+
+impl SelectorDefinition {
+    /// # Errors
+    /// Returns an error if the definition is not a valid dbt selector definition
+    pub fn parse(definition: &Value) -> Result<Self> {
+        if let Some(spec) = definition.as_str() {
+            return SelectionSpec::parse(&[spec]).map(Self::Spec);
+        }
+        let Some(object) = definition.as_object() else {
+            anyhow::bail!("Unsupported selector definition: {definition}");
+        };
+
+        if let Some(union) = object.get("union") {
+            let (items, exclude) = parse_items(as_list(union, "union")?)?;
+            return Ok(Self::Union { items, exclude });
+        }
+        if let Some(intersection) = object.get("intersection") {
+            let (items, exclude) = parse_items(as_list(intersection, "intersection")?)?;
+            return Ok(Self::Intersection { items, exclude });
+        }
+        if let Some(method) = object.get("method") {
+            let method = method
+                .as_str()
+                .with_context(|| format!("Expected a string for `method`, got: {method}"))?;
+            let value = object
+                .get("value")
+                .with_context(|| format!("Missing `value` for method '{method}'"))?;
+            return parse_method(object, method, value);
+        }
+        match object.iter().next() {
+            Some((method, value)) if object.len() == 1 => parse_method(object, method, value),
+            _ => anyhow::bail!("Unsupported selector definition: {definition}"),
+        }
+    }
+}
+
+/// Resolves named selectors, following `method: selector` references between them
+pub(super) struct SelectorResolver<'a> {
+    objects: &'a [SelectableObject<'a>],
+    manifest: &'a Manifest,
+    /// Selectors currently being resolved, to detect cycles
+    stack: Vec<String>,
+}
+
+fn resolve_all(
+    definitions: &[SelectorDefinition],
+    resolver: &mut SelectorResolver,
+) -> Result<Vec<HashSet<String>>> {
+    definitions
+        .iter()
+        .map(|definition| resolver.resolve_definition(definition))
+        .collect()
+}
+
+fn union(sets: Vec<HashSet<String>>) -> HashSet<String> {
+    sets.into_iter().flatten().collect()
+}
+
+impl<'a> SelectorResolver<'a> {
+    pub(super) const fn new(objects: &'a [SelectableObject<'a>], manifest: &'a Manifest) -> Self {
+        Self {
+            objects,
+            manifest,
+            stack: Vec::new(),
+        }
+    }
+
+    /// # Errors
+    /// Returns an error if the selector does not exist, is invalid or references itself
+    pub(super) fn resolve(&mut self, name: &str) -> Result<HashSet<String>> {
+        let selectors = &self.manifest.selectors;
+        let Some(selector) = selectors.get(name) else {
+            let mut available: Vec<&str> = selectors.keys().map(String::as_str).collect();
+            available.sort_unstable();
+            anyhow::bail!(
+                "Unknown selector '{name}'. Selectors defined in the manifest: {}",
+                if available.is_empty() {
+                    "none".to_string()
+                } else {
+                    available.join(", ")
+                }
+            );
+        };
+        if self.stack.iter().any(|resolving| resolving == name) {
+            anyhow::bail!(
+                "Selector '{name}' references itself: {} -> {name}",
+                self.stack.join(" -> ")
+            );
+        }
+
+        let definition = SelectorDefinition::parse(&selector.definition)
+            .map_err(|err| anyhow::anyhow!("Invalid definition of selector '{name}': {err}"))?;
+        self.stack.push(name.to_string());
+        let selected = self.resolve_definition(&definition);
+        self.stack.pop();
+        selected
+    }
+
+    fn resolve_definition(&mut self, definition: &SelectorDefinition) -> Result<HashSet<String>> {
+        let (selected, exclude) = match definition {
+            SelectorDefinition::Spec(spec) => return Ok(spec.resolve(self.objects, self.manifest)),
+            SelectorDefinition::Criterion(criterion) => {
+                return Ok(resolve_criterion(criterion, self.objects, self.manifest))
+            }
+            SelectorDefinition::Selector(name) => return self.resolve(name),
+            SelectorDefinition::Union { items, exclude } => {
+                (union(resolve_all(items, self)?), exclude)
+            }
+            SelectorDefinition::Intersection { items, exclude } => (
+                resolve_all(items, self)?
+                    .into_iter()
+                    .reduce(|acc, selected| acc.intersection(&selected).cloned().collect())
+                    .unwrap_or_default(),
+                exclude,
+            ),
+        };
+
+        let excluded = union(resolve_all(exclude, self)?);
+        Ok(selected
+            .into_iter()
+            .filter(|unique_id| !excluded.contains(unique_id))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_definitions() {
+        let definition = SelectorDefinition::parse(&json!({
+            "union": [
+                {"method": "tag", "value": "nightly", "parents": true, "parents_depth": 1},
+                {"path": "models/marts"},
+                {"exclude": [{"method": "selector", "value": "deprecated"}]}
+            ]
+        }))
+        .unwrap();
+
+        let SelectorDefinition::Union { items, exclude } = definition else {
+            panic!("Expected a union");
+        };
+        let SelectorDefinition::Criterion(criterion) = &items[0] else {
+            panic!("Expected a criterion");
+        };
+        assert_eq!(criterion.method, SelectionMethod::Tag);
+        assert_eq!(criterion.parents, Some(Some(1)));
+        assert_eq!(criterion.children, None);
+        assert!(
+            matches!(&items[1], SelectorDefinition::Criterion(c) if c.method == SelectionMethod::Path)
+        );
+        assert_eq!(
+            exclude,
+            vec![SelectorDefinition::Selector("deprecated".to_string())]
+        );
+
+        assert!(matches!(
+            SelectorDefinition::parse(&json!("tag:nightly +orders")).unwrap(),
+            SelectorDefinition::Spec(_)
+        ));
+    }
+
+    #[test]
+    fn test_parse_invalid_definitions() {
+        assert!(SelectorDefinition::parse(&json!({"method": "unknown", "value": "x"})).is_err());
+        assert!(SelectorDefinition::parse(&json!({"method": "tag"})).is_err());
+        assert!(SelectorDefinition::parse(&json!({"union": "tag:nightly"})).is_err());
+        assert!(SelectorDefinition::parse(&json!(42)).is_err());
+        assert!(SelectorDefinition::parse(
+            &json!({"method": "selector", "value": "nightly", "parents": true})
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,196 @@
+use anyhow::{Context, Result};
+use strum::IntoEnumIterator;
+use strum_macros::{AsRefStr, EnumIter, EnumString};
+
+/// Method of a selection criterion, e.g. the `tag` in `tag:finance`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, AsRefStr, EnumString)]
+pub enum SelectionMethod {
+    #[strum(serialize = "fqn")]
+    Fqn,
+    #[strum(serialize = "tag")]
+    Tag,
+    #[strum(serialize = "path")]
+    Path,
+    #[strum(serialize = "package")]
+    Package,
+    #[strum(serialize = "resource_type")]
+    ResourceType,
+    #[strum(serialize = "config.materialized")]
+    Materialized,
+}
+
+impl SelectionMethod {
+    /// # Errors
+    /// Returns an error if the method is not supported, `selector` is used in the message
+    pub fn from_name(method: &str, selector: &str) -> Result<Self> {
+        method.parse::<Self>().map_err(|_| {
+            let supported: Vec<String> = Self::iter().map(|m| m.as_ref().to_string()).collect();
+            anyhow::anyhow!(
+                "Unknown selection method '{method}' in selector '{selector}'. Supported methods are: {}",
+                supported.join(", ")
+            )
+        })
+    }
+}
+
+/// How far to walk the graph for `+` operators, `None` walks all the way.
+pub type Depth = Option<usize>;
+
+/// A single selection criterion with its graph operators, e.g. `2+tag:finance+`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Criterion {
+    pub method: SelectionMethod,
+    pub value: String,
+    /// `+model`: also select the ancestors
+    pub parents: Option<Depth>,
+    /// `model+`: also select the descendants
+    pub children: Option<Depth>,
+    /// `@model`: also select the descendants and the ancestors of all of them
+    pub childrens_parents: bool,
+}
+
+/// A dbt selection expression: space separated items are combined as a union,
+/// comma separated criteria within an item as an intersection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectionSpec {
+    pub unions: Vec<Vec<Criterion>>,
+}
+
+fn parse_depth(digits: &str, criterion: &str) -> Result<Depth> {
+    if digits.is_empty() {
+        return Ok(None);
+    }
+    digits.parse().map(Some).context(format!(
+        "Invalid graph operator depth in selector '{criterion}'"
+    ))
+}
+
+/// Without a method, values that look like files or directories select by path and others by fqn
+fn default_method(value: &str) -> SelectionMethod {
+    let is_file = [".sql", ".py", ".csv", ".yml", ".yaml"]
+        .iter()
+        .any(|extension| value.ends_with(extension));
+    if value.contains('/') || value.contains('\\') || is_file {
+        SelectionMethod::Path
+    } else {
+        SelectionMethod::Fqn
+    }
+}
+
+impl Criterion {
+    /// # Errors
+    /// Returns an error if the method is unknown, a depth is invalid or the value is missing
+    pub fn parse(criterion: &str) -> Result<Self> {
+        let (childrens_parents, rest) = criterion
+            .strip_prefix('@')
+            .map_or((false, criterion), |rest| (true, rest));
+
+        let (parents, rest) = match rest.find('+') {
+            Some(index) if rest[..index].chars().all(|c| c.is_ascii_digit()) => (
+                Some(parse_depth(&rest[..index], criterion)?),
+                &rest[index + 1..],
+            ),
+            _ => (None, rest),
+        };
+
+        let (children, rest) = match rest.rfind('+') {
+            Some(index) if rest[index + 1..].chars().all(|c| c.is_ascii_digit()) => (
+                Some(parse_depth(&rest[index + 1..], criterion)?),
+                &rest[..index],
+            ),
+            _ => (None, rest),
+        };
+
+        if childrens_parents && (parents.is_some() || children.is_some()) {
+            anyhow::bail!("The '@' operator cannot be combined with '+' in selector '{criterion}'");
+        }
+
+        let (method, value) = match rest.split_once(':') {
+            Some((method, value)) => (SelectionMethod::from_name(method, criterion)?, value),
+            None => (default_method(rest), rest),
+        };
+
+        if value.is_empty() {
+            anyhow::bail!("Missing value in selector '{criterion}'");
+        }
+
+        Ok(Self {
+            method,
+            value: value.to_string(),
+            parents,
+            children,
+            childrens_parents,
+        })
+    }
+}
+
+impl SelectionSpec {
+    /// Parse selection arguments, every argument can contain several space separated items.
+    /// # Errors
+    /// Returns an error if any criterion is invalid
+    pub fn parse<S: AsRef<str>>(arguments: &[S]) -> Result<Self> {
+        let unions = arguments
+            .iter()
+            .flat_map(|argument| argument.as_ref().split_whitespace())
+            .map(|item| {
+                item.split(',')
+                    .filter(|criterion| !criterion.is_empty())
+                    .map(Criterion::parse)
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self { unions })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_methods() {
+        let criterion = Criterion::parse("tag:finance").unwrap();
+        assert_eq!(criterion.method, SelectionMethod::Tag);
+        assert_eq!(criterion.value, "finance");
+
+        let criterion = Criterion::parse("config.materialized:incremental").unwrap();
+        assert_eq!(criterion.method, SelectionMethod::Materialized);
+
+        assert_eq!(
+            Criterion::parse("orders").unwrap().method,
+            SelectionMethod::Fqn
+        );
+        assert_eq!(
+            Criterion::parse("models/staging").unwrap().method,
+            SelectionMethod::Path
+        );
+        assert!(Criterion::parse("unknown:value").is_err());
+        assert!(Criterion::parse("tag:").is_err());
+    }
+
+    #[test]
+    fn test_parse_graph_operators() {
+        let criterion = Criterion::parse("+orders").unwrap();
+        assert_eq!(criterion.parents, Some(None));
+        assert_eq!(criterion.children, None);
+
+        let criterion = Criterion::parse("2+tag:finance+1").unwrap();
+        assert_eq!(criterion.parents, Some(Some(2)));
+        assert_eq!(criterion.children, Some(Some(1)));
+        assert_eq!(criterion.value, "finance");
+
+        let criterion = Criterion::parse("@orders").unwrap();
+        assert!(criterion.childrens_parents);
+        assert!(Criterion::parse("@orders+").is_err());
+    }
+
+    #[test]
+    fn test_parse_unions_and_intersections() {
+        let spec =
+            SelectionSpec::parse(&["tag:finance,path:models/marts orders+", "customers"]).unwrap();
+        assert_eq!(spec.unions.len(), 3);
+        assert_eq!(spec.unions[0].len(), 2);
+        assert_eq!(spec.unions[1][0].children, Some(None));
+    }
+}
@@ -1,6 +1,7 @@
 mod cli;
 mod core;
 use crate::cli::commands::{Cli, Commands};
+use crate::core::baseline::baseline;
 use crate::core::init::init;
 use crate::core::run::run;
 use clap::{CommandFactory, Parser};
@@ -31,6 +32,13 @@ fn main() {
             }
             std::process::exit(init(options, args.verbose));
         }
+        Some(Commands::Baseline { options }) => {
+            if args.verbose {
+                debug!("Writing dbtective baseline...");
+                debug!("{options:#?}");
+            }
+            std::process::exit(baseline(options, args.verbose));
+        }
         None => {
             println!(
                 r"
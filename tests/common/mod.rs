@@ -4,11 +4,14 @@ use dbtective::cli::table::{show_results_and_exit, RuleResult};
 use dbtective::core::catalog::parse_catalog::Catalog;
 use dbtective::core::config::severity::Severity;
 use dbtective::core::config::Config;
+use dbtective::core::freshness::parse_sources::FreshnessResults;
 use dbtective::core::manifest::Manifest;
 use dbtective::core::rules::catalog::apply_catalog_node_rules::apply_catalog_node_rules;
 use dbtective::core::rules::catalog::apply_catalog_source_rules::apply_catalog_source_rules;
+use dbtective::core::rules::freshness::apply_freshness_source_rules::apply_freshness_source_rules;
 use dbtective::core::rules::manifest::apply_manifest_node_rules::apply_manifest_node_rules;
 use dbtective::core::rules::manifest::apply_other_manifest_object_rules::apply_manifest_object_rules;
+use dbtective::core::rules::rule_outcomes::RuleOutcomes;
 use std::io::Write;
 use tempfile::TempDir;
 
@@ -17,6 +20,7 @@ pub struct TestEnvironment {
     pub manifest_path: std::path::PathBuf,
     pub config_path: std::path::PathBuf,
     pub catalog_path: Option<std::path::PathBuf>,
+    pub sources_path: Option<std::path::PathBuf>,
 }
 
 impl TestEnvironment {
@@ -45,6 +49,7 @@ impl TestEnvironment {
             manifest_path,
             config_path,
             catalog_path: None,
+            sources_path: None,
         }
     }
 
@@ -81,9 +86,25 @@ impl TestEnvironment {
             manifest_path,
             config_path,
             catalog_path: Some(catalog_path),
+            sources_path: None,
         }
     }
 
+    pub fn new_with_sources(manifest_json: &str, sources_json: &str, config_yaml: &str) -> Self {
+        let mut env = Self::new(manifest_json, config_yaml);
+
+        // Write sources.json
+        let sources_path = env.temp_dir.path().join("sources.json");
+        let mut sources_file =
+            std::fs::File::create(&sources_path).expect("Failed to create sources file");
+        sources_file
+            .write_all(sources_json.as_bytes())
+            .expect("Failed to write sources");
+
+        env.sources_path = Some(sources_path);
+        env
+    }
+
     pub fn run_maniest_rules(&self, verbose: bool) -> Vec<(RuleResult, Severity)> {
         let manifest = Manifest::from_file(&self.manifest_path).expect("Failed to load manifest");
         let config = Config::from_file(&self.config_path).expect("Failed to load config");
@@ -97,6 +118,7 @@ impl TestEnvironment {
 
         // Convert from Vec<(RuleResult, &Severity)> to Vec<(RuleResult, Severity)>
         findings
+            .findings
             .into_iter()
             .map(|(result, severity)| (result, severity.clone()))
             .collect()
@@ -111,7 +133,7 @@ impl TestEnvironment {
             .map(Catalog::from_file)
             .transpose()?;
 
-        let mut findings = Vec::new();
+        let mut findings = RuleOutcomes::default();
 
         if let Some(ref catalog) = catalog {
             findings.extend(apply_catalog_node_rules(
@@ -123,6 +145,28 @@ impl TestEnvironment {
         }
 
         Ok(findings
+            .findings
+            .into_iter()
+            .map(|(result, severity)| (result, severity.clone()))
+            .collect())
+    }
+
+    pub fn run_freshness_rules(&self) -> anyhow::Result<Vec<(RuleResult, Severity)>> {
+        let manifest = Manifest::from_file(&self.manifest_path)?;
+        let config = Config::from_file(&self.config_path)?;
+        let freshness = self
+            .sources_path
+            .as_ref()
+            .map(FreshnessResults::from_file)
+            .transpose()?;
+
+        let mut findings = RuleOutcomes::default();
+        if let Some(ref freshness) = freshness {
+            findings.extend(apply_freshness_source_rules(&config, freshness, &manifest)?);
+        }
+
+        Ok(findings
+            .findings
             .into_iter()
             .map(|(result, severity)| (result, severity.clone()))
             .collect())
@@ -140,7 +184,7 @@ impl TestEnvironment {
         );
 
         show_results_and_exit(
-            &findings,
+            &findings.findings,
             verbose,
             self.temp_dir.path().to_str().unwrap(),
             false,
@@ -92,6 +92,26 @@ manifest_tests:
     );
 }
 
+#[test]
+fn test_applies_to_macros_rejected_for_model_sql_rules() {
+    for rule_type in ["no_order_by", "import_ctes", "no_hardcoded_references"] {
+        let config = format!(
+            r#"
+manifest_tests:
+  - type: "{rule_type}"
+    applies_to: ["models", "macros"]
+"#
+        );
+        let temp_file = create_temp_config(&config, Some(".yml"));
+        let result = Config::from_file(temp_file.path());
+
+        assert!(
+            result.is_err(),
+            "{rule_type} should not accept macros in applies_to"
+        );
+    }
+}
+
 #[test]
 fn test_applies_to_completely_invalid_target() {
     let config = r#"
@@ -0,0 +1,304 @@
+mod common;
+
+use common::TestEnvironment;
+use dbtective::core::config::Config;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "generated_at": "2025-01-01T00:00:00.000000Z",
+    "invocation_id": "test-invocation",
+    "env": {},
+    "project_name": "test",
+    "adapter_type": "postgres",
+    "quoting": {}
+  },
+  "nodes": {
+    "model.test.orders": {
+      "database": "db",
+      "schema": "public",
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "marts/orders.sql",
+      "original_file_path": "models/marts/orders.sql",
+      "unique_id": "model.test.orders",
+      "fqn": ["test", "marts", "orders"],
+      "alias": "orders",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "raw_code": "select 1",
+      "tags": [],
+      "description": "",
+      "columns": {
+        "order_id": {
+          "name": "order_id",
+          "description": "",
+          "meta": {},
+          "tags": []
+        },
+        "customer_id": {
+          "name": "customer_id",
+          "description": "",
+          "meta": {},
+          "tags": []
+        },
+        "store_id": {
+          "name": "store_id",
+          "description": "",
+          "meta": {},
+          "tags": []
+        },
+        "product_id": {
+          "name": "product_id",
+          "description": "",
+          "meta": {},
+          "tags": []
+        },
+        "amount": {
+          "name": "amount",
+          "description": "",
+          "meta": {},
+          "tags": []
+        }
+      },
+      "meta": {},
+      "primary_key": ["order_id"],
+      "depends_on": {
+        "nodes": []
+      }
+    },
+    "model.test.customers": {
+      "database": "db",
+      "schema": "public",
+      "name": "customers",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "marts/customers.sql",
+      "original_file_path": "models/marts/customers.sql",
+      "unique_id": "model.test.customers",
+      "fqn": ["test", "marts", "customers"],
+      "alias": "customers",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "raw_code": "select 1",
+      "tags": [],
+      "description": "",
+      "columns": {
+        "customer_id": {
+          "name": "customer_id",
+          "description": "",
+          "meta": {},
+          "tags": []
+        },
+        "name": {
+          "name": "name",
+          "description": "",
+          "meta": {},
+          "tags": []
+        }
+      },
+      "meta": {},
+      "primary_key": ["customer_id"],
+      "depends_on": {
+        "nodes": []
+      }
+    },
+    "test.test.relationships_orders_customer_id": {
+      "database": "db",
+      "schema": "dbt_test__audit",
+      "name": "relationships_orders_customer_id",
+      "resource_type": "test",
+      "package_name": "test",
+      "path": "relationships_orders_customer_id.sql",
+      "original_file_path": "models/marts/schema.yml",
+      "unique_id": "test.test.relationships_orders_customer_id",
+      "fqn": ["test", "marts", "relationships_orders_customer_id"],
+      "alias": "relationships_orders_customer_id",
+      "checksum": {"name": "sha256", "checksum": "def"},
+      "raw_code": "",
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.orders"]
+      },
+      "column_name": "customer_id",
+      "attached_node": "model.test.orders",
+      "test_metadata": {
+        "name": "relationships",
+        "kwargs": {"column_name": "customer_id", "to": "ref('customers')", "field": "customer_id"}
+      }
+    },
+    "test.test.relationships_orders_store_id": {
+      "database": "db",
+      "schema": "dbt_test__audit",
+      "name": "relationships_orders_store_id",
+      "resource_type": "test",
+      "package_name": "test",
+      "path": "relationships_orders_store_id.sql",
+      "original_file_path": "models/marts/schema.yml",
+      "unique_id": "test.test.relationships_orders_store_id",
+      "fqn": ["test", "marts", "relationships_orders_store_id"],
+      "alias": "relationships_orders_store_id",
+      "checksum": {"name": "sha256", "checksum": "def"},
+      "raw_code": "",
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.orders"]
+      },
+      "column_name": "store_id",
+      "attached_node": "model.test.orders",
+      "test_metadata": {
+        "name": "relationships",
+        "kwargs": {"column_name": "store_id", "to": "ref('stores')", "field": "store_id"}
+      }
+    },
+    "test.test.relationships_where_payments_order_id": {
+      "database": "db",
+      "schema": "dbt_test__audit",
+      "name": "relationships_where_payments_order_id",
+      "resource_type": "test",
+      "package_name": "test",
+      "path": "relationships_where_payments_order_id.sql",
+      "original_file_path": "models/marts/schema.yml",
+      "unique_id": "test.test.relationships_where_payments_order_id",
+      "fqn": ["test", "marts", "relationships_where_payments_order_id"],
+      "alias": "relationships_where_payments_order_id",
+      "checksum": {"name": "sha256", "checksum": "def"},
+      "raw_code": "",
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["source.test.raw.payments"]
+      },
+      "column_name": "order_id",
+      "attached_node": "source.test.raw.payments",
+      "test_metadata": {
+        "name": "relationships_where",
+        "kwargs": {"column_name": "order_id", "to": "ref('orders')", "field": "order_id", "from_condition": "amount > 0"},
+        "namespace": "dbt_utils"
+      }
+    }
+  },
+  "sources": {
+    "source.test.raw.payments": {
+      "database": "db",
+      "schema": "raw",
+      "name": "payments",
+      "resource_type": "source",
+      "package_name": "test",
+      "path": "models/staging/sources.yml",
+      "original_file_path": "models/staging/sources.yml",
+      "unique_id": "source.test.raw.payments",
+      "fqn": ["test", "staging", "raw", "payments"],
+      "source_name": "raw",
+      "source_description": "",
+      "loader": "",
+      "identifier": "payments",
+      "columns": {
+        "payment_id": {
+          "name": "payment_id",
+          "description": "",
+          "meta": {},
+          "tags": []
+        },
+        "order_id": {
+          "name": "order_id",
+          "description": "",
+          "meta": {},
+          "tags": []
+        },
+        "customer_id": {
+          "name": "customer_id",
+          "description": "",
+          "meta": {},
+          "tags": []
+        }
+      }
+    }
+  },
+  "macros": {},
+  "exposures": {},
+  "metrics": {},
+  "groups": {},
+  "selectors": {},
+  "disabled": {},
+  "parent_map": {},
+  "child_map": {},
+  "group_map": {},
+  "saved_queries": {},
+  "semantic_models": {},
+  "unit_tests": {}
+}"#;
+
+fn messages(config: &str) -> Vec<String> {
+    let env = TestEnvironment::new(MANIFEST, config);
+    let mut messages: Vec<String> = env
+        .run_maniest_rules(false)
+        .into_iter()
+        .map(|(finding, _)| finding.message)
+        .collect();
+    messages.sort();
+    messages
+}
+
+#[test]
+fn test_missing_and_unresolved_relationships() {
+    let config = r#"
+manifest_tests:
+  - type: "has_relationship_test"
+"#;
+    // The primary keys order_id and customer_id need no relationships test
+    assert_eq!(
+        messages(config),
+        vec![
+            "orders has no relationships test on product_id and has relationships tests to relations that do not exist: store_id to ref('stores')"
+        ]
+    );
+}
+
+#[test]
+fn test_sources_and_package_tests() {
+    let config = r#"
+manifest_tests:
+  - type: "has_relationship_test"
+    applies_to: ["sources"]
+"#;
+    // payments has no primary key, the relationships_where test on order_id counts
+    assert_eq!(
+        messages(config),
+        vec!["payments has no relationships test on customer_id, payment_id"]
+    );
+}
+
+#[test]
+fn test_custom_columns_and_test_names() {
+    let config = r#"
+manifest_tests:
+  - type: "has_relationship_test"
+    applies_to: ["models", "sources"]
+    columns: ["customer_id", "order_id"]
+    test_names: ["relationships"]
+"#;
+    assert_eq!(
+        messages(config),
+        vec!["payments has no relationships test on customer_id, order_id"]
+    );
+}
+
+#[test]
+fn test_applies_to_options() {
+    let config = r#"
+manifest_tests:
+  - type: "has_relationship_test"
+    applies_to: ["exposures"]
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    assert!(Config::from_file(&env.config_path).is_err());
+}
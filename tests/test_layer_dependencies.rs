@@ -0,0 +1,261 @@
+mod common;
+
+use common::TestEnvironment;
+use dbtective::core::config::Config;
+
+// sources -> stg_customers -> int_customers -> customers -> orders
+// customers also selects from the source directly, stg_orders selects from the orders mart
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "generated_at": "2025-01-01T00:00:00.000000Z",
+    "invocation_id": "test-invocation",
+    "env": {},
+    "project_name": "test",
+    "adapter_type": "postgres",
+    "quoting": {}
+  },
+  "nodes": {
+    "model.test.stg_customers": {
+      "database": "db",
+      "schema": "public",
+      "name": "stg_customers",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "staging/stg_customers.sql",
+      "original_file_path": "models/staging/stg_customers.sql",
+      "unique_id": "model.test.stg_customers",
+      "fqn": ["test", "staging", "stg_customers"],
+      "alias": "stg_customers",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["source.test.raw.customers"]
+      }
+    },
+    "model.test.int_customers": {
+      "database": "db",
+      "schema": "public",
+      "name": "int_customers",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "intermediate/int_customers.sql",
+      "original_file_path": "models/intermediate/int_customers.sql",
+      "unique_id": "model.test.int_customers",
+      "fqn": ["test", "intermediate", "int_customers"],
+      "alias": "int_customers",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.stg_customers"]
+      }
+    },
+    "model.test.customers": {
+      "database": "db",
+      "schema": "public",
+      "name": "customers",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "marts/customers.sql",
+      "original_file_path": "models/marts/customers.sql",
+      "unique_id": "model.test.customers",
+      "fqn": ["test", "marts", "customers"],
+      "alias": "customers",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.int_customers", "source.test.raw.customers"]
+      }
+    },
+    "model.test.orders": {
+      "database": "db",
+      "schema": "public",
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "marts/orders.sql",
+      "original_file_path": "models/marts/orders.sql",
+      "unique_id": "model.test.orders",
+      "fqn": ["test", "marts", "orders"],
+      "alias": "orders",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.customers", "model.test.stg_customers"]
+      }
+    },
+    "model.test.stg_orders": {
+      "database": "db",
+      "schema": "public",
+      "name": "stg_orders",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "staging/stg_orders.sql",
+      "original_file_path": "models/staging/stg_orders.sql",
+      "unique_id": "model.test.stg_orders",
+      "fqn": ["test", "staging", "stg_orders"],
+      "alias": "stg_orders",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.orders"]
+      }
+    },
+    "model.test.scratch": {
+      "database": "db",
+      "schema": "public",
+      "name": "scratch",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "scratch.sql",
+      "original_file_path": "models/scratch.sql",
+      "unique_id": "model.test.scratch",
+      "fqn": ["test", "scratch"],
+      "alias": "scratch",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.orders"]
+      }
+    }
+  },
+  "sources": {
+    "source.test.raw.customers": {
+      "database": "db",
+      "schema": "raw",
+      "name": "customers",
+      "resource_type": "source",
+      "package_name": "test",
+      "path": "models/staging/sources.yml",
+      "original_file_path": "models/staging/sources.yml",
+      "unique_id": "source.test.raw.customers",
+      "fqn": ["test", "staging", "raw", "customers"],
+      "source_name": "raw",
+      "source_description": "",
+      "loader": "",
+      "identifier": "customers"
+    }
+  },
+  "macros": {},
+  "exposures": {},
+  "metrics": {},
+  "groups": {},
+  "selectors": {},
+  "disabled": {},
+  "parent_map": {"model.test.stg_customers": ["source.test.raw.customers"], "model.test.int_customers": ["model.test.stg_customers"], "model.test.customers": ["model.test.int_customers", "source.test.raw.customers"], "model.test.orders": ["model.test.customers", "model.test.stg_customers"], "model.test.stg_orders": ["model.test.orders"], "model.test.scratch": ["model.test.orders"]},
+  "child_map": {"model.test.stg_customers": ["model.test.int_customers", "model.test.orders"], "source.test.raw.customers": ["model.test.stg_customers", "model.test.customers"], "model.test.int_customers": ["model.test.customers"], "model.test.customers": ["model.test.orders"], "model.test.orders": ["model.test.stg_orders", "model.test.scratch"], "model.test.stg_orders": [], "model.test.scratch": []},
+  "group_map": {},
+  "saved_queries": {},
+  "semantic_models": {},
+  "unit_tests": {}
+}"#;
+
+const LAYERS: &str = r#"
+manifest_tests:
+  - name: "dag_layers"
+    type: "layer_dependencies"
+    layers:
+      - name: sources
+        resource_types: ["source"]
+      - name: staging
+        name_prefixes: ["stg_"]
+        allowed_upstream: ["sources"]
+      - name: intermediate
+        fqn_prefixes: ["test.intermediate"]
+        allowed_upstream: ["staging", "intermediate"]
+      - name: marts
+        paths: ["models/marts/**"]
+        allowed_upstream: ["staging", "intermediate", "marts"]
+"#;
+
+#[test]
+fn test_layer_dependencies() {
+    let env = TestEnvironment::new(MANIFEST, LAYERS);
+    let mut findings = env.run_maniest_rules(false);
+    findings.sort_by(|a, b| a.0.message.cmp(&b.0.message));
+
+    assert_eq!(findings.len(), 2);
+    assert_eq!(findings[0].0.rule_name, "dag_layers");
+    assert_eq!(findings[0].0.object_type, "Model");
+    assert_eq!(
+        findings[0].0.message,
+        "customers in layer 'marts' depends on customers (layer 'sources'), allowed upstream layers are: staging, intermediate, marts"
+    );
+    assert_eq!(
+        findings[0].0.relative_path,
+        Some("models/marts/customers.sql".to_string())
+    );
+    assert_eq!(
+        findings[1].0.message,
+        "stg_orders in layer 'staging' depends on orders (layer 'marts'), allowed upstream layers are: sources"
+    );
+}
+
+#[test]
+fn test_layer_dependencies_without_restrictions() {
+    // Layers without `allowed_upstream` may depend on any layer
+    let config = r#"
+manifest_tests:
+  - type: "layer_dependencies"
+    layers:
+      - name: sources
+        resource_types: ["source"]
+      - name: models
+        paths: ["models/"]
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    assert!(env.run_maniest_rules(false).is_empty());
+}
+
+#[test]
+fn test_invalid_layers_are_rejected() {
+    let unknown_upstream = r#"
+manifest_tests:
+  - type: "layer_dependencies"
+    layers:
+      - name: staging
+        name_prefixes: ["stg_"]
+        allowed_upstream: ["raw"]
+"#;
+    let env = TestEnvironment::new(MANIFEST, unknown_upstream);
+    let error = Config::from_file(&env.config_path).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("Layer 'staging' allows unknown upstream layer 'raw'"));
+
+    let without_matcher = r#"
+manifest_tests:
+  - type: "layer_dependencies"
+    layers:
+      - name: staging
+"#;
+    let env = TestEnvironment::new(MANIFEST, without_matcher);
+    assert!(Config::from_file(&env.config_path).is_err());
+
+    let no_layers = r#"
+manifest_tests:
+  - type: "layer_dependencies"
+    layers: []
+"#;
+    let env = TestEnvironment::new(MANIFEST, no_layers);
+    assert!(Config::from_file(&env.config_path).is_err());
+}
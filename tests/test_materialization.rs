@@ -0,0 +1,182 @@
+mod common;
+
+use common::TestEnvironment;
+use dbtective::core::config::Config;
+
+// dbt always renders `on_schema_change`, only `unrendered_config` shows if it was set explicitly
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "generated_at": "2025-01-01T00:00:00.000000Z",
+    "invocation_id": "test-invocation",
+    "env": {},
+    "project_name": "test",
+    "adapter_type": "postgres",
+    "quoting": {}
+  },
+  "nodes": {
+    "model.test.stg_orders": {
+      "database": "db",
+      "schema": "public",
+      "name": "stg_orders",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "staging/stg_orders.sql",
+      "original_file_path": "models/staging/stg_orders.sql",
+      "unique_id": "model.test.stg_orders",
+      "fqn": ["test", "staging", "stg_orders"],
+      "alias": "stg_orders",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "config": {"materialized": "view", "unique_key": null, "on_schema_change": "ignore"},
+      "unrendered_config": {},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": []
+      }
+    },
+    "model.test.stg_payments": {
+      "database": "db",
+      "schema": "public",
+      "name": "stg_payments",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "staging/stg_payments.sql",
+      "original_file_path": "models/staging/stg_payments.sql",
+      "unique_id": "model.test.stg_payments",
+      "fqn": ["test", "staging", "stg_payments"],
+      "alias": "stg_payments",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "config": {"materialized": "table", "unique_key": null, "on_schema_change": "ignore"},
+      "unrendered_config": {"materialized": "table"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": []
+      }
+    },
+    "model.test.orders": {
+      "database": "db",
+      "schema": "public",
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "marts/orders.sql",
+      "original_file_path": "models/marts/orders.sql",
+      "unique_id": "model.test.orders",
+      "fqn": ["test", "marts", "orders"],
+      "alias": "orders",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "config": {"materialized": "incremental", "unique_key": ["order_id", "order_date"], "on_schema_change": "append_new_columns"},
+      "unrendered_config": {"materialized": "incremental", "unique_key": ["order_id", "order_date"], "on_schema_change": "append_new_columns"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": []
+      }
+    },
+    "model.test.events": {
+      "database": "db",
+      "schema": "public",
+      "name": "events",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "marts/events.sql",
+      "original_file_path": "models/marts/events.sql",
+      "unique_id": "model.test.events",
+      "fqn": ["test", "marts", "events"],
+      "alias": "events",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "config": {"materialized": "incremental", "unique_key": null, "on_schema_change": "ignore"},
+      "unrendered_config": {"materialized": "incremental"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": []
+      }
+    }
+  },
+  "sources": {},
+  "macros": {},
+  "exposures": {},
+  "metrics": {},
+  "groups": {},
+  "selectors": {},
+  "disabled": {},
+  "parent_map": {},
+  "child_map": {},
+  "group_map": {},
+  "saved_queries": {},
+  "semantic_models": {},
+  "unit_tests": {}
+}"#;
+
+const POLICIES: &str = r#"
+manifest_tests:
+  - name: "materializations"
+    type: "materialization"
+    policies:
+      - paths: ["models/staging/**"]
+        allowed: ["view"]
+      - fqn_prefixes: ["test.marts"]
+        allowed: ["table", "incremental"]
+"#;
+
+#[test]
+fn test_materialization() {
+    let env = TestEnvironment::new(MANIFEST, POLICIES);
+    let mut findings = env.run_maniest_rules(false);
+    findings.sort_by(|a, b| a.0.message.cmp(&b.0.message));
+
+    assert_eq!(findings.len(), 2);
+    assert_eq!(findings[0].0.rule_name, "materializations");
+    assert_eq!(findings[0].0.object_type, "Model");
+    assert_eq!(
+        findings[0].0.message,
+        "events is incremental without a unique_key and an explicit on_schema_change"
+    );
+    assert_eq!(
+        findings[0].0.relative_path,
+        Some("models/marts/events.sql".to_string())
+    );
+    assert_eq!(
+        findings[1].0.message,
+        "stg_payments is materialized as 'table' (allowed: view)"
+    );
+}
+
+#[test]
+fn test_materialization_incremental_checks_can_be_disabled() {
+    let config = r#"
+manifest_tests:
+  - type: "materialization"
+    require_unique_key: false
+    require_on_schema_change: false
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    assert!(env.run_maniest_rules(false).is_empty());
+}
+
+#[test]
+fn test_invalid_policies_are_rejected() {
+    let config = r#"
+manifest_tests:
+  - type: "materialization"
+    policies:
+      - allowed: ["view"]
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    let error = Config::from_file(&env.config_path).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("Materialization policy 1 needs at least one of `paths`"));
+}
@@ -0,0 +1,213 @@
+mod common;
+
+use common::TestEnvironment;
+
+// raw.orders -> stg_orders (view) -> int_orders (ephemeral) -> int_orders_enriched (table) -> orders (view) -> orders_report (view)
+// orders_report also selects from stg_orders directly
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "generated_at": "2025-01-01T00:00:00.000000Z",
+    "invocation_id": "test-invocation",
+    "env": {},
+    "project_name": "test",
+    "adapter_type": "postgres",
+    "quoting": {}
+  },
+  "nodes": {
+    "model.test.stg_orders": {
+      "database": "db",
+      "schema": "public",
+      "name": "stg_orders",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "staging/stg_orders.sql",
+      "original_file_path": "models/staging/stg_orders.sql",
+      "unique_id": "model.test.stg_orders",
+      "fqn": ["test", "staging", "stg_orders"],
+      "alias": "stg_orders",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "config": {"materialized": "view"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["source.test.raw.orders"]
+      }
+    },
+    "model.test.int_orders": {
+      "database": "db",
+      "schema": "public",
+      "name": "int_orders",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "intermediate/int_orders.sql",
+      "original_file_path": "models/intermediate/int_orders.sql",
+      "unique_id": "model.test.int_orders",
+      "fqn": ["test", "intermediate", "int_orders"],
+      "alias": "int_orders",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "config": {"materialized": "ephemeral"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.stg_orders"]
+      }
+    },
+    "model.test.int_orders_enriched": {
+      "database": "db",
+      "schema": "public",
+      "name": "int_orders_enriched",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "intermediate/int_orders_enriched.sql",
+      "original_file_path": "models/intermediate/int_orders_enriched.sql",
+      "unique_id": "model.test.int_orders_enriched",
+      "fqn": ["test", "intermediate", "int_orders_enriched"],
+      "alias": "int_orders_enriched",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "config": {"materialized": "table"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.int_orders"]
+      }
+    },
+    "model.test.orders": {
+      "database": "db",
+      "schema": "public",
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "marts/orders.sql",
+      "original_file_path": "models/marts/orders.sql",
+      "unique_id": "model.test.orders",
+      "fqn": ["test", "marts", "orders"],
+      "alias": "orders",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "config": {"materialized": "view"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.int_orders_enriched"]
+      }
+    },
+    "model.test.orders_report": {
+      "database": "db",
+      "schema": "public",
+      "name": "orders_report",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "marts/orders_report.sql",
+      "original_file_path": "models/marts/orders_report.sql",
+      "unique_id": "model.test.orders_report",
+      "fqn": ["test", "marts", "orders_report"],
+      "alias": "orders_report",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "config": {"materialized": "view"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.orders", "model.test.stg_orders"]
+      }
+    }
+  },
+  "sources": {},
+  "macros": {},
+  "exposures": {},
+  "metrics": {},
+  "groups": {},
+  "selectors": {},
+  "disabled": {},
+  "parent_map": {"model.test.stg_orders": ["source.test.raw.orders"], "model.test.int_orders": ["model.test.stg_orders"], "model.test.int_orders_enriched": ["model.test.int_orders"], "model.test.orders": ["model.test.int_orders_enriched"], "model.test.orders_report": ["model.test.orders", "model.test.stg_orders"]},
+  "child_map": {"model.test.stg_orders": ["model.test.int_orders", "model.test.orders_report"], "model.test.int_orders": ["model.test.int_orders_enriched"], "model.test.int_orders_enriched": ["model.test.orders"], "model.test.orders": ["model.test.orders_report"], "model.test.orders_report": []},
+  "group_map": {},
+  "saved_queries": {},
+  "semantic_models": {},
+  "unit_tests": {}
+}"#;
+
+#[test]
+fn test_max_chain_depth() {
+    let config = r#"
+manifest_tests:
+  - name: "dag_depth"
+    type: "max_chain_depth"
+    max_depth: 4
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].0.rule_name, "dag_depth");
+    assert_eq!(findings[0].0.object_type, "Model");
+    assert_eq!(
+        findings[0].0.message,
+        "orders_report has a chain depth of 5 (stg_orders -> int_orders -> int_orders_enriched -> orders -> orders_report), the maximum is 4"
+    );
+    assert_eq!(
+        findings[0].0.relative_path,
+        Some("models/marts/orders_report.sql".to_string())
+    );
+}
+
+#[test]
+fn test_max_chain_depth_of_views() {
+    // The table ends the chain of views and ephemeral models
+    let config = r#"
+manifest_tests:
+  - type: "max_chain_depth"
+    max_depth: 1
+    materializations: ["view", "ephemeral"]
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    let mut messages: Vec<String> = env
+        .run_maniest_rules(false)
+        .into_iter()
+        .map(|(finding, _)| finding.message)
+        .collect();
+    messages.sort();
+
+    assert_eq!(
+        messages,
+        vec![
+            "int_orders has a chain depth of 2 (stg_orders -> int_orders), the maximum is 1",
+            // The table itself is not part of the chain
+            "int_orders_enriched has a chain depth of 2 (stg_orders -> int_orders), the maximum is 1",
+            "orders_report has a chain depth of 2 (orders -> orders_report), the maximum is 1",
+        ]
+    );
+}
+
+#[test]
+fn test_max_chain_depth_excluded_model_is_not_counted() {
+    // int_orders_enriched is a table, so it doesn't add to the depth of the views above it
+    let config = r#"
+manifest_tests:
+  - type: "max_chain_depth"
+    max_depth: 2
+    materializations: ["view", "ephemeral"]
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    assert!(env.run_maniest_rules(false).is_empty());
+}
+
+#[test]
+fn test_max_chain_depth_default() {
+    let config = r#"
+manifest_tests:
+  - type: "max_chain_depth"
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    assert!(env.run_maniest_rules(false).is_empty());
+}
@@ -0,0 +1,224 @@
+mod common;
+
+use common::TestEnvironment;
+use dbtective::core::config::Config;
+
+// orders has 3 parents (two staging models and a source) and 3 children (two models and a test)
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "generated_at": "2025-01-01T00:00:00.000000Z",
+    "invocation_id": "test-invocation",
+    "env": {},
+    "project_name": "test",
+    "adapter_type": "postgres",
+    "quoting": {}
+  },
+  "nodes": {
+    "model.test.stg_customers": {
+      "database": "db",
+      "schema": "public",
+      "name": "stg_customers",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "staging/stg_customers.sql",
+      "original_file_path": "models/staging/stg_customers.sql",
+      "unique_id": "model.test.stg_customers",
+      "fqn": ["test", "staging", "stg_customers"],
+      "alias": "stg_customers",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["source.test.raw.customers"]
+      }
+    },
+    "model.test.stg_orders": {
+      "database": "db",
+      "schema": "public",
+      "name": "stg_orders",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "staging/stg_orders.sql",
+      "original_file_path": "models/staging/stg_orders.sql",
+      "unique_id": "model.test.stg_orders",
+      "fqn": ["test", "staging", "stg_orders"],
+      "alias": "stg_orders",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["source.test.raw.orders"]
+      }
+    },
+    "model.test.orders": {
+      "database": "db",
+      "schema": "public",
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "marts/orders.sql",
+      "original_file_path": "models/marts/orders.sql",
+      "unique_id": "model.test.orders",
+      "fqn": ["test", "marts", "orders"],
+      "alias": "orders",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.stg_customers", "model.test.stg_orders", "source.test.raw.payments"]
+      }
+    },
+    "model.test.orders_daily": {
+      "database": "db",
+      "schema": "public",
+      "name": "orders_daily",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "marts/orders_daily.sql",
+      "original_file_path": "models/marts/orders_daily.sql",
+      "unique_id": "model.test.orders_daily",
+      "fqn": ["test", "marts", "orders_daily"],
+      "alias": "orders_daily",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.orders"]
+      }
+    },
+    "model.test.orders_weekly": {
+      "database": "db",
+      "schema": "public",
+      "name": "orders_weekly",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "marts/orders_weekly.sql",
+      "original_file_path": "models/marts/orders_weekly.sql",
+      "unique_id": "model.test.orders_weekly",
+      "fqn": ["test", "marts", "orders_weekly"],
+      "alias": "orders_weekly",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.orders"]
+      }
+    }
+  },
+  "sources": {},
+  "macros": {},
+  "exposures": {},
+  "metrics": {},
+  "groups": {},
+  "selectors": {},
+  "disabled": {},
+  "parent_map": {"model.test.stg_customers": ["source.test.raw.customers"], "model.test.stg_orders": ["source.test.raw.orders"], "model.test.orders": ["model.test.stg_customers", "model.test.stg_orders", "source.test.raw.payments"], "model.test.orders_daily": ["model.test.orders"], "model.test.orders_weekly": ["model.test.orders"]},
+  "child_map": {"model.test.stg_customers": ["model.test.orders"], "model.test.stg_orders": ["model.test.orders"], "model.test.orders": ["model.test.orders_daily", "model.test.orders_weekly", "test.test.unique_orders_order_id"], "model.test.orders_daily": [], "model.test.orders_weekly": []},
+  "group_map": {},
+  "saved_queries": {},
+  "semantic_models": {},
+  "unit_tests": {}
+}"#;
+
+#[test]
+fn test_max_children() {
+    let config = r#"
+manifest_tests:
+  - name: "fan_out"
+    type: "max_children"
+    max_children: 1
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+
+    // Tests are not counted by default
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].0.rule_name, "fan_out");
+    assert_eq!(findings[0].0.object_type, "Model");
+    assert_eq!(
+        findings[0].0.message,
+        "orders has 2 direct children, the maximum is 1"
+    );
+    assert_eq!(
+        findings[0].0.relative_path,
+        Some("models/marts/orders.sql".to_string())
+    );
+
+    let config = r#"
+manifest_tests:
+  - type: "max_children"
+    max_children: 2
+    resource_types: ["model", "test"]
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+    assert_eq!(findings.len(), 1);
+    assert_eq!(
+        findings[0].0.message,
+        "orders has 3 direct children, the maximum is 2"
+    );
+}
+
+#[test]
+fn test_max_parents() {
+    let config = r#"
+manifest_tests:
+  - name: "fan_in"
+    type: "max_parents"
+    max_parents: 2
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+    assert_eq!(findings.len(), 1);
+    assert_eq!(
+        findings[0].0.message,
+        "orders has 3 direct parents, the maximum is 2"
+    );
+
+    // Only count models, the source parent of orders is ignored
+    let config = r#"
+manifest_tests:
+  - type: "max_parents"
+    max_parents: 2
+    resource_types: ["model"]
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    assert!(env.run_maniest_rules(false).is_empty());
+}
+
+#[test]
+fn test_default_thresholds() {
+    let config = r#"
+manifest_tests:
+  - type: "max_children"
+  - type: "max_parents"
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    assert!(env.run_maniest_rules(false).is_empty());
+}
+
+#[test]
+fn test_unknown_resource_type_is_rejected() {
+    let config = r#"
+manifest_tests:
+  - type: "max_children"
+    resource_types: ["models"]
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    let error = Config::from_file(&env.config_path).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("Unknown resource type 'models' in `resource_types`"));
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use common::TestEnvironment;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "generated_at": "2025-01-01T00:00:00.000000Z",
+    "invocation_id": "test-invocation",
+    "env": {},
+    "project_name": "test",
+    "adapter_type": "postgres",
+    "quoting": {}
+  },
+  "nodes": {
+    "model.test.orders": {
+      "database": "db",
+      "schema": "public",
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "marts/orders.sql",
+      "original_file_path": "models/marts/orders.sql",
+      "unique_id": "model.test.orders",
+      "fqn": ["test", "marts", "orders"],
+      "alias": "orders",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "raw_code": "with payments as (\n    select * from {{ ref('stg_payments') }}\n)\n\nselect *\nfrom analytics.prod.orders o\nleft join payments p on o.id = p.order_id\nleft join prod.customers c on o.customer_id = c.id",
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": []
+      }
+    },
+    "model.test.customers": {
+      "database": "db",
+      "schema": "public",
+      "name": "customers",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "marts/customers.sql",
+      "original_file_path": "models/marts/customers.sql",
+      "unique_id": "model.test.customers",
+      "fqn": ["test", "marts", "customers"],
+      "alias": "customers",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "raw_code": "-- previously: select * from analytics.prod.customers\nselect * from {{ ref('stg_customers') }}",
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": []
+      }
+    }
+  },
+  "sources": {},
+  "macros": {},
+  "exposures": {},
+  "metrics": {},
+  "groups": {},
+  "selectors": {},
+  "disabled": {},
+  "parent_map": {},
+  "child_map": {},
+  "group_map": {},
+  "saved_queries": {},
+  "semantic_models": {},
+  "unit_tests": {}
+}"#;
+
+#[test]
+fn test_no_hardcoded_references() {
+    let config = r#"
+manifest_tests:
+  - name: "use_ref_or_source"
+    type: "no_hardcoded_references"
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+
+    // Commented out references are ignored
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].0.rule_name, "use_ref_or_source");
+    assert_eq!(findings[0].0.object_type, "Model");
+    assert_eq!(
+        findings[0].0.message,
+        "orders has hard-coded relation references: analytics.prod.orders (line 6), prod.customers (line 8), use ref() or source() instead"
+    );
+    assert_eq!(
+        findings[0].0.relative_path,
+        Some("models/marts/orders.sql".to_string())
+    );
+    assert_eq!(findings[0].0.line, Some(6));
+    assert_eq!(findings[0].0.column, Some(6));
+}
@@ -0,0 +1,198 @@
+mod common;
+
+use common::TestEnvironment;
+
+// customers selects from int_customers, but also from stg_customers and raw.customers which int_customers is built on.
+// orders selects from two independent branches and has no redundant dependencies.
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "generated_at": "2025-01-01T00:00:00.000000Z",
+    "invocation_id": "test-invocation",
+    "env": {},
+    "project_name": "test",
+    "adapter_type": "postgres",
+    "quoting": {}
+  },
+  "nodes": {
+    "model.test.stg_customers": {
+      "database": "db",
+      "schema": "public",
+      "name": "stg_customers",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "staging/stg_customers.sql",
+      "original_file_path": "models/staging/stg_customers.sql",
+      "unique_id": "model.test.stg_customers",
+      "fqn": ["test", "staging", "stg_customers"],
+      "alias": "stg_customers",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["source.test.raw.customers"]
+      }
+    },
+    "model.test.stg_orders": {
+      "database": "db",
+      "schema": "public",
+      "name": "stg_orders",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "staging/stg_orders.sql",
+      "original_file_path": "models/staging/stg_orders.sql",
+      "unique_id": "model.test.stg_orders",
+      "fqn": ["test", "staging", "stg_orders"],
+      "alias": "stg_orders",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["source.test.raw.orders"]
+      }
+    },
+    "model.test.int_customers": {
+      "database": "db",
+      "schema": "public",
+      "name": "int_customers",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "intermediate/int_customers.sql",
+      "original_file_path": "models/intermediate/int_customers.sql",
+      "unique_id": "model.test.int_customers",
+      "fqn": ["test", "intermediate", "int_customers"],
+      "alias": "int_customers",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.stg_customers"]
+      }
+    },
+    "model.test.customers": {
+      "database": "db",
+      "schema": "public",
+      "name": "customers",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "marts/customers.sql",
+      "original_file_path": "models/marts/customers.sql",
+      "unique_id": "model.test.customers",
+      "fqn": ["test", "marts", "customers"],
+      "alias": "customers",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.int_customers", "model.test.stg_customers", "source.test.raw.customers"]
+      }
+    },
+    "model.test.orders": {
+      "database": "db",
+      "schema": "public",
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "marts/orders.sql",
+      "original_file_path": "models/marts/orders.sql",
+      "unique_id": "model.test.orders",
+      "fqn": ["test", "marts", "orders"],
+      "alias": "orders",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.stg_orders", "model.test.customers"]
+      }
+    }
+  },
+  "sources": {
+    "source.test.raw.customers": {
+      "database": "db",
+      "schema": "raw",
+      "name": "customers",
+      "resource_type": "source",
+      "package_name": "test",
+      "path": "models/staging/sources.yml",
+      "original_file_path": "models/staging/sources.yml",
+      "unique_id": "source.test.raw.customers",
+      "fqn": ["test", "staging", "raw", "customers"],
+      "source_name": "raw",
+      "source_description": "",
+      "loader": "",
+      "identifier": "customers"
+    },
+    "source.test.raw.orders": {
+      "database": "db",
+      "schema": "raw",
+      "name": "orders",
+      "resource_type": "source",
+      "package_name": "test",
+      "path": "models/staging/sources.yml",
+      "original_file_path": "models/staging/sources.yml",
+      "unique_id": "source.test.raw.orders",
+      "fqn": ["test", "staging", "raw", "orders"],
+      "source_name": "raw",
+      "source_description": "",
+      "loader": "",
+      "identifier": "orders"
+    }
+  },
+  "macros": {},
+  "exposures": {},
+  "metrics": {},
+  "groups": {},
+  "selectors": {},
+  "disabled": {},
+  "parent_map": {"model.test.stg_customers": ["source.test.raw.customers"], "model.test.stg_orders": ["source.test.raw.orders"], "model.test.int_customers": ["model.test.stg_customers"], "model.test.customers": ["model.test.int_customers", "model.test.stg_customers", "source.test.raw.customers"], "model.test.orders": ["model.test.stg_orders", "model.test.customers"], "source.test.raw.customers": [], "source.test.raw.orders": []},
+  "child_map": {"source.test.raw.customers": ["model.test.stg_customers", "model.test.customers"], "source.test.raw.orders": ["model.test.stg_orders"], "model.test.stg_customers": ["model.test.int_customers", "model.test.customers"], "model.test.stg_orders": ["model.test.orders"], "model.test.int_customers": ["model.test.customers"], "model.test.customers": ["model.test.orders"], "model.test.orders": []},
+  "group_map": {},
+  "saved_queries": {},
+  "semantic_models": {},
+  "unit_tests": {}
+}"#;
+
+#[test]
+fn test_no_redundant_dependencies() {
+    let config = r#"
+manifest_tests:
+  - name: "no_rejoins"
+    type: "no_redundant_dependencies"
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].0.rule_name, "no_rejoins");
+    assert_eq!(findings[0].0.object_type, "Model");
+    assert_eq!(
+        findings[0].0.message,
+        "customers has redundant dependencies on stg_customers (already via stg_customers -> int_customers -> customers), raw.customers (already via raw.customers -> stg_customers -> customers)"
+    );
+    assert_eq!(
+        findings[0].0.relative_path,
+        Some("models/marts/customers.sql".to_string())
+    );
+}
+
+#[test]
+fn test_no_redundant_dependencies_respects_includes() {
+    let config = r#"
+manifest_tests:
+  - type: "no_redundant_dependencies"
+    excludes: ["models/marts/customers.sql"]
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    assert!(env.run_maniest_rules(false).is_empty());
+}
@@ -0,0 +1,311 @@
+mod common;
+
+use common::TestEnvironment;
+use dbtective::core::config::Config;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "generated_at": "2025-01-01T00:00:00.000000Z",
+    "invocation_id": "test-invocation",
+    "env": {},
+    "project_name": "test",
+    "adapter_type": "postgres",
+    "quoting": {}
+  },
+  "nodes": {
+    "model.test.orders": {
+      "database": "db",
+      "schema": "public",
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "marts/orders.sql",
+      "original_file_path": "models/marts/orders.sql",
+      "unique_id": "model.test.orders",
+      "fqn": ["test", "marts", "orders"],
+      "alias": "orders",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "raw_code": "select 1",
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "primary_key": ["order_id"],
+      "depends_on": {
+        "nodes": []
+      }
+    },
+    "model.test.order_items": {
+      "database": "db",
+      "schema": "public",
+      "name": "order_items",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "marts/order_items.sql",
+      "original_file_path": "models/marts/order_items.sql",
+      "unique_id": "model.test.order_items",
+      "fqn": ["test", "marts", "order_items"],
+      "alias": "order_items",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "raw_code": "select 1",
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "primary_key": [],
+      "depends_on": {
+        "nodes": []
+      }
+    },
+    "model.test.customers": {
+      "database": "db",
+      "schema": "public",
+      "name": "customers",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "marts/customers.sql",
+      "original_file_path": "models/marts/customers.sql",
+      "unique_id": "model.test.customers",
+      "fqn": ["test", "marts", "customers"],
+      "alias": "customers",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "raw_code": "select 1",
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {"primary_key": "customer_id"},
+      "primary_key": [],
+      "depends_on": {
+        "nodes": []
+      }
+    },
+    "test.test.unique_orders_order_id": {
+      "database": "db",
+      "schema": "dbt_test__audit",
+      "name": "unique_orders_order_id",
+      "resource_type": "test",
+      "package_name": "test",
+      "path": "unique_orders_order_id.sql",
+      "original_file_path": "models/marts/schema.yml",
+      "unique_id": "test.test.unique_orders_order_id",
+      "fqn": ["test", "marts", "unique_orders_order_id"],
+      "alias": "unique_orders_order_id",
+      "checksum": {"name": "sha256", "checksum": "def"},
+      "raw_code": "",
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.orders"]
+      },
+      "column_name": "order_id",
+      "attached_node": "model.test.orders",
+      "test_metadata": {
+        "name": "unique",
+        "kwargs": {"column_name": "order_id"}
+      }
+    },
+    "test.test.not_null_orders_order_id": {
+      "database": "db",
+      "schema": "dbt_test__audit",
+      "name": "not_null_orders_order_id",
+      "resource_type": "test",
+      "package_name": "test",
+      "path": "not_null_orders_order_id.sql",
+      "original_file_path": "models/marts/schema.yml",
+      "unique_id": "test.test.not_null_orders_order_id",
+      "fqn": ["test", "marts", "not_null_orders_order_id"],
+      "alias": "not_null_orders_order_id",
+      "checksum": {"name": "sha256", "checksum": "def"},
+      "raw_code": "",
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.orders"]
+      },
+      "column_name": "order_id",
+      "attached_node": "model.test.orders",
+      "test_metadata": {
+        "name": "not_null",
+        "kwargs": {"column_name": "order_id"}
+      }
+    },
+    "test.test.unique_combination_order_items": {
+      "database": "db",
+      "schema": "dbt_test__audit",
+      "name": "unique_combination_order_items",
+      "resource_type": "test",
+      "package_name": "test",
+      "path": "unique_combination_order_items.sql",
+      "original_file_path": "models/marts/schema.yml",
+      "unique_id": "test.test.unique_combination_order_items",
+      "fqn": ["test", "marts", "unique_combination_order_items"],
+      "alias": "unique_combination_order_items",
+      "checksum": {"name": "sha256", "checksum": "def"},
+      "raw_code": "",
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.order_items"]
+      },
+      "column_name": null,
+      "attached_node": "model.test.order_items",
+      "test_metadata": {
+        "name": "unique_combination_of_columns",
+        "kwargs": {"combination_of_columns": ["order_id", "item_id"]},
+        "namespace": "dbt_utils"
+      }
+    },
+    "test.test.not_null_order_items_order_id": {
+      "database": "db",
+      "schema": "dbt_test__audit",
+      "name": "not_null_order_items_order_id",
+      "resource_type": "test",
+      "package_name": "test",
+      "path": "not_null_order_items_order_id.sql",
+      "original_file_path": "models/marts/schema.yml",
+      "unique_id": "test.test.not_null_order_items_order_id",
+      "fqn": ["test", "marts", "not_null_order_items_order_id"],
+      "alias": "not_null_order_items_order_id",
+      "checksum": {"name": "sha256", "checksum": "def"},
+      "raw_code": "",
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.order_items"]
+      },
+      "column_name": "order_id",
+      "attached_node": "model.test.order_items",
+      "test_metadata": {
+        "name": "not_null",
+        "kwargs": {"column_name": "order_id"}
+      }
+    },
+    "test.test.unique_customers_email": {
+      "database": "db",
+      "schema": "dbt_test__audit",
+      "name": "unique_customers_email",
+      "resource_type": "test",
+      "package_name": "test",
+      "path": "unique_customers_email.sql",
+      "original_file_path": "models/marts/schema.yml",
+      "unique_id": "test.test.unique_customers_email",
+      "fqn": ["test", "marts", "unique_customers_email"],
+      "alias": "unique_customers_email",
+      "checksum": {"name": "sha256", "checksum": "def"},
+      "raw_code": "",
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.customers"]
+      },
+      "column_name": "email",
+      "attached_node": "model.test.customers",
+      "test_metadata": {
+        "name": "unique",
+        "kwargs": {"column_name": "email"}
+      }
+    }
+  },
+  "sources": {},
+  "macros": {},
+  "exposures": {},
+  "metrics": {},
+  "groups": {},
+  "selectors": {},
+  "disabled": {},
+  "parent_map": {},
+  "child_map": {},
+  "group_map": {},
+  "saved_queries": {},
+  "semantic_models": {},
+  "unit_tests": {}
+}"#;
+
+fn messages(config: &str) -> Vec<String> {
+    let env = TestEnvironment::new(MANIFEST, config);
+    let mut messages: Vec<String> = env
+        .run_maniest_rules(false)
+        .into_iter()
+        .map(|(finding, _)| finding.message)
+        .collect();
+    messages.sort();
+    messages
+}
+
+#[test]
+fn test_primary_key_tested() {
+    let config = r#"
+manifest_tests:
+  - type: "primary_key_tested"
+"#;
+    assert_eq!(
+        messages(config),
+        vec![
+            "customers has no primary key, define one with a primary key constraint, unique and not_null tests",
+            "order_items primary key (order_id, item_id) is missing tests: not_null on item_id",
+        ]
+    );
+}
+
+#[test]
+fn test_primary_key_from_meta_key() {
+    let config = r#"
+manifest_tests:
+  - name: "pk_tested"
+    type: "primary_key_tested"
+    meta_key: "primary_key"
+    includes: ["models/marts/customers.sql"]
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+
+    // The unique test on email is not on the primary key
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].0.rule_name, "pk_tested");
+    assert_eq!(
+        findings[0].0.message,
+        "customers primary key (customer_id) is missing tests: a uniqueness test, not_null on customer_id"
+    );
+    assert_eq!(
+        findings[0].0.relative_path,
+        Some("models/marts/customers.sql".to_string())
+    );
+}
+
+#[test]
+fn test_custom_test_names() {
+    let config = r#"
+manifest_tests:
+  - type: "primary_key_tested"
+    includes: ["models/marts/orders.sql"]
+    unique_test_names: ["dbt_utils.unique_combination_of_columns"]
+    not_null_test_names: ["not_null", "dbt_utils.not_null_proportion"]
+"#;
+    assert_eq!(
+        messages(config),
+        vec!["orders primary key (order_id) is missing tests: a uniqueness test"]
+    );
+}
+
+#[test]
+fn test_applies_to_options() {
+    let config = r#"
+manifest_tests:
+  - type: "primary_key_tested"
+    applies_to: ["macros"]
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    assert!(Config::from_file(&env.config_path).is_err());
+}
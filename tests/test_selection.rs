@@ -0,0 +1,441 @@
+mod common;
+
+use common::TestEnvironment;
+use dbtective::cli::table::RuleResult;
+use dbtective::core::config::severity::Severity;
+use dbtective::core::config::Config;
+use dbtective::core::manifest::Manifest;
+use dbtective::core::rules::manifest::apply_manifest_node_rules::apply_manifest_node_rules;
+
+// stg_orders -> orders -> orders_report, none of them have a description
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "generated_at": "2025-01-01T00:00:00.000000Z",
+    "invocation_id": "test-invocation",
+    "env": {},
+    "project_name": "test_project",
+    "adapter_type": "postgres",
+    "quoting": {
+      "database": true,
+      "schema": true,
+      "identifier": true,
+      "column": null
+    }
+  },
+  "nodes": {
+    "model.test_project.stg_orders": {
+      "database": "analytics",
+      "schema": "public",
+      "name": "stg_orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "path": "staging/stg_orders.sql",
+      "original_file_path": "models/staging/stg_orders.sql",
+      "unique_id": "model.test_project.stg_orders",
+      "fqn": [
+        "test_project",
+        "staging",
+        "stg_orders"
+      ],
+      "alias": "stg_orders",
+      "checksum": {
+        "name": "sha256",
+        "checksum": "abc123"
+      },
+      "config": {
+        "enabled": true,
+        "materialized": "table",
+        "tags": [
+          "staging"
+        ]
+      },
+      "tags": [
+        "staging"
+      ],
+      "description": "",
+      "columns": {
+        "customer_id": {
+          "name": "customer_id",
+          "description": "Primary key for customers",
+          "meta": {},
+          "data_type": "integer",
+          "constraints": [],
+          "tags": []
+        }
+      },
+      "meta": {},
+      "group": null,
+      "docs": {
+        "show": true
+      },
+      "patch_path": null,
+      "compiled_path": null,
+      "build_path": null,
+      "deferred": false,
+      "unrendered_config": {},
+      "created_at": 1704067200.0,
+      "config_call_dict": {},
+      "relation_name": "analytics.public.stg_orders",
+      "raw_code": "select * from raw_customers",
+      "language": "sql",
+      "refs": [],
+      "sources": [],
+      "metrics": [],
+      "depends_on": {
+        "macros": [],
+        "nodes": []
+      },
+      "compiled_code": null,
+      "extra_ctes_injected": false,
+      "extra_ctes": [],
+      "contract": {
+        "enforced": false,
+        "checksum": null
+      }
+    },
+    "model.test_project.orders": {
+      "database": "analytics",
+      "schema": "public",
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "path": "marts/orders.sql",
+      "original_file_path": "models/marts/orders.sql",
+      "unique_id": "model.test_project.orders",
+      "fqn": [
+        "test_project",
+        "marts",
+        "orders"
+      ],
+      "alias": "orders",
+      "checksum": {
+        "name": "sha256",
+        "checksum": "abc123"
+      },
+      "config": {
+        "enabled": true,
+        "materialized": "table",
+        "tags": [
+          "finance"
+        ]
+      },
+      "tags": [
+        "finance"
+      ],
+      "description": "",
+      "columns": {
+        "customer_id": {
+          "name": "customer_id",
+          "description": "Primary key for customers",
+          "meta": {},
+          "data_type": "integer",
+          "constraints": [],
+          "tags": []
+        }
+      },
+      "meta": {},
+      "group": null,
+      "docs": {
+        "show": true
+      },
+      "patch_path": null,
+      "compiled_path": null,
+      "build_path": null,
+      "deferred": false,
+      "unrendered_config": {},
+      "created_at": 1704067200.0,
+      "config_call_dict": {},
+      "relation_name": "analytics.public.orders",
+      "raw_code": "select * from raw_customers",
+      "language": "sql",
+      "refs": [],
+      "sources": [],
+      "metrics": [],
+      "depends_on": {
+        "macros": [],
+        "nodes": []
+      },
+      "compiled_code": null,
+      "extra_ctes_injected": false,
+      "extra_ctes": [],
+      "contract": {
+        "enforced": false,
+        "checksum": null
+      }
+    },
+    "model.test_project.orders_report": {
+      "database": "analytics",
+      "schema": "public",
+      "name": "orders_report",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "path": "marts/orders_report.sql",
+      "original_file_path": "models/marts/orders_report.sql",
+      "unique_id": "model.test_project.orders_report",
+      "fqn": [
+        "test_project",
+        "marts",
+        "orders_report"
+      ],
+      "alias": "orders_report",
+      "checksum": {
+        "name": "sha256",
+        "checksum": "abc123"
+      },
+      "config": {
+        "enabled": true,
+        "materialized": "table",
+        "tags": [
+          "finance"
+        ]
+      },
+      "tags": [
+        "finance"
+      ],
+      "description": "",
+      "columns": {
+        "customer_id": {
+          "name": "customer_id",
+          "description": "Primary key for customers",
+          "meta": {},
+          "data_type": "integer",
+          "constraints": [],
+          "tags": []
+        }
+      },
+      "meta": {},
+      "group": null,
+      "docs": {
+        "show": true
+      },
+      "patch_path": null,
+      "compiled_path": null,
+      "build_path": null,
+      "deferred": false,
+      "unrendered_config": {},
+      "created_at": 1704067200.0,
+      "config_call_dict": {},
+      "relation_name": "analytics.public.orders_report",
+      "raw_code": "select * from raw_customers",
+      "language": "sql",
+      "refs": [],
+      "sources": [],
+      "metrics": [],
+      "depends_on": {
+        "macros": [],
+        "nodes": []
+      },
+      "compiled_code": null,
+      "extra_ctes_injected": false,
+      "extra_ctes": [],
+      "contract": {
+        "enforced": false,
+        "checksum": null
+      }
+    }
+  },
+  "sources": {},
+  "macros": {},
+  "exposures": {},
+  "metrics": {},
+  "groups": {},
+  "selectors": {},
+  "disabled": {},
+  "parent_map": {
+    "model.test_project.stg_orders": [],
+    "model.test_project.orders": [
+      "model.test_project.stg_orders"
+    ],
+    "model.test_project.orders_report": [
+      "model.test_project.orders"
+    ]
+  },
+  "child_map": {
+    "model.test_project.stg_orders": [
+      "model.test_project.orders"
+    ],
+    "model.test_project.orders": [
+      "model.test_project.orders_report"
+    ],
+    "model.test_project.orders_report": []
+  },
+  "group_map": {},
+  "saved_queries": {},
+  "semantic_models": {},
+  "unit_tests": {}
+}"#;
+
+fn names(findings: &[(RuleResult, Severity)]) -> Vec<String> {
+    let mut names: Vec<String> = findings
+        .iter()
+        .map(|(result, _)| result.unique_id.clone().unwrap())
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn test_rule_select_with_graph_operator() {
+    let config = r#"
+manifest_tests:
+  - name: "upstream_of_orders_has_description"
+    type: "has_description"
+    select: ["+orders"]
+    exclude: ["tag:staging"]
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+
+    assert_eq!(names(&findings), vec!["model.test_project.orders"]);
+}
+
+#[test]
+fn test_rule_select_intersection() {
+    let config = r#"
+manifest_tests:
+  - name: "finance_marts_have_description"
+    type: "has_description"
+    select: ["tag:finance,path:models/marts", "test_project.staging"]
+    exclude: ["orders_report"]
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+
+    assert_eq!(
+        names(&findings),
+        vec!["model.test_project.orders", "model.test_project.stg_orders"]
+    );
+}
+
+#[test]
+fn test_rule_select_in_toml_config() {
+    let env = TestEnvironment::new(MANIFEST, "");
+    let config_path = env.temp_dir.path().join("dbtective.toml");
+    std::fs::write(
+        &config_path,
+        r#"
+[[manifest_tests]]
+type = "has_description"
+select = ["orders+"]
+"#,
+    )
+    .unwrap();
+
+    let manifest = Manifest::from_file(&env.manifest_path).expect("Failed to load manifest");
+    let config = Config::from_file(&config_path).expect("Failed to load config");
+    let outcomes =
+        apply_manifest_node_rules(&manifest, &config, false).expect("Failed to apply node rules");
+
+    assert_eq!(outcomes.findings.len(), 2);
+}
+
+#[test]
+fn test_invalid_rule_selector_is_rejected() {
+    let config = r#"
+manifest_tests:
+  - type: "has_description"
+    select: ["unknown_method:value"]
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+
+    assert!(Config::from_file(&env.config_path).is_err());
+}
+
+const SELECTORS: &str = r#""selectors": {
+    "finance_models": {
+      "name": "finance_models",
+      "default": false,
+      "definition": {
+        "union": [
+          {"method": "tag", "value": "finance"},
+          {"exclude": [{"method": "fqn", "value": "orders_report"}]}
+        ]
+      }
+    },
+    "nightly_marts": {
+      "name": "nightly_marts",
+      "default": false,
+      "definition": {
+        "intersection": [
+          {"method": "selector", "value": "nightly"},
+          {"method": "path", "value": "models/marts"},
+          {"exclude": [{"method": "selector", "value": "finance_models"}]}
+        ]
+      }
+    },
+    "nightly": {
+      "name": "nightly",
+      "default": false,
+      "definition": "+orders_report"
+    }
+  }"#;
+
+fn manifest_with_selectors() -> String {
+    MANIFEST.replace(r#""selectors": {}"#, SELECTORS)
+}
+
+#[test]
+fn test_rule_selector() {
+    let config = r#"
+manifest_tests:
+  - type: "has_description"
+    selector: "finance_models"
+"#;
+    let env = TestEnvironment::new(&manifest_with_selectors(), config);
+    let findings = env.run_maniest_rules(false);
+
+    assert_eq!(names(&findings), vec!["model.test_project.orders"]);
+}
+
+#[test]
+fn test_rule_selector_referencing_selector() {
+    let config = r#"
+manifest_tests:
+  - type: "has_description"
+    selector: "nightly_marts"
+"#;
+    let env = TestEnvironment::new(&manifest_with_selectors(), config);
+    let findings = env.run_maniest_rules(false);
+
+    assert_eq!(names(&findings), vec!["model.test_project.orders_report"]);
+}
+
+#[test]
+fn test_rule_selector_combined_with_select() {
+    let config = r#"
+manifest_tests:
+  - type: "has_description"
+    selector: "nightly"
+    select: ["tag:finance"]
+"#;
+    let env = TestEnvironment::new(&manifest_with_selectors(), config);
+    let findings = env.run_maniest_rules(false);
+
+    assert_eq!(
+        names(&findings),
+        vec![
+            "model.test_project.orders",
+            "model.test_project.orders_report"
+        ]
+    );
+}
+
+#[test]
+fn test_unknown_rule_selector_is_an_error() {
+    let config = r#"
+manifest_tests:
+  - type: "has_description"
+    selector: "does_not_exist"
+"#;
+    let env = TestEnvironment::new(&manifest_with_selectors(), config);
+    let manifest = Manifest::from_file(&env.manifest_path).expect("Failed to load manifest");
+    let config = Config::from_file(&env.config_path).expect("Failed to load config");
+
+    let error = apply_manifest_node_rules(&manifest, &config, false).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("Unknown selector 'does_not_exist'"));
+    assert!(error.to_string().contains("finance_models"));
+}
@@ -0,0 +1,177 @@
+mod common;
+
+use common::TestEnvironment;
+use dbtective::core::config::Config;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "generated_at": "2025-01-01T00:00:00.000000Z",
+    "invocation_id": "test-invocation",
+    "env": {},
+    "project_name": "test",
+    "adapter_type": "postgres",
+    "quoting": {}
+  },
+  "nodes": {},
+  "sources": {
+    "source.test.raw.orders": {
+      "database": "db",
+      "schema": "raw",
+      "name": "orders",
+      "resource_type": "source",
+      "package_name": "test",
+      "path": "models/staging/sources.yml",
+      "original_file_path": "models/staging/sources.yml",
+      "unique_id": "source.test.raw.orders",
+      "fqn": ["test", "staging", "raw", "orders"],
+      "source_name": "raw",
+      "source_description": "",
+      "loader": "",
+      "identifier": "orders",
+      "columns": {},
+      "loaded_at_field": "_loaded_at",
+      "freshness": {
+        "warn_after": {"count": 12, "period": "hour"},
+        "error_after": {"count": 24, "period": "hour"},
+        "filter": null
+      }
+    },
+    "source.test.raw.customers": {
+      "database": "db",
+      "schema": "raw",
+      "name": "customers",
+      "resource_type": "source",
+      "package_name": "test",
+      "path": "models/staging/sources.yml",
+      "original_file_path": "models/staging/sources.yml",
+      "unique_id": "source.test.raw.customers",
+      "fqn": ["test", "staging", "raw", "customers"],
+      "source_name": "raw",
+      "source_description": "",
+      "loader": "",
+      "identifier": "customers",
+      "columns": {},
+      "loaded_at_field": null,
+      "freshness": {
+        "warn_after": {"count": null, "period": null},
+        "error_after": {"count": 365, "period": "day"},
+        "filter": null
+      },
+      "config": {
+        "enabled": true,
+        "loaded_at_field": "_synced_at",
+        "freshness": {
+          "warn_after": {"count": null, "period": null},
+          "error_after": {"count": 365, "period": "day"},
+          "filter": null
+        }
+      }
+    },
+    "source.test.raw.events": {
+      "database": "db",
+      "schema": "raw",
+      "name": "events",
+      "resource_type": "source",
+      "package_name": "test",
+      "path": "models/staging/sources.yml",
+      "original_file_path": "models/staging/sources.yml",
+      "unique_id": "source.test.raw.events",
+      "fqn": ["test", "staging", "raw", "events"],
+      "source_name": "raw",
+      "source_description": "",
+      "loader": "",
+      "identifier": "events",
+      "columns": {},
+      "loaded_at_field": null,
+      "freshness": {
+        "warn_after": {"count": null, "period": null},
+        "error_after": {"count": null, "period": null},
+        "filter": null
+      },
+      "config": {
+        "enabled": true
+      }
+    }
+  },
+  "macros": {},
+  "exposures": {},
+  "metrics": {},
+  "groups": {},
+  "selectors": {},
+  "disabled": {},
+  "parent_map": {},
+  "child_map": {},
+  "group_map": {},
+  "saved_queries": {},
+  "semantic_models": {},
+  "unit_tests": {}
+}"#;
+
+fn messages(config: &str) -> Vec<String> {
+    let env = TestEnvironment::new(MANIFEST, config);
+    let mut messages: Vec<String> = env
+        .run_maniest_rules(false)
+        .into_iter()
+        .map(|(finding, _)| finding.message)
+        .collect();
+    messages.sort();
+    messages
+}
+
+#[test]
+fn test_source_has_freshness() {
+    let config = r#"
+manifest_tests:
+  - type: "source_has_freshness"
+"#;
+    // customers inherits its loaded_at_field from the config of the source block
+    assert_eq!(
+        messages(config),
+        vec!["events freshness: no loaded_at_field; no warn_after or error_after threshold"]
+    );
+}
+
+#[test]
+fn test_max_thresholds() {
+    let config = r#"
+manifest_tests:
+  - type: "source_has_freshness"
+    max_warn_after: {count: 6, period: hour}
+    max_error_after: {count: 7, period: day}
+"#;
+    assert_eq!(
+        messages(config),
+        vec![
+            "customers freshness: error_after of 365 day(s) exceeds the maximum of 7 day(s)",
+            "events freshness: no loaded_at_field; no warn_after or error_after threshold",
+            "orders freshness: warn_after of 12 hour(s) exceeds the maximum of 6 hour(s)",
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_max_threshold() {
+    let config = r#"
+manifest_tests:
+  - type: "source_has_freshness"
+    max_error_after: {count: 7}
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    let error = Config::from_file(&env.config_path).unwrap_err();
+    assert!(format!("{error:#}").contains(
+        "`max_error_after` needs a positive `count` and a `period` (minute, hour or day)"
+    ));
+}
+
+#[test]
+fn test_applies_to_options() {
+    let config = r#"
+manifest_tests:
+  - type: "source_has_freshness"
+    applies_to: ["models"]
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    assert!(Config::from_file(&env.config_path).is_err());
+}
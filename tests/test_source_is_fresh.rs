@@ -0,0 +1,236 @@
+mod common;
+
+use common::TestEnvironment;
+use dbtective::core::config::{severity::Severity, Config};
+use dbtective::core::freshness::parse_sources::FreshnessResults;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "generated_at": "2025-01-01T00:00:00.000000Z",
+    "invocation_id": "test-invocation",
+    "env": {},
+    "project_name": "test",
+    "adapter_type": "postgres",
+    "quoting": {}
+  },
+  "nodes": {},
+  "sources": {
+    "source.test.raw.orders": {
+      "database": "db",
+      "schema": "raw",
+      "name": "orders",
+      "resource_type": "source",
+      "package_name": "test",
+      "path": "models/staging/sources.yml",
+      "original_file_path": "models/staging/sources.yml",
+      "unique_id": "source.test.raw.orders",
+      "fqn": ["test", "staging", "raw", "orders"],
+      "source_name": "raw",
+      "source_description": "",
+      "loader": "",
+      "identifier": "orders",
+      "columns": {}
+    },
+    "source.test.raw.customers": {
+      "database": "db",
+      "schema": "raw",
+      "name": "customers",
+      "resource_type": "source",
+      "package_name": "test",
+      "path": "models/staging/sources.yml",
+      "original_file_path": "models/staging/sources.yml",
+      "unique_id": "source.test.raw.customers",
+      "fqn": ["test", "staging", "raw", "customers"],
+      "source_name": "raw",
+      "source_description": "",
+      "loader": "",
+      "identifier": "customers",
+      "columns": {}
+    },
+    "source.test.raw.payments": {
+      "database": "db",
+      "schema": "raw",
+      "name": "payments",
+      "resource_type": "source",
+      "package_name": "test",
+      "path": "models/staging/sources.yml",
+      "original_file_path": "models/staging/sources.yml",
+      "unique_id": "source.test.raw.payments",
+      "fqn": ["test", "staging", "raw", "payments"],
+      "source_name": "raw",
+      "source_description": "",
+      "loader": "",
+      "identifier": "payments",
+      "columns": {}
+    },
+    "source.test.raw.events": {
+      "database": "db",
+      "schema": "raw",
+      "name": "events",
+      "resource_type": "source",
+      "package_name": "test",
+      "path": "models/staging/sources.yml",
+      "original_file_path": "models/staging/sources.yml",
+      "unique_id": "source.test.raw.events",
+      "fqn": ["test", "staging", "raw", "events"],
+      "source_name": "raw",
+      "source_description": "",
+      "loader": "",
+      "identifier": "events",
+      "columns": {}
+    }
+  },
+  "macros": {},
+  "exposures": {},
+  "metrics": {},
+  "groups": {},
+  "selectors": {},
+  "disabled": {},
+  "parent_map": {},
+  "child_map": {},
+  "group_map": {},
+  "saved_queries": {},
+  "semantic_models": {},
+  "unit_tests": {}
+}"#;
+
+const SOURCES: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/sources/v3.json",
+    "dbt_version": "1.10.0",
+    "generated_at": "2025-01-02T00:00:00.000000Z",
+    "invocation_id": "test-invocation",
+    "env": {}
+  },
+  "results": [
+    {
+      "unique_id": "source.test.raw.orders",
+      "max_loaded_at": "2025-01-01T00:00:00+00:00",
+      "snapshotted_at": "2025-01-01T00:00:00+00:00",
+      "max_loaded_at_time_ago_in_s": 0.0,
+      "status": "pass",
+      "criteria": {
+        "warn_after": {"count": 1, "period": "hour"},
+        "error_after": {"count": 12, "period": "hour"},
+        "filter": null
+      },
+      "adapter_response": {},
+      "timing": [],
+      "thread_id": "Thread-1",
+      "execution_time": 0.1
+    },
+    {
+      "unique_id": "source.test.raw.customers",
+      "max_loaded_at": "2025-01-01T00:00:00+00:00",
+      "snapshotted_at": "2025-01-01T13:00:00+00:00",
+      "max_loaded_at_time_ago_in_s": 46800.0,
+      "status": "error",
+      "criteria": {
+        "warn_after": {"count": 1, "period": "hour"},
+        "error_after": {"count": 12, "period": "hour"},
+        "filter": null
+      },
+      "adapter_response": {},
+      "timing": [],
+      "thread_id": "Thread-1",
+      "execution_time": 0.1
+    },
+    {
+      "unique_id": "source.test.raw.payments",
+      "max_loaded_at": "2025-01-01T00:00:00+00:00",
+      "snapshotted_at": "2025-01-01T02:00:00+00:00",
+      "max_loaded_at_time_ago_in_s": 7200.0,
+      "status": "warn",
+      "criteria": {
+        "warn_after": {"count": 1, "period": "hour"},
+        "error_after": {"count": 12, "period": "hour"},
+        "filter": null
+      },
+      "adapter_response": {},
+      "timing": [],
+      "thread_id": "Thread-1",
+      "execution_time": 0.1
+    }
+  ],
+  "elapsed_time": 0.5
+}"#;
+
+fn messages(config: &str) -> Vec<String> {
+    let env = TestEnvironment::new_with_sources(MANIFEST, SOURCES, config);
+    let mut messages: Vec<String> = env
+        .run_freshness_rules()
+        .unwrap()
+        .into_iter()
+        .map(|(finding, _)| finding.message)
+        .collect();
+    messages.sort();
+    messages
+}
+
+#[test]
+fn test_source_is_fresh() {
+    let config = r#"
+freshness_tests:
+  - type: "source_is_fresh"
+"#;
+    // events has no freshness result at all
+    assert_eq!(
+        messages(config),
+        vec![
+            "customers freshness status is error, last loaded at 2025-01-01T00:00:00+00:00",
+            "events has no freshness result, configure its freshness and run 'dbt source freshness'",
+            "payments freshness status is warn, last loaded at 2025-01-01T00:00:00+00:00",
+        ]
+    );
+}
+
+#[test]
+fn test_warnings_do_not_fail() {
+    let config = r#"
+freshness_tests:
+  - name: "sources_not_stale"
+    type: "source_is_fresh"
+    severity: "warning"
+    fail_on_warn: false
+    excludes: ["models/staging/*"]
+"#;
+    assert!(messages(config).is_empty());
+
+    let config = r#"
+freshness_tests:
+  - name: "sources_not_stale"
+    type: "source_is_fresh"
+    severity: "warning"
+    fail_on_warn: false
+"#;
+    let env = TestEnvironment::new_with_sources(MANIFEST, SOURCES, config);
+    let findings = env.run_freshness_rules().unwrap();
+    assert_eq!(findings.len(), 2);
+    assert!(findings.iter().all(
+        |(finding, severity)| finding.rule_name == "sources_not_stale"
+            && *severity == Severity::Warning
+    ));
+}
+
+#[test]
+fn test_applies_to_options() {
+    let config = r#"
+freshness_tests:
+  - type: "source_is_fresh"
+    applies_to: ["models"]
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    assert!(Config::from_file(&env.config_path).is_err());
+}
+
+#[test]
+fn test_unsupported_sources_version() {
+    let sources = SOURCES.replace("sources/v3.json", "sources/v9.json");
+    let env = TestEnvironment::new_with_sources(MANIFEST, &sources, "");
+    let error = FreshnessResults::from_file(env.sources_path.unwrap()).unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("Unsupported sources.json schema version"));
+}
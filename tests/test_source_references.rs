@@ -0,0 +1,235 @@
+mod common;
+
+use common::TestEnvironment;
+
+// raw.customers is selected by stg_customers and customers, raw.orders only by stg_orders.
+// legacy_orders lives in models/legacy/ and selects raw.orders directly as well.
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "generated_at": "2025-01-01T00:00:00.000000Z",
+    "invocation_id": "test-invocation",
+    "env": {},
+    "project_name": "test",
+    "adapter_type": "postgres",
+    "quoting": {}
+  },
+  "nodes": {
+    "model.test.stg_customers": {
+      "database": "db",
+      "schema": "public",
+      "name": "stg_customers",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "staging/stg_customers.sql",
+      "original_file_path": "models/staging/stg_customers.sql",
+      "unique_id": "model.test.stg_customers",
+      "fqn": ["test", "staging", "stg_customers"],
+      "alias": "stg_customers",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["source.test.raw.customers"]
+      }
+    },
+    "model.test.stg_orders": {
+      "database": "db",
+      "schema": "public",
+      "name": "stg_orders",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "staging/stg_orders.sql",
+      "original_file_path": "models/staging/stg_orders.sql",
+      "unique_id": "model.test.stg_orders",
+      "fqn": ["test", "staging", "stg_orders"],
+      "alias": "stg_orders",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["source.test.raw.orders"]
+      }
+    },
+    "model.test.customers": {
+      "database": "db",
+      "schema": "public",
+      "name": "customers",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "marts/customers.sql",
+      "original_file_path": "models/marts/customers.sql",
+      "unique_id": "model.test.customers",
+      "fqn": ["test", "marts", "customers"],
+      "alias": "customers",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.stg_customers", "source.test.raw.customers"]
+      }
+    },
+    "model.test.legacy_orders": {
+      "database": "db",
+      "schema": "public",
+      "name": "legacy_orders",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "legacy/legacy_orders.sql",
+      "original_file_path": "models/legacy/legacy_orders.sql",
+      "unique_id": "model.test.legacy_orders",
+      "fqn": ["test", "legacy", "legacy_orders"],
+      "alias": "legacy_orders",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["source.test.raw.orders"]
+      }
+    }
+  },
+  "sources": {
+    "source.test.raw.customers": {
+      "database": "db",
+      "schema": "raw",
+      "name": "customers",
+      "resource_type": "source",
+      "package_name": "test",
+      "path": "models/staging/sources.yml",
+      "original_file_path": "models/staging/sources.yml",
+      "unique_id": "source.test.raw.customers",
+      "fqn": ["test", "staging", "raw", "customers"],
+      "source_name": "raw",
+      "source_description": "",
+      "loader": "",
+      "identifier": "customers"
+    },
+    "source.test.raw.orders": {
+      "database": "db",
+      "schema": "raw",
+      "name": "orders",
+      "resource_type": "source",
+      "package_name": "test",
+      "path": "models/staging/sources.yml",
+      "original_file_path": "models/staging/sources.yml",
+      "unique_id": "source.test.raw.orders",
+      "fqn": ["test", "staging", "raw", "orders"],
+      "source_name": "raw",
+      "source_description": "",
+      "loader": "",
+      "identifier": "orders"
+    }
+  },
+  "macros": {},
+  "exposures": {},
+  "metrics": {},
+  "groups": {},
+  "selectors": {},
+  "disabled": {},
+  "parent_map": {"model.test.stg_customers": ["source.test.raw.customers"], "model.test.stg_orders": ["source.test.raw.orders"], "model.test.customers": ["model.test.stg_customers", "source.test.raw.customers"], "model.test.legacy_orders": ["source.test.raw.orders"]},
+  "child_map": {"source.test.raw.customers": ["model.test.stg_customers", "model.test.customers"], "source.test.raw.orders": ["model.test.stg_orders", "model.test.legacy_orders"], "model.test.stg_customers": ["model.test.customers"], "model.test.stg_orders": [], "model.test.customers": [], "model.test.legacy_orders": []},
+  "group_map": {},
+  "saved_queries": {},
+  "semantic_models": {},
+  "unit_tests": {}
+}"#;
+
+#[test]
+fn test_source_fanout() {
+    let config = r#"
+manifest_tests:
+  - name: "single_staging_model"
+    type: "source_fanout"
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    let mut findings = env.run_maniest_rules(false);
+    findings.sort_by(|a, b| a.0.message.cmp(&b.0.message));
+
+    assert_eq!(findings.len(), 2);
+    assert_eq!(findings[0].0.rule_name, "single_staging_model");
+    assert_eq!(findings[0].0.object_type, "Source");
+    assert_eq!(
+        findings[0].0.message,
+        "customers is selected by 2 models (customers, stg_customers), the maximum is 1"
+    );
+    assert_eq!(
+        findings[0].0.relative_path,
+        Some("models/staging/sources.yml".to_string())
+    );
+    assert_eq!(
+        findings[1].0.message,
+        "orders is selected by 2 models (legacy_orders, stg_orders), the maximum is 1"
+    );
+}
+
+#[test]
+fn test_source_fanout_max_references() {
+    let config = r#"
+manifest_tests:
+  - type: "source_fanout"
+    max_references: 2
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    assert!(env.run_maniest_rules(false).is_empty());
+}
+
+#[test]
+fn test_direct_source_reference_defaults_to_staging_prefix() {
+    let config = r#"
+manifest_tests:
+  - type: "direct_source_reference"
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    let mut findings = env.run_maniest_rules(false);
+    findings.sort_by(|a, b| a.0.message.cmp(&b.0.message));
+
+    assert_eq!(findings.len(), 2);
+    assert_eq!(findings[0].0.object_type, "Model");
+    assert_eq!(
+        findings[0].0.message,
+        "customers selects directly from source(s) raw.customers, use a staging model instead"
+    );
+    assert_eq!(
+        findings[1].0.message,
+        "legacy_orders selects directly from source(s) raw.orders, use a staging model instead"
+    );
+}
+
+#[test]
+fn test_direct_source_reference_allowed_paths_and_prefixes() {
+    let config = r#"
+manifest_tests:
+  - type: "direct_source_reference"
+    allowed_paths: ["models/staging/", "models/legacy/"]
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+    assert_eq!(findings.len(), 1);
+    assert!(findings[0]
+        .0
+        .message
+        .starts_with("customers selects directly"));
+
+    // Configuring prefixes replaces the default `stg_` prefix
+    let config = r#"
+manifest_tests:
+  - type: "direct_source_reference"
+    allowed_name_prefixes: ["legacy_"]
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    let mut findings = env.run_maniest_rules(false);
+    findings.sort_by(|a, b| a.0.message.cmp(&b.0.message));
+    assert_eq!(findings.len(), 3);
+    assert!(findings[0].0.message.starts_with("customers "));
+    assert!(findings[1].0.message.starts_with("stg_customers "));
+    assert!(findings[2].0.message.starts_with("stg_orders "));
+}
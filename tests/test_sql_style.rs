@@ -0,0 +1,191 @@
+mod common;
+
+use common::TestEnvironment;
+use dbtective::core::config::Config;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "generated_at": "2025-01-01T00:00:00.000000Z",
+    "invocation_id": "test-invocation",
+    "env": {},
+    "project_name": "test",
+    "adapter_type": "postgres",
+    "quoting": {}
+  },
+  "nodes": {
+    "model.test.stg_orders": {
+      "database": "db",
+      "schema": "public",
+      "name": "stg_orders",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "staging/stg_orders.sql",
+      "original_file_path": "models/staging/stg_orders.sql",
+      "unique_id": "model.test.stg_orders",
+      "fqn": ["test", "staging", "stg_orders"],
+      "alias": "stg_orders",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "raw_code": "select *\nfrom {{ source('raw', 'orders') }}\norder by id",
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": []
+      }
+    },
+    "model.test.orders": {
+      "database": "db",
+      "schema": "public",
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "marts/orders.sql",
+      "original_file_path": "models/marts/orders.sql",
+      "unique_id": "model.test.orders",
+      "fqn": ["test", "marts", "orders"],
+      "alias": "orders",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "raw_code": "with stg_orders as (\n    select * from {{ ref('stg_orders') }}\n),\n\nfinal as (\n    select distinct id, nvl(amount, 0) as amount\n    from stg_orders\n    order by id\n)\n\nselect * from final",
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": []
+      }
+    }
+  },
+  "sources": {},
+  "macros": {
+    "macro.test.all_columns": {
+      "name": "all_columns",
+      "resource_type": "macro",
+      "package_name": "test",
+      "path": "macros/all_columns.sql",
+      "original_file_path": "macros/all_columns.sql",
+      "unique_id": "macro.test.all_columns",
+      "macro_sql": "{% macro all_columns(relation) %}\n    select * from {{ relation }}\n{% endmacro %}",
+      "depends_on": {
+        "macros": []
+      },
+      "description": "",
+      "meta": {},
+      "docs": {
+        "show": true
+      },
+      "patch_path": null,
+      "arguments": []
+    }
+  },
+  "exposures": {},
+  "metrics": {},
+  "groups": {},
+  "selectors": {},
+  "disabled": {},
+  "parent_map": {},
+  "child_map": {
+    "model.test.stg_orders": ["model.test.orders"],
+    "model.test.orders": []
+  },
+  "group_map": {},
+  "saved_queries": {},
+  "semantic_models": {},
+  "unit_tests": {}
+}"#;
+
+fn messages(config: &str) -> Vec<String> {
+    let env = TestEnvironment::new(MANIFEST, config);
+    let mut messages: Vec<String> = env
+        .run_maniest_rules(false)
+        .into_iter()
+        .map(|(finding, _)| finding.message)
+        .collect();
+    messages.sort();
+    messages
+}
+
+#[test]
+fn test_no_select_star_applies_to_macros() {
+    let config = r#"
+manifest_tests:
+  - type: "no_select_star"
+"#;
+    // Import CTEs and the final `select * from final` are allowed
+    assert_eq!(
+        messages(config),
+        vec![
+            "all_columns uses select * on line 2, select the columns explicitly",
+            "stg_orders uses select * on line 1, select the columns explicitly",
+        ]
+    );
+}
+
+#[test]
+fn test_no_order_by_only_flags_models_with_children() {
+    let config = r#"
+manifest_tests:
+  - type: "no_order_by"
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+
+    assert_eq!(findings.len(), 1);
+    assert_eq!(
+        findings[0].0.message,
+        "stg_orders is used by other models but has an order by on line 3, only order the final model"
+    );
+    assert_eq!(
+        findings[0].0.relative_path,
+        Some("models/staging/stg_orders.sql".to_string())
+    );
+    assert_eq!(findings[0].0.line, Some(3));
+    assert_eq!(findings[0].0.column, Some(1));
+}
+
+#[test]
+fn test_distinct_and_forbidden_keywords() {
+    let config = r#"
+manifest_tests:
+  - type: "no_distinct_without_comment"
+  - type: "forbidden_keywords"
+    keywords: ["nvl", "getdate"]
+"#;
+    assert_eq!(
+        messages(config),
+        vec![
+            "orders uses forbidden keywords: nvl (line 6)",
+            "orders uses select distinct without a comment explaining why on line 6",
+        ]
+    );
+}
+
+#[test]
+fn test_import_ctes() {
+    let config = r#"
+manifest_tests:
+  - type: "import_ctes"
+"#;
+    assert_eq!(
+        messages(config),
+        vec![
+            "stg_orders does not follow the import CTE structure: source('raw', 'orders') is used outside of an import CTE (line 2)"
+        ]
+    );
+}
+
+#[test]
+fn test_empty_forbidden_keywords_are_rejected() {
+    let config = r#"
+manifest_tests:
+  - type: "forbidden_keywords"
+    keywords: []
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    let error = Config::from_file(&env.config_path).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("`keywords` must contain at least one keyword"));
+}
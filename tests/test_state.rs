@@ -0,0 +1,464 @@
+mod common;
+
+use common::TestEnvironment;
+use dbtective::core::config::modified_state::ModifiedState;
+use dbtective::core::config::Config;
+use dbtective::core::manifest::Manifest;
+use dbtective::core::rules::manifest::apply_manifest_node_rules::apply_manifest_node_rules;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "generated_at": "2025-01-01T00:00:00.000000Z",
+    "invocation_id": "test-invocation",
+    "env": {},
+    "project_name": "test_project",
+    "adapter_type": "postgres",
+    "quoting": {
+      "database": true,
+      "schema": true,
+      "identifier": true,
+      "column": null
+    }
+  },
+  "nodes": {
+    "model.test_project.customers": {
+      "database": "analytics",
+      "schema": "public",
+      "name": "customers",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "path": "customers.sql",
+      "original_file_path": "models/customers.sql",
+      "unique_id": "model.test_project.customers",
+      "fqn": [
+        "test_project",
+        "customers"
+      ],
+      "alias": "customers",
+      "checksum": {
+        "name": "sha256",
+        "checksum": "abc"
+      },
+      "config": {
+        "enabled": true,
+        "materialized": "table",
+        "tags": [
+          "finance"
+        ]
+      },
+      "tags": [
+        "finance"
+      ],
+      "description": "",
+      "columns": {
+        "customer_id": {
+          "name": "customer_id",
+          "description": "Primary key for customers",
+          "meta": {},
+          "data_type": "integer",
+          "constraints": [],
+          "tags": []
+        }
+      },
+      "meta": {},
+      "group": null,
+      "docs": {
+        "show": true
+      },
+      "patch_path": null,
+      "compiled_path": null,
+      "build_path": null,
+      "deferred": false,
+      "unrendered_config": {},
+      "created_at": 1704067200.0,
+      "config_call_dict": {},
+      "relation_name": "analytics.public.customers",
+      "raw_code": "select * from raw_customers",
+      "language": "sql",
+      "refs": [],
+      "sources": [],
+      "metrics": [],
+      "depends_on": {
+        "macros": [],
+        "nodes": []
+      },
+      "compiled_code": null,
+      "extra_ctes_injected": false,
+      "extra_ctes": [],
+      "contract": {
+        "enforced": false,
+        "checksum": null
+      }
+    },
+    "model.test_project.orders": {
+      "database": "analytics",
+      "schema": "public",
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "path": "orders.sql",
+      "original_file_path": "models/orders.sql",
+      "unique_id": "model.test_project.orders",
+      "fqn": [
+        "test_project",
+        "orders"
+      ],
+      "alias": "orders",
+      "checksum": {
+        "name": "sha256",
+        "checksum": "def"
+      },
+      "config": {
+        "enabled": true,
+        "materialized": "table",
+        "tags": [
+          "finance"
+        ]
+      },
+      "tags": [
+        "finance"
+      ],
+      "description": "",
+      "columns": {
+        "customer_id": {
+          "name": "customer_id",
+          "description": "Primary key for customers",
+          "meta": {},
+          "data_type": "integer",
+          "constraints": [],
+          "tags": []
+        }
+      },
+      "meta": {},
+      "group": null,
+      "docs": {
+        "show": true
+      },
+      "patch_path": null,
+      "compiled_path": null,
+      "build_path": null,
+      "deferred": false,
+      "unrendered_config": {},
+      "created_at": 1704067200.0,
+      "config_call_dict": {},
+      "relation_name": "analytics.public.orders",
+      "raw_code": "select * from raw_customers",
+      "language": "sql",
+      "refs": [],
+      "sources": [],
+      "metrics": [],
+      "depends_on": {
+        "macros": [],
+        "nodes": []
+      },
+      "compiled_code": null,
+      "extra_ctes_injected": false,
+      "extra_ctes": [],
+      "contract": {
+        "enforced": false,
+        "checksum": null
+      }
+    },
+    "model.test_project.payments": {
+      "database": "analytics",
+      "schema": "public",
+      "name": "payments",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "path": "payments.sql",
+      "original_file_path": "models/payments.sql",
+      "unique_id": "model.test_project.payments",
+      "fqn": [
+        "test_project",
+        "payments"
+      ],
+      "alias": "payments",
+      "checksum": {
+        "name": "sha256",
+        "checksum": "ghi"
+      },
+      "config": {
+        "enabled": true,
+        "materialized": "table",
+        "tags": [
+          "finance"
+        ]
+      },
+      "tags": [
+        "finance"
+      ],
+      "description": "",
+      "columns": {
+        "customer_id": {
+          "name": "customer_id",
+          "description": "Primary key for customers",
+          "meta": {},
+          "data_type": "integer",
+          "constraints": [],
+          "tags": []
+        }
+      },
+      "meta": {},
+      "group": null,
+      "docs": {
+        "show": true
+      },
+      "patch_path": null,
+      "compiled_path": null,
+      "build_path": null,
+      "deferred": false,
+      "unrendered_config": {},
+      "created_at": 1704067200.0,
+      "config_call_dict": {},
+      "relation_name": "analytics.public.payments",
+      "raw_code": "select * from raw_customers",
+      "language": "sql",
+      "refs": [],
+      "sources": [],
+      "metrics": [],
+      "depends_on": {
+        "macros": [],
+        "nodes": []
+      },
+      "compiled_code": null,
+      "extra_ctes_injected": false,
+      "extra_ctes": [],
+      "contract": {
+        "enforced": false,
+        "checksum": null
+      }
+    }
+  },
+  "sources": {},
+  "macros": {},
+  "exposures": {},
+  "metrics": {},
+  "groups": {},
+  "selectors": {},
+  "disabled": {},
+  "parent_map": {},
+  "child_map": {},
+  "group_map": {},
+  "saved_queries": {},
+  "semantic_models": {},
+  "unit_tests": {}
+}"#;
+
+// customers is unchanged, orders has a different checksum and payments did not exist yet
+const PREVIOUS_MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "generated_at": "2025-01-01T00:00:00.000000Z",
+    "invocation_id": "test-invocation",
+    "env": {},
+    "project_name": "test_project",
+    "adapter_type": "postgres",
+    "quoting": {
+      "database": true,
+      "schema": true,
+      "identifier": true,
+      "column": null
+    }
+  },
+  "nodes": {
+    "model.test_project.customers": {
+      "database": "analytics",
+      "schema": "public",
+      "name": "customers",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "path": "customers.sql",
+      "original_file_path": "models/customers.sql",
+      "unique_id": "model.test_project.customers",
+      "fqn": [
+        "test_project",
+        "customers"
+      ],
+      "alias": "customers",
+      "checksum": {
+        "name": "sha256",
+        "checksum": "abc"
+      },
+      "config": {
+        "enabled": true,
+        "materialized": "table",
+        "tags": [
+          "finance"
+        ]
+      },
+      "tags": [
+        "finance"
+      ],
+      "description": "",
+      "columns": {
+        "customer_id": {
+          "name": "customer_id",
+          "description": "Primary key for customers",
+          "meta": {},
+          "data_type": "integer",
+          "constraints": [],
+          "tags": []
+        }
+      },
+      "meta": {},
+      "group": null,
+      "docs": {
+        "show": true
+      },
+      "patch_path": null,
+      "compiled_path": null,
+      "build_path": null,
+      "deferred": false,
+      "unrendered_config": {},
+      "created_at": 1704067200.0,
+      "config_call_dict": {},
+      "relation_name": "analytics.public.customers",
+      "raw_code": "select * from raw_customers",
+      "language": "sql",
+      "refs": [],
+      "sources": [],
+      "metrics": [],
+      "depends_on": {
+        "macros": [],
+        "nodes": []
+      },
+      "compiled_code": null,
+      "extra_ctes_injected": false,
+      "extra_ctes": [],
+      "contract": {
+        "enforced": false,
+        "checksum": null
+      }
+    },
+    "model.test_project.orders": {
+      "database": "analytics",
+      "schema": "public",
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "path": "orders.sql",
+      "original_file_path": "models/orders.sql",
+      "unique_id": "model.test_project.orders",
+      "fqn": [
+        "test_project",
+        "orders"
+      ],
+      "alias": "orders",
+      "checksum": {
+        "name": "sha256",
+        "checksum": "old"
+      },
+      "config": {
+        "enabled": true,
+        "materialized": "table",
+        "tags": [
+          "finance"
+        ]
+      },
+      "tags": [
+        "finance"
+      ],
+      "description": "",
+      "columns": {
+        "customer_id": {
+          "name": "customer_id",
+          "description": "Primary key for customers",
+          "meta": {},
+          "data_type": "integer",
+          "constraints": [],
+          "tags": []
+        }
+      },
+      "meta": {},
+      "group": null,
+      "docs": {
+        "show": true
+      },
+      "patch_path": null,
+      "compiled_path": null,
+      "build_path": null,
+      "deferred": false,
+      "unrendered_config": {},
+      "created_at": 1704067200.0,
+      "config_call_dict": {},
+      "relation_name": "analytics.public.orders",
+      "raw_code": "select * from raw_customers",
+      "language": "sql",
+      "refs": [],
+      "sources": [],
+      "metrics": [],
+      "depends_on": {
+        "macros": [],
+        "nodes": []
+      },
+      "compiled_code": null,
+      "extra_ctes_injected": false,
+      "extra_ctes": [],
+      "contract": {
+        "enforced": false,
+        "checksum": null
+      }
+    }
+  },
+  "sources": {},
+  "macros": {},
+  "exposures": {},
+  "metrics": {},
+  "groups": {},
+  "selectors": {},
+  "disabled": {},
+  "parent_map": {},
+  "child_map": {},
+  "group_map": {},
+  "saved_queries": {},
+  "semantic_models": {},
+  "unit_tests": {}
+}"#;
+
+const CONFIG: &str = r#"
+manifest_tests:
+  - name: "models_have_description"
+    type: "has_description"
+    severity: "error"
+    applies_to:
+      - "models"
+"#;
+
+#[test]
+fn test_state_only_evaluates_new_and_modified_objects() {
+    let env = TestEnvironment::new(MANIFEST, CONFIG);
+    let previous_path = env.temp_dir.path().join("previous_manifest.json");
+    std::fs::write(&previous_path, PREVIOUS_MANIFEST).expect("Failed to write manifest");
+
+    let manifest = Manifest::from_file(&env.manifest_path).expect("Failed to load manifest");
+    let previous = Manifest::from_file(&previous_path).expect("Failed to load manifest");
+    let mut config = Config::from_file(&env.config_path).expect("Failed to load config");
+    config.modified_state = Some(ModifiedState::compare(&manifest, &previous));
+
+    let outcomes =
+        apply_manifest_node_rules(&manifest, &config, false).expect("Failed to apply node rules");
+    let mut unique_ids: Vec<&str> = outcomes
+        .findings
+        .iter()
+        .map(|(result, _)| result.unique_id.as_deref().unwrap())
+        .collect();
+    unique_ids.sort_unstable();
+
+    assert_eq!(
+        unique_ids,
+        vec!["model.test_project.orders", "model.test_project.payments"]
+    );
+}
+
+#[test]
+fn test_unchanged_manifest_evaluates_nothing() {
+    let env = TestEnvironment::new(MANIFEST, CONFIG);
+    let manifest = Manifest::from_file(&env.manifest_path).expect("Failed to load manifest");
+    let previous = Manifest::from_file(&env.manifest_path).expect("Failed to load manifest");
+
+    let modified_state = ModifiedState::compare(&manifest, &previous);
+    assert!(modified_state.is_empty());
+}
@@ -0,0 +1,319 @@
+mod common;
+
+use common::TestEnvironment;
+use dbtective::core::config::Config;
+use dbtective::core::manifest::Manifest;
+use dbtective::core::rules::manifest::apply_manifest_node_rules::apply_manifest_node_rules;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "generated_at": "2025-01-01T00:00:00.000000Z",
+    "invocation_id": "test-invocation",
+    "env": {},
+    "project_name": "test_project",
+    "adapter_type": "postgres",
+    "quoting": {
+      "database": true,
+      "schema": true,
+      "identifier": true,
+      "column": null
+    }
+  },
+  "nodes": {
+    "model.test_project.customers": {
+      "database": "analytics",
+      "schema": "public",
+      "name": "customers",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "path": "customers.sql",
+      "original_file_path": "models/customers.sql",
+      "unique_id": "model.test_project.customers",
+      "fqn": [
+        "test_project",
+        "customers"
+      ],
+      "alias": "customers",
+      "checksum": {
+        "name": "sha256",
+        "checksum": "abc123"
+      },
+      "config": {
+        "enabled": true,
+        "materialized": "table",
+        "tags": [
+          "finance"
+        ]
+      },
+      "tags": [
+        "finance"
+      ],
+      "description": "",
+      "columns": {
+        "customer_id": {
+          "name": "customer_id",
+          "description": "Primary key for customers",
+          "meta": {},
+          "data_type": "integer",
+          "constraints": [],
+          "tags": []
+        }
+      },
+      "meta": {
+        "dbtective": {
+          "disable": [
+            "models_have_description"
+          ],
+          "reason": "Described in the wiki"
+        }
+      },
+      "group": null,
+      "docs": {
+        "show": true
+      },
+      "patch_path": null,
+      "compiled_path": null,
+      "build_path": null,
+      "deferred": false,
+      "unrendered_config": {},
+      "created_at": 1704067200.0,
+      "config_call_dict": {},
+      "relation_name": "analytics.public.customers",
+      "raw_code": "select * from raw_customers",
+      "language": "sql",
+      "refs": [],
+      "sources": [],
+      "metrics": [],
+      "depends_on": {
+        "macros": [],
+        "nodes": []
+      },
+      "compiled_code": null,
+      "extra_ctes_injected": false,
+      "extra_ctes": [],
+      "contract": {
+        "enforced": false,
+        "checksum": null
+      }
+    },
+    "model.test_project.orders": {
+      "database": "analytics",
+      "schema": "public",
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "path": "orders.sql",
+      "original_file_path": "models/orders.sql",
+      "unique_id": "model.test_project.orders",
+      "fqn": [
+        "test_project",
+        "orders"
+      ],
+      "alias": "orders",
+      "checksum": {
+        "name": "sha256",
+        "checksum": "abc123"
+      },
+      "config": {
+        "enabled": true,
+        "materialized": "table",
+        "tags": [
+          "finance"
+        ],
+        "meta": {
+          "dbtective": {
+            "disable": [
+              {
+                "rule": "has_description",
+                "reason": "Legacy model"
+              }
+            ]
+          }
+        }
+      },
+      "tags": [
+        "finance"
+      ],
+      "description": "",
+      "columns": {
+        "customer_id": {
+          "name": "customer_id",
+          "description": "Primary key for customers",
+          "meta": {},
+          "data_type": "integer",
+          "constraints": [],
+          "tags": []
+        }
+      },
+      "meta": {},
+      "group": null,
+      "docs": {
+        "show": true
+      },
+      "patch_path": null,
+      "compiled_path": null,
+      "build_path": null,
+      "deferred": false,
+      "unrendered_config": {},
+      "created_at": 1704067200.0,
+      "config_call_dict": {},
+      "relation_name": "analytics.public.orders",
+      "raw_code": "select * from raw_customers",
+      "language": "sql",
+      "refs": [],
+      "sources": [],
+      "metrics": [],
+      "depends_on": {
+        "macros": [],
+        "nodes": []
+      },
+      "compiled_code": null,
+      "extra_ctes_injected": false,
+      "extra_ctes": [],
+      "contract": {
+        "enforced": false,
+        "checksum": null
+      }
+    },
+    "model.test_project.payments": {
+      "database": "analytics",
+      "schema": "public",
+      "name": "payments",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "path": "payments.sql",
+      "original_file_path": "models/payments.sql",
+      "unique_id": "model.test_project.payments",
+      "fqn": [
+        "test_project",
+        "payments"
+      ],
+      "alias": "payments",
+      "checksum": {
+        "name": "sha256",
+        "checksum": "abc123"
+      },
+      "config": {
+        "enabled": true,
+        "materialized": "table",
+        "tags": [
+          "finance"
+        ]
+      },
+      "tags": [
+        "finance"
+      ],
+      "description": "",
+      "columns": {
+        "customer_id": {
+          "name": "customer_id",
+          "description": "Primary key for customers",
+          "meta": {},
+          "data_type": "integer",
+          "constraints": [],
+          "tags": []
+        }
+      },
+      "meta": {
+        "dbtective": {
+          "disable": [
+            "has_refs"
+          ]
+        }
+      },
+      "group": null,
+      "docs": {
+        "show": true
+      },
+      "patch_path": null,
+      "compiled_path": null,
+      "build_path": null,
+      "deferred": false,
+      "unrendered_config": {},
+      "created_at": 1704067200.0,
+      "config_call_dict": {},
+      "relation_name": "analytics.public.payments",
+      "raw_code": "select * from raw_customers",
+      "language": "sql",
+      "refs": [],
+      "sources": [],
+      "metrics": [],
+      "depends_on": {
+        "macros": [],
+        "nodes": []
+      },
+      "compiled_code": null,
+      "extra_ctes_injected": false,
+      "extra_ctes": [],
+      "contract": {
+        "enforced": false,
+        "checksum": null
+      }
+    }
+  },
+  "sources": {},
+  "macros": {},
+  "exposures": {},
+  "metrics": {},
+  "groups": {},
+  "selectors": {},
+  "disabled": {},
+  "parent_map": {},
+  "child_map": {},
+  "group_map": {},
+  "saved_queries": {},
+  "semantic_models": {},
+  "unit_tests": {}
+}"#;
+
+const CONFIG: &str = r#"
+manifest_tests:
+  - name: "models_have_description"
+    type: "has_description"
+    severity: "error"
+    applies_to:
+      - "models"
+"#;
+
+#[test]
+fn test_disabled_rules_are_not_reported() {
+    let env = TestEnvironment::new(MANIFEST, CONFIG);
+    let findings = env.run_maniest_rules(false);
+
+    // customers disables the rule by name, orders by type through `config.meta`
+    assert_eq!(
+        findings.len(),
+        1,
+        "Expected one finding, but got: {findings:?}"
+    );
+    assert!(findings[0].0.message.contains("payments"));
+}
+
+#[test]
+fn test_suppressed_findings_keep_their_reason() {
+    let env = TestEnvironment::new(MANIFEST, CONFIG);
+    let manifest = Manifest::from_file(&env.manifest_path).expect("Failed to load manifest");
+    let config = Config::from_file(&env.config_path).expect("Failed to load config");
+
+    let outcomes =
+        apply_manifest_node_rules(&manifest, &config, false).expect("Failed to apply node rules");
+
+    let mut suppressed: Vec<(&str, Option<&str>)> = outcomes
+        .suppressed
+        .iter()
+        .map(|s| (s.result.unique_id.as_deref().unwrap(), s.reason.as_deref()))
+        .collect();
+    suppressed.sort_unstable();
+    assert_eq!(
+        suppressed,
+        vec![
+            (
+                "model.test_project.customers",
+                Some("Described in the wiki")
+            ),
+            ("model.test_project.orders", Some("Legacy model")),
+        ]
+    );
+}
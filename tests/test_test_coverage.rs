@@ -0,0 +1,425 @@
+mod common;
+
+use common::TestEnvironment;
+use dbtective::core::config::Config;
+use dbtective::core::manifest::Manifest;
+use dbtective::core::rules::manifest::apply_manifest_node_rules::apply_manifest_node_rules;
+use dbtective::core::rules::manifest::apply_other_manifest_object_rules::apply_manifest_object_rules;
+use dbtective::core::rules::rule_config::test_coverage::{project_coverage, ColumnCoverage};
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "generated_at": "2025-01-01T00:00:00.000000Z",
+    "invocation_id": "test-invocation",
+    "env": {},
+    "project_name": "test",
+    "adapter_type": "postgres",
+    "quoting": {}
+  },
+  "nodes": {
+    "model.test.orders": {
+      "database": "db",
+      "schema": "public",
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "marts/orders.sql",
+      "original_file_path": "models/marts/orders.sql",
+      "unique_id": "model.test.orders",
+      "fqn": ["test", "marts", "orders"],
+      "alias": "orders",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "raw_code": "select 1",
+      "tags": [],
+      "description": "",
+      "columns": {
+        "order_id": {
+          "name": "order_id",
+          "description": "",
+          "meta": {},
+          "tags": []
+        },
+        "customer_id": {
+          "name": "customer_id",
+          "description": "",
+          "meta": {},
+          "tags": []
+        },
+        "amount": {
+          "name": "amount",
+          "description": "",
+          "meta": {},
+          "tags": []
+        },
+        "status": {
+          "name": "status",
+          "description": "",
+          "meta": {},
+          "tags": []
+        }
+      },
+      "meta": {},
+      "depends_on": {
+        "nodes": []
+      }
+    },
+    "model.test.customers": {
+      "database": "db",
+      "schema": "public",
+      "name": "customers",
+      "resource_type": "model",
+      "package_name": "test",
+      "path": "marts/customers.sql",
+      "original_file_path": "models/marts/customers.sql",
+      "unique_id": "model.test.customers",
+      "fqn": ["test", "marts", "customers"],
+      "alias": "customers",
+      "checksum": {"name": "sha256", "checksum": "abc"},
+      "raw_code": "select 1",
+      "tags": [],
+      "description": "",
+      "columns": {
+        "customer_id": {
+          "name": "customer_id",
+          "description": "",
+          "meta": {},
+          "tags": []
+        },
+        "name": {
+          "name": "name",
+          "description": "",
+          "meta": {},
+          "tags": []
+        }
+      },
+      "meta": {},
+      "depends_on": {
+        "nodes": []
+      }
+    },
+    "test.test.unique_orders_order_id": {
+      "database": "db",
+      "schema": "dbt_test__audit",
+      "name": "unique_orders_order_id",
+      "resource_type": "test",
+      "package_name": "test",
+      "path": "unique_orders_order_id.sql",
+      "original_file_path": "models/marts/schema.yml",
+      "unique_id": "test.test.unique_orders_order_id",
+      "fqn": ["test", "marts", "unique_orders_order_id"],
+      "alias": "unique_orders_order_id",
+      "checksum": {"name": "sha256", "checksum": "def"},
+      "raw_code": "",
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.orders"]
+      },
+      "column_name": "order_id",
+      "attached_node": "model.test.orders",
+      "test_metadata": {
+        "name": "unique",
+        "kwargs": {"column_name": "order_id"}
+      }
+    },
+    "test.test.not_null_orders_order_id": {
+      "database": "db",
+      "schema": "dbt_test__audit",
+      "name": "not_null_orders_order_id",
+      "resource_type": "test",
+      "package_name": "test",
+      "path": "not_null_orders_order_id.sql",
+      "original_file_path": "models/marts/schema.yml",
+      "unique_id": "test.test.not_null_orders_order_id",
+      "fqn": ["test", "marts", "not_null_orders_order_id"],
+      "alias": "not_null_orders_order_id",
+      "checksum": {"name": "sha256", "checksum": "def"},
+      "raw_code": "",
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.orders"]
+      },
+      "column_name": "order_id",
+      "attached_node": "model.test.orders",
+      "test_metadata": {
+        "name": "not_null",
+        "kwargs": {"column_name": "order_id"}
+      }
+    },
+    "test.test.relationships_orders_customer_id": {
+      "database": "db",
+      "schema": "dbt_test__audit",
+      "name": "relationships_orders_customer_id",
+      "resource_type": "test",
+      "package_name": "test",
+      "path": "relationships_orders_customer_id.sql",
+      "original_file_path": "models/marts/schema.yml",
+      "unique_id": "test.test.relationships_orders_customer_id",
+      "fqn": ["test", "marts", "relationships_orders_customer_id"],
+      "alias": "relationships_orders_customer_id",
+      "checksum": {"name": "sha256", "checksum": "def"},
+      "raw_code": "",
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.orders"]
+      },
+      "column_name": "customer_id",
+      "attached_node": "model.test.orders",
+      "test_metadata": {
+        "name": "relationships",
+        "kwargs": {"column_name": "customer_id"}
+      }
+    },
+    "test.test.accepted_values_orders_status": {
+      "database": "db",
+      "schema": "dbt_test__audit",
+      "name": "accepted_values_orders_status",
+      "resource_type": "test",
+      "package_name": "test",
+      "path": "accepted_values_orders_status.sql",
+      "original_file_path": "models/marts/schema.yml",
+      "unique_id": "test.test.accepted_values_orders_status",
+      "fqn": ["test", "marts", "accepted_values_orders_status"],
+      "alias": "accepted_values_orders_status",
+      "checksum": {"name": "sha256", "checksum": "def"},
+      "raw_code": "",
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.orders"]
+      },
+      "column_name": "status",
+      "attached_node": "model.test.orders",
+      "test_metadata": {
+        "name": "accepted_values",
+        "kwargs": {"column_name": "status"}
+      }
+    },
+    "test.test.unique_customers_customer_id": {
+      "database": "db",
+      "schema": "dbt_test__audit",
+      "name": "unique_customers_customer_id",
+      "resource_type": "test",
+      "package_name": "test",
+      "path": "unique_customers_customer_id.sql",
+      "original_file_path": "models/marts/schema.yml",
+      "unique_id": "test.test.unique_customers_customer_id",
+      "fqn": ["test", "marts", "unique_customers_customer_id"],
+      "alias": "unique_customers_customer_id",
+      "checksum": {"name": "sha256", "checksum": "def"},
+      "raw_code": "",
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.customers"]
+      },
+      "column_name": "customer_id",
+      "attached_node": "model.test.customers",
+      "test_metadata": {
+        "name": "unique",
+        "kwargs": {"column_name": "customer_id"}
+      }
+    },
+    "test.test.not_null_customers_customer_id": {
+      "database": "db",
+      "schema": "dbt_test__audit",
+      "name": "not_null_customers_customer_id",
+      "resource_type": "test",
+      "package_name": "test",
+      "path": "not_null_customers_customer_id.sql",
+      "original_file_path": "models/marts/schema.yml",
+      "unique_id": "test.test.not_null_customers_customer_id",
+      "fqn": ["test", "marts", "not_null_customers_customer_id"],
+      "alias": "not_null_customers_customer_id",
+      "checksum": {"name": "sha256", "checksum": "def"},
+      "raw_code": "",
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["model.test.customers"]
+      },
+      "column_name": "customer_id",
+      "attached_node": "model.test.customers",
+      "test_metadata": {
+        "name": "not_null",
+        "kwargs": {"column_name": "customer_id"}
+      }
+    },
+    "test.test.not_null_raw_payments_payment_id": {
+      "database": "db",
+      "schema": "dbt_test__audit",
+      "name": "not_null_raw_payments_payment_id",
+      "resource_type": "test",
+      "package_name": "test",
+      "path": "not_null_raw_payments_payment_id.sql",
+      "original_file_path": "models/marts/schema.yml",
+      "unique_id": "test.test.not_null_raw_payments_payment_id",
+      "fqn": ["test", "marts", "not_null_raw_payments_payment_id"],
+      "alias": "not_null_raw_payments_payment_id",
+      "checksum": {"name": "sha256", "checksum": "def"},
+      "raw_code": "",
+      "tags": [],
+      "description": "",
+      "columns": {},
+      "meta": {},
+      "depends_on": {
+        "nodes": ["source.test.raw.payments"]
+      },
+      "column_name": "payment_id",
+      "attached_node": "source.test.raw.payments",
+      "test_metadata": {
+        "name": "not_null",
+        "kwargs": {"column_name": "payment_id"}
+      }
+    }
+  },
+  "sources": {
+    "source.test.raw.payments": {
+      "database": "db",
+      "schema": "raw",
+      "name": "payments",
+      "resource_type": "source",
+      "package_name": "test",
+      "path": "models/staging/sources.yml",
+      "original_file_path": "models/staging/sources.yml",
+      "unique_id": "source.test.raw.payments",
+      "fqn": ["test", "staging", "raw", "payments"],
+      "source_name": "raw",
+      "source_description": "",
+      "loader": "",
+      "identifier": "payments",
+      "columns": {
+        "payment_id": {
+          "name": "payment_id",
+          "description": "",
+          "meta": {},
+          "tags": []
+        },
+        "order_id": {
+          "name": "order_id",
+          "description": "",
+          "meta": {},
+          "tags": []
+        }
+      }
+    }
+  },
+  "macros": {},
+  "exposures": {},
+  "metrics": {},
+  "groups": {},
+  "selectors": {},
+  "disabled": {},
+  "parent_map": {},
+  "child_map": {},
+  "group_map": {},
+  "saved_queries": {},
+  "semantic_models": {},
+  "unit_tests": {}
+}"#;
+
+fn messages(config: &str) -> Vec<String> {
+    let env = TestEnvironment::new(MANIFEST, config);
+    let mut messages: Vec<String> = env
+        .run_maniest_rules(false)
+        .into_iter()
+        .map(|(finding, _)| finding.message)
+        .collect();
+    messages.sort();
+    messages
+}
+
+#[test]
+fn test_minimum_coverage() {
+    let config = r#"
+manifest_tests:
+  - type: "test_coverage"
+    min_coverage: 60
+"#;
+    // orders has 3 of 4 columns tested, customers and payments 1 of 2
+    assert_eq!(
+        messages(config),
+        vec![
+            "customers test coverage: 1 of 2 documented columns are tested (50%, the minimum is 60%), untested: name",
+            "payments test coverage: 1 of 2 documented columns are tested (50%, the minimum is 60%), untested: order_id"
+        ]
+    );
+}
+
+#[test]
+fn test_required_tests() {
+    let config = r#"
+manifest_tests:
+  - type: "test_coverage"
+    min_coverage: 0
+    required_tests:
+      - columns: ["*_id"]
+        tests: ["not_null"]
+      - columns: ["customer_id"]
+        tests: ["relationships"]
+"#;
+    assert_eq!(
+        messages(config),
+        vec![
+            "customers test coverage: customer_id is missing relationships",
+            "orders test coverage: customer_id is missing not_null",
+            "payments test coverage: order_id is missing not_null",
+        ]
+    );
+}
+
+#[test]
+fn test_project_coverage_counts_objects_once() {
+    let config = r#"
+manifest_tests:
+  - type: "test_coverage"
+    min_coverage: 0
+  - name: "marts_coverage"
+    type: "test_coverage"
+    applies_to: ["models"]
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    let manifest = Manifest::from_file(&env.manifest_path).unwrap();
+    let config = Config::from_file(&env.config_path).unwrap();
+    let mut outcomes = apply_manifest_node_rules(&manifest, &config, false).unwrap();
+    outcomes.extend(apply_manifest_object_rules(&manifest, &config, false).unwrap());
+
+    assert_eq!(outcomes.column_coverage.len(), 3);
+    assert_eq!(
+        project_coverage(&outcomes.column_coverage),
+        ColumnCoverage {
+            tested: 5,
+            documented: 8
+        }
+    );
+}
+
+#[test]
+fn test_min_coverage_is_a_percentage() {
+    let config = r#"
+manifest_tests:
+  - type: "test_coverage"
+    min_coverage: 101
+"#;
+    let env = TestEnvironment::new(MANIFEST, config);
+    let error = Config::from_file(&env.config_path).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("`min_coverage` is a percentage and can not be above 100"));
+}